@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use criterion::{criterion_main, BenchmarkId, Criterion, Throughput};
+use ip::{concrete::PrefixMap, traits::Prefix as _, Ipv4, Ipv6, Prefix};
+use utils::{data_sets, DataSet};
+
+macro_rules! benchmarks {
+    ( $id:ident: $t:ty =>
+        [
+            $( name = $name:literal, prefixes = $prefixes:literal, ranges = $ranges:literal );*
+            $(;)?
+        ]
+    ) => {
+        mod $id {
+            use super::*;
+
+            static DATA_SETS: &[DataSet<$t>] = data_sets!( $(
+                name = $name, prefixes = $prefixes, ranges = $ranges
+            );* );
+
+            pub fn benches(mut c: &mut Criterion) {
+                construct(&mut c);
+                longest_match(&mut c);
+                matches(&mut c);
+            }
+
+            fn construct(c: &mut Criterion) {
+                let mut g = c.benchmark_group("map-construct");
+                g.measurement_time(Duration::from_secs(10));
+                g.sample_size(10);
+
+                for ds in DATA_SETS {
+                    let prefixes = ds.read();
+                    g.throughput(Throughput::Elements(prefixes.len() as u64));
+                    g.bench_function(ds.name(), |b| {
+                        b.iter(|| {
+                            prefixes
+                                .iter()
+                                .enumerate()
+                                .map(|(i, p)| (*p, i))
+                                .collect::<PrefixMap<_, _>>()
+                        })
+                    });
+                }
+                g.finish()
+            }
+
+            // Look up the same addresses in maps built from increasingly large
+            // subsets of each data set. The number of nodes visited by a
+            // lookup is bounded by the depth of the tree, rather than the
+            // size of the map, so any growth in the cost of a lookup with map
+            // size is due to memory latency.
+            fn longest_match(c: &mut Criterion) {
+                let mut g = c.benchmark_group("map-longest-match");
+                g.measurement_time(Duration::from_secs(10));
+
+                for ds in DATA_SETS {
+                    let prefixes = ds.read();
+                    let addrs: Vec<_> = prefixes
+                        .iter()
+                        .step_by(LOOKUP_STEP)
+                        .map(|p| p.network())
+                        .collect();
+                    g.throughput(Throughput::Elements(addrs.len() as u64));
+                    for size in map_sizes(prefixes.len()) {
+                        let map: PrefixMap<_, _> =
+                            prefixes.iter().take(size).map(|p| (*p, ())).collect();
+                        g.bench_with_input(BenchmarkId::new(ds.name(), size), &map, |b, map| {
+                            b.iter(|| addrs.iter().filter_map(|addr| map.longest_match(*addr)).count())
+                        });
+                    }
+                }
+                g.finish()
+            }
+
+            fn matches(c: &mut Criterion) {
+                let mut g = c.benchmark_group("map-matches");
+                g.measurement_time(Duration::from_secs(10));
+
+                for ds in DATA_SETS {
+                    let prefixes = ds.read();
+                    let addrs: Vec<_> = prefixes
+                        .iter()
+                        .step_by(LOOKUP_STEP)
+                        .map(|p| p.network())
+                        .collect();
+                    let map: PrefixMap<_, _> = prefixes.iter().map(|p| (*p, ())).collect();
+                    g.throughput(Throughput::Elements(addrs.len() as u64));
+                    g.bench_function(ds.name(), |b| {
+                        b.iter(|| addrs.iter().map(|addr| map.matches(*addr).count()).sum::<usize>())
+                    });
+                }
+                g.finish()
+            }
+        }
+    }
+}
+
+const LOOKUP_STEP: usize = 100;
+
+fn map_sizes(total: usize) -> impl Iterator<Item = usize> {
+    [1_000, 10_000, 100_000]
+        .into_iter()
+        .filter(move |size| *size < total)
+        .chain([total])
+}
+
+benchmarks! {
+    ipv4_prefixes: Prefix<Ipv4> => [
+        name = "AS-WOLCOMM-ipv4-prefixes", prefixes = 755053, ranges = 163330;
+        name = "AS-HURRICANE-ipv4-prefixes", prefixes = 817756, ranges = 145101;
+    ]
+}
+
+benchmarks! {
+    ipv6_prefixes: Prefix<Ipv6> => [
+        name = "AS-WOLCOMM-ipv6-prefixes", prefixes = 274714, ranges = 34740;
+        name = "AS-HURRICANE-ipv6-prefixes", prefixes = 218805, ranges = 24774;
+    ]
+}
+
+fn benches() {
+    let mut c = Criterion::default().configure_from_args();
+    ipv4_prefixes::benches(&mut c);
+    ipv6_prefixes::benches(&mut c);
+}
+
+criterion_main!(benches);
@@ -1,7 +1,11 @@
 use std::time::Duration;
 
 use criterion::{criterion_main, Criterion, Throughput};
-use ip::{concrete::PrefixSet, traits::PrefixSet as _, Ipv4, Ipv6, Prefix, PrefixRange};
+use ip::{
+    concrete::PrefixSet,
+    traits::{Afi, Prefix as _, PrefixSet as _},
+    Ipv4, Ipv6, Prefix, PrefixRange,
+};
 use itertools::Itertools;
 use utils::{data_sets, DataSet};
 
@@ -21,11 +25,14 @@ macro_rules! benchmarks {
 
             pub fn benches(mut c: &mut Criterion) {
                 construct(&mut c);
+                #[cfg(feature = "rayon")]
+                construct_parallel(&mut c);
                 iterate_prefix_ranges(&mut c);
                 iterate_prefixes(&mut c);
                 compute_intersection(&mut c);
                 compute_union(&mut c);
                 compute_difference(&mut c);
+                longest_match(&mut c);
             }
 
             fn construct(c: &mut Criterion) {
@@ -43,6 +50,25 @@ macro_rules! benchmarks {
                 g.finish()
             }
 
+            #[cfg(feature = "rayon")]
+            fn construct_parallel(c: &mut Criterion) {
+                let mut g = c.benchmark_group("construct-parallel");
+                g.measurement_time(Duration::from_secs(10));
+                g.sample_size(10);
+
+                for ds in DATA_SETS {
+                    let prefixes = ds.read();
+                    g.throughput(Throughput::Elements(prefixes.len() as u64));
+                    g.bench_function(format!("{} sequential", ds.name()), |b| {
+                        b.iter(|| { prefixes.clone().into_iter().collect::<PrefixSet<_>>() })
+                    });
+                    g.bench_function(format!("{} parallel", ds.name()), |b| {
+                        b.iter(|| PrefixSet::par_from_iter(prefixes.clone()))
+                    });
+                }
+                g.finish()
+            }
+
             fn iterate_prefix_ranges(c: &mut Criterion) {
                 let mut g = c.benchmark_group("iter-ranges");
                 g.measurement_time(Duration::from_secs(10));
@@ -82,6 +108,9 @@ macro_rules! benchmarks {
                         let name = format!("{} & {}", x.name(), y.name());
                         let s: PrefixSet<_> = x.read().into_iter().collect();
                         let t: PrefixSet<_> = y.read().into_iter().collect();
+                        g.bench_function(format!("{name} by reference"), |b| {
+                            b.iter(|| &s & &t)
+                        });
                         g.bench_function(name, |b| b.iter(|| s.clone() & t.clone()));
                     });
                 g.finish()
@@ -98,6 +127,9 @@ macro_rules! benchmarks {
                         let name = format!("{} | {}", x.name(), y.name());
                         let s: PrefixSet<_> = x.read().into_iter().collect();
                         let t: PrefixSet<_> = y.read().into_iter().collect();
+                        g.bench_function(format!("{name} by reference"), |b| {
+                            b.iter(|| &s | &t)
+                        });
                         g.bench_function(name, |b| b.iter(|| s.clone() | t.clone()));
                     });
                 g.finish()
@@ -114,12 +146,49 @@ macro_rules! benchmarks {
                         let name = format!("{} ^ {}", x.name(), y.name());
                         let s: PrefixSet<_> = x.read().into_iter().collect();
                         let t: PrefixSet<_> = y.read().into_iter().collect();
+                        g.bench_function(format!("{name} by reference"), |b| {
+                            b.iter(|| &s ^ &t)
+                        });
                         g.bench_function(name, |b| b.iter(|| s.clone() ^ t.clone()));
                     });
                 g.finish()
             }
+
+            fn longest_match(c: &mut Criterion) {
+                let mut g = c.benchmark_group("longest-match");
+                g.measurement_time(Duration::from_secs(10));
+
+                for ds in DATA_SETS {
+                    let set: PrefixSet<_> = ds.read().into_iter().collect();
+                    let addrs: Vec<_> = set.prefixes().step_by(LOOKUP_STEP).map(|p| p.network()).collect();
+                    g.throughput(Throughput::Elements(addrs.len() as u64));
+                    g.bench_function(format!("{} radix", ds.name()), |b| {
+                        b.iter(|| addrs.iter().filter_map(|addr| set.longest_match(*addr)).count())
+                    });
+                    g.bench_function(format!("{} naive", ds.name()), |b| {
+                        b.iter(|| addrs.iter().filter_map(|addr| naive_longest_match(&set, *addr)).count())
+                    });
+                }
+                g.finish()
+            }
+        }
+    }
+}
+
+const LOOKUP_STEP: usize = 100;
+
+fn naive_longest_match<A: Afi>(
+    set: &PrefixSet<A>,
+    addr: ip::concrete::Address<A>,
+) -> Option<Prefix<A>> {
+    let mut candidate = Some(Prefix::<A>::from(addr));
+    while let Some(prefix) = candidate {
+        if set.contains(prefix) {
+            return Some(prefix);
         }
+        candidate = prefix.supernet();
     }
+    None
 }
 
 benchmarks! {
@@ -0,0 +1,19 @@
+use std::env;
+use std::process::Command;
+
+// Detect whether the crate is being compiled by a nightly toolchain, so that
+// features requiring unstable language or library features can be enabled
+// only where they are available.
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(nightly)");
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let nightly = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .is_some_and(|version| version.contains("-nightly") || version.contains("-dev"));
+    if nightly {
+        println!("cargo::rustc-cfg=nightly");
+    }
+}
@@ -13,6 +13,9 @@ use crate::{
     },
 };
 
+mod range;
+pub use self::range::Range;
+
 /// Either an IPv4 or IPv6 address.
 ///
 /// # Memory Use
@@ -23,6 +26,30 @@ use crate::{
 /// As a result, users should avoid using this type in a context where only
 /// [`Address::Ipv4`] variants are expected.
 ///
+/// # Ordering
+///
+/// [`Address`] is totally ordered, so that addresses of both families can
+/// be sorted or stored in ordered collections together. All IPv4 addresses
+/// are ordered before all IPv6 addresses, and addresses of the same family
+/// are ordered numerically.
+///
+/// ``` rust
+/// use std::collections::BTreeSet;
+///
+/// use ip::{Address, Any};
+///
+/// let addrs = ["2001:db8::1", "192.0.2.2", "::1", "192.0.2.1"]
+///     .into_iter()
+///     .map(str::parse::<Address<Any>>)
+///     .collect::<Result<BTreeSet<_>, _>>()?;
+///
+/// assert_eq!(
+///     addrs.into_iter().map(|addr| addr.to_string()).collect::<Vec<_>>(),
+///     ["192.0.2.1", "192.0.2.2", "::1", "2001:db8::1"],
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+///
 /// # Examples
 ///
 /// ``` rust
@@ -34,7 +61,7 @@ use crate::{
 /// # Ok::<(), ip::Error>(())
 /// ```
 #[allow(variant_size_differences)]
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Address {
     /// IPv4 address variant.
     Ipv4(concrete::Address<Ipv4>),
@@ -81,6 +108,274 @@ impl Address {
         matches!(self, Self::Ipv6(_))
     }
 
+    /// Parse an [`Address`] from a string, where an IPv6 address may
+    /// optionally be enclosed in square brackets.
+    ///
+    /// Bracketed input is always parsed as an IPv6 address. Input without
+    /// brackets is parsed as per [`FromStr`].
+    ///
+    /// See also [`concrete::strip_port()`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is not a valid address, or if the bracketed input
+    /// is not a valid IPv6 address.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// let addr = Address::<Any>::from_bracketed("[2001:db8::1]")?;
+    /// assert!(addr.is_ipv6());
+    ///
+    /// let addr = Address::<Any>::from_bracketed("192.0.2.1")?;
+    /// assert!(addr.is_ipv4());
+    ///
+    /// assert!(Address::<Any>::from_bracketed("[192.0.2.1]").is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn from_bracketed(s: &str) -> Result<Self, Error> {
+        concrete::strip_brackets(s).map_or_else(
+            || s.parse(),
+            |inner| inner.parse::<concrete::Address<Ipv6>>().map(Self::Ipv6),
+        )
+    }
+
+    /// Parse an [`Address`] from its reverse DNS name, with the address
+    /// family determined by whether the name is within `in-addr.arpa` or
+    /// `ip6.arpa`.
+    ///
+    /// See [`concrete::Address::from_reverse_dns()`].
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`ParserError`][crate::error::Kind::ParserError] is
+    /// returned if the name is not a valid reverse DNS name of a full
+    /// address.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// assert_eq!(
+    ///     Address::<Any>::from_reverse_dns("1.2.0.192.in-addr.arpa.")?,
+    ///     "192.0.2.1".parse::<Address<Any>>()?,
+    /// );
+    /// assert!(Address::<Any>::from_reverse_dns("1.0.ip6.arpa.").is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn from_reverse_dns(name: &str) -> Result<Self, Error> {
+        match concrete::reverse_dns_afi(name)? {
+            concrete::Afi::Ipv4 => concrete::Address::from_reverse_dns(name).map(Self::Ipv4),
+            concrete::Afi::Ipv6 => concrete::Address::from_reverse_dns(name).map(Self::Ipv6),
+        }
+    }
+
+    /// Returns an iterator over every entry of the IANA Special-Purpose
+    /// Address Registry that contains `self`.
+    ///
+    /// See [`concrete::Address::special_ranges()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::SpecialPurpose, Address, Any};
+    ///
+    /// assert_eq!(
+    ///     "2001:1::1"
+    ///         .parse::<Address<Any>>()?
+    ///         .special_ranges()
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         SpecialPurpose::ProtocolAssignments,
+    ///         SpecialPurpose::PortControlProtocolAnycast,
+    ///     ],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn special_ranges(&self) -> impl Iterator<Item = concrete::SpecialPurpose> {
+        let (ipv4, ipv6) = match self {
+            Self::Ipv4(addr) => (Some(addr.special_ranges()), None),
+            Self::Ipv6(addr) => (None, Some(addr.special_ranges())),
+        };
+        ipv4.into_iter().flatten().chain(ipv6.into_iter().flatten())
+    }
+
+    /// Returns the big-endian [`Octets`] representing the value of `self`.
+    ///
+    /// See also [`concrete::Address::octets()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{any::Octets, Address, Any};
+    ///
+    /// let ipv4 = "192.0.2.1".parse::<Address<Any>>()?.octets();
+    /// let ipv6 = "2001:db8::1".parse::<Address<Any>>()?.octets();
+    ///
+    /// assert_eq!(ipv4, Octets::Ipv4([192, 0, 2, 1]));
+    /// assert_eq!(ipv4.as_ref(), &[192, 0, 2, 1]);
+    /// assert_eq!(ipv6.as_ref().len(), 16);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn octets(&self) -> Octets {
+        match self {
+            Self::Ipv4(addr) => Octets::Ipv4(addr.octets()),
+            Self::Ipv6(addr) => Octets::Ipv6(addr.octets()),
+        }
+    }
+
+    /// Returns a big-endian [`[u16; 8]`] representing the segments of an
+    /// [`Address::Ipv6`], or [`None`] for [`Address::Ipv4`] variants.
+    ///
+    /// See also [`concrete::Address::<Ipv6>::segments()`] and
+    /// [`concrete::Address::<Ipv4>::segments()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// assert_eq!(
+    ///     "2001:db8::1".parse::<Address<Any>>()?.segments(),
+    ///     Some([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]),
+    /// );
+    /// assert_eq!("192.0.2.1".parse::<Address<Any>>()?.segments(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn segments(&self) -> Option<[u16; 8]> {
+        match self {
+            Self::Ipv4(_) => None,
+            Self::Ipv6(addr) => Some(addr.segments()),
+        }
+    }
+
+    /// Returns [`true`] if this is a 6to4 IPv6 address, within `2002::/16`.
+    ///
+    /// [`Address::Ipv4`] variants always return [`false`].
+    ///
+    /// See also [`concrete::Address::<Ipv6>::is_6to4()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// assert!("2002:c000:204::1".parse::<Address<Any>>()?.is_6to4());
+    /// assert!(!"192.0.2.4".parse::<Address<Any>>()?.is_6to4());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_6to4(&self) -> bool {
+        match self {
+            Self::Ipv4(_) => false,
+            Self::Ipv6(addr) => addr.is_6to4(),
+        }
+    }
+
+    /// Returns [`true`] if this is an IPv4-mapped IPv6 address, within
+    /// `::ffff:0:0/96`.
+    ///
+    /// [`Address::Ipv4`] variants always return [`false`].
+    ///
+    /// See also [`concrete::Address::<Ipv6>::is_ipv4_mapped()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// assert!("::ffff:192.0.2.1".parse::<Address<Any>>()?.is_ipv4_mapped());
+    /// assert!(!"192.0.2.1".parse::<Address<Any>>()?.is_ipv4_mapped());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_ipv4_mapped(&self) -> bool {
+        match self {
+            Self::Ipv4(_) => false,
+            Self::Ipv6(addr) => addr.is_ipv4_mapped(),
+        }
+    }
+
+    /// Returns [`true`] if this is an IPv4-compatible IPv6 address, within
+    /// `::/96`.
+    ///
+    /// [`Address::Ipv4`] variants always return [`false`].
+    ///
+    /// See also [`concrete::Address::<Ipv6>::is_ipv4_compatible()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// assert!("::192.0.2.1".parse::<Address<Any>>()?.is_ipv4_compatible());
+    /// assert!(!"192.0.2.1".parse::<Address<Any>>()?.is_ipv4_compatible());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_ipv4_compatible(&self) -> bool {
+        match self {
+            Self::Ipv4(_) => false,
+            Self::Ipv6(addr) => addr.is_ipv4_compatible(),
+        }
+    }
+
+    /// Returns the solicited-node multicast address corresponding to `self`,
+    /// or [`None`] for [`Address::Ipv4`] variants.
+    ///
+    /// See also [`concrete::Address::<Ipv6>::solicited_node()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// assert_eq!(
+    ///     "2001:db8::1:2:3".parse::<Address<Any>>()?.solicited_node(),
+    ///     Some("ff02::1:ff02:3".parse::<Address<Any>>()?),
+    /// );
+    /// assert_eq!("192.0.2.1".parse::<Address<Any>>()?.solicited_node(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn solicited_node(&self) -> Option<Self> {
+        match self {
+            Self::Ipv4(_) => None,
+            Self::Ipv6(addr) => Some(Self::Ipv6(addr.solicited_node())),
+        }
+    }
+
+    /// Returns [`true`] if this is a solicited-node multicast IPv6 address,
+    /// within `ff02::1:ff00:0/104`.
+    ///
+    /// [`Address::Ipv4`] variants always return [`false`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any};
+    ///
+    /// assert!("ff02::1:ff02:3"
+    ///     .parse::<Address<Any>>()?
+    ///     .is_solicited_node_multicast());
+    /// assert!(!"192.0.2.1"
+    ///     .parse::<Address<Any>>()?
+    ///     .is_solicited_node_multicast());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn is_solicited_node_multicast(&self) -> bool {
+        match self {
+            Self::Ipv4(_) => false,
+            Self::Ipv6(addr) => addr.is_solicited_node_multicast(),
+        }
+    }
+
     // TODO: move to `traits::Address`
     /// Convert the address to its canonical representation.
     ///
@@ -109,6 +404,32 @@ impl Address {
             Self::Ipv6(ipv6_addr) => ipv6_addr.to_canonical(),
         }
     }
+
+    /// Convert `self` into a [`concrete::Address<A>`], returning [`None`] if
+    /// `self` is not of address family `A`.
+    ///
+    /// This is the inverse of [`concrete::Address::into_any()`]. See
+    /// [Address-families](crate#address-families) for why these methods
+    /// are provided alongside [`TryFrom`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any, Ipv4, Ipv6};
+    ///
+    /// let addr = "192.0.2.1".parse::<Address<Any>>()?;
+    ///
+    /// assert_eq!(addr.into_concrete(), Some("192.0.2.1".parse::<Address<Ipv4>>()?));
+    /// assert_eq!(addr.into_concrete::<Ipv6>(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn into_concrete<A: Afi>(self) -> Option<concrete::Address<A>>
+    where
+        concrete::Address<A>: TryFrom<Self>,
+    {
+        concrete::Address::<A>::try_from(self).ok()
+    }
 }
 
 impl traits::Address for Address {
@@ -128,6 +449,41 @@ impl traits::Address for Address {
         fn is_unicast(&self) -> bool;
         fn is_unspecified(&self) -> bool;
         fn is_unique_local(&self) -> bool;
+        fn is_unicast_link_local(&self) -> bool;
+        fn is_global_unicast(&self) -> bool;
+        #[cfg(feature = "std")]
+        fn to_reverse_dns(&self) -> std::string::String;
+    }
+}
+
+/// The big-endian byte-array representing the value of either an IPv4 or
+/// IPv6 address.
+///
+/// See [`Address::octets()`].
+#[allow(variant_size_differences)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Octets {
+    /// IPv4 address octets variant.
+    Ipv4(<Ipv4 as Afi>::Octets),
+    /// IPv6 address octets variant.
+    Ipv6(<Ipv6 as Afi>::Octets),
+}
+
+impl AsRef<[u8]> for Octets {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Ipv4(octets) => octets,
+            Self::Ipv6(octets) => octets,
+        }
+    }
+}
+
+impl From<Octets> for Address {
+    fn from(octets: Octets) -> Self {
+        match octets {
+            Octets::Ipv4(octets) => octets.into(),
+            Octets::Ipv6(octets) => octets.into(),
+        }
     }
 }
 
@@ -176,36 +532,32 @@ impl From<[u16; 8]> for Address {
     }
 }
 
-#[cfg(feature = "std")]
-impl From<std::net::Ipv4Addr> for Address {
-    fn from(addr: std::net::Ipv4Addr) -> Self {
+impl From<core::net::Ipv4Addr> for Address {
+    fn from(addr: core::net::Ipv4Addr) -> Self {
         concrete::Address::from(addr).into()
     }
 }
 
-#[cfg(feature = "std")]
-impl From<std::net::Ipv6Addr> for Address {
-    fn from(addr: std::net::Ipv6Addr) -> Self {
+impl From<core::net::Ipv6Addr> for Address {
+    fn from(addr: core::net::Ipv6Addr) -> Self {
         concrete::Address::from(addr).into()
     }
 }
 
-#[cfg(feature = "std")]
-impl From<std::net::IpAddr> for Address {
-    fn from(addr: std::net::IpAddr) -> Self {
+impl From<core::net::IpAddr> for Address {
+    fn from(addr: core::net::IpAddr) -> Self {
         match addr {
-            std::net::IpAddr::V4(addr) => addr.into(),
-            std::net::IpAddr::V6(addr) => addr.into(),
+            core::net::IpAddr::V4(addr) => addr.into(),
+            core::net::IpAddr::V6(addr) => addr.into(),
         }
     }
 }
 
-impl PartialOrd for Address {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (self, other) {
-            (Self::Ipv4(addr), Self::Ipv4(other)) => addr.partial_cmp(other),
-            (Self::Ipv6(addr), Self::Ipv6(other)) => addr.partial_cmp(other),
-            _ => None,
+impl From<Address> for core::net::IpAddr {
+    fn from(addr: Address) -> Self {
+        match addr {
+            Address::Ipv4(addr) => Self::V4(addr.into()),
+            Address::Ipv6(addr) => Self::V6(addr.into()),
         }
     }
 }
@@ -231,11 +583,7 @@ macro_rules! impl_partial_cmp {
 
             impl PartialOrd<concrete::Address<$af>> for Address {
                 fn partial_cmp(&self, other: &concrete::Address<$af>) -> Option<Ordering> {
-                    if let Self::$af(addr) = self {
-                        addr.partial_cmp(other)
-                    } else {
-                        None
-                    }
+                    self.partial_cmp(&Self::$af(*other))
                 }
             }
 
@@ -316,6 +664,13 @@ mod tests {
         }
     }
 
+    proptest! {
+        #[test]
+        fn ip_addr_round_trip(addr in any::<Address>()) {
+            assert_eq!(Address::from(core::net::IpAddr::from(addr)), addr);
+        }
+    }
+
     proptest! {
         #[test]
         fn symmetric_eq((a, b) in any::<(Address, Address)>()) {
@@ -355,6 +710,24 @@ mod tests {
     }
 
     proptest! {
+        #[test]
+        fn partial_cmp_consistent_with_cmp((a, b) in any::<(Address, Address)>()) {
+            assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+            assert_eq!(a.cmp(&b) == Ordering::Equal, a == b);
+        }
+
+        #[test]
+        fn ipv4_before_ipv6(a in any::<concrete::Address<Ipv4>>(), b in any::<concrete::Address<Ipv6>>()) {
+            assert!(Address::Ipv4(a) < Address::Ipv6(b));
+            assert!(Address::Ipv6(b) > a);
+            assert!(a < Address::Ipv6(b));
+        }
+
+        #[test]
+        fn within_family_order_is_numeric(a in any::<concrete::Address<Ipv6>>(), b in any::<concrete::Address<Ipv6>>()) {
+            assert_eq!(Address::Ipv6(a).cmp(&Address::Ipv6(b)), a.cmp(&b));
+        }
+
         #[test]
         fn dual_cmp((a, b) in any::<(Address, Address)>()) {
             assert_eq!(a.partial_cmp(&b), b.partial_cmp(&a).map(Ordering::reverse));
@@ -425,6 +798,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_bracketed_ipv6() {
+        assert_eq!(
+            Address::from_bracketed("[2001:db8::1]").unwrap(),
+            "2001:db8::1".parse::<Address>().unwrap()
+        );
+    }
+
+    #[test]
+    fn from_bracketed_ipv4_with_port() {
+        let (addr, port) = concrete::strip_port("192.0.2.1:8080");
+        assert_eq!(port, Some(8080));
+        assert_eq!(
+            Address::from_bracketed(addr).unwrap(),
+            "192.0.2.1".parse::<Address>().unwrap()
+        );
+    }
+
+    #[test]
+    fn from_bracketed_bare_ipv6_with_port_like_suffix() {
+        let (addr, port) = concrete::strip_port("2001:db8::1:8080");
+        assert_eq!(port, None);
+        assert_eq!(
+            Address::from_bracketed(addr).unwrap(),
+            "2001:db8::1:8080".parse::<Address>().unwrap()
+        );
+    }
+
+    #[test]
+    fn from_bracketed_empty_brackets() {
+        assert!(Address::from_bracketed("[]").is_err());
+    }
+
+    #[test]
+    fn from_bracketed_rejects_bracketed_ipv4() {
+        assert!(Address::from_bracketed("[192.0.2.1]").is_err());
+    }
+
+    #[test]
+    fn ipv6_6to4_is_6to4() {
+        assert!("2002:c000:204::1".parse::<Address>().unwrap().is_6to4());
+    }
+
+    #[test]
+    fn ipv6_documentation_is_not_6to4() {
+        assert!(!"2001:db8::1".parse::<Address>().unwrap().is_6to4());
+    }
+
+    #[test]
+    fn ipv4_is_not_6to4() {
+        assert!(!"192.0.2.4".parse::<Address>().unwrap().is_6to4());
+    }
+
     #[test]
     fn ipv4_broadcast_is_broadcast() {
         assert!("255.255.255.255".parse::<Address>().unwrap().is_broadcast());
@@ -808,4 +1234,96 @@ mod tests {
     fn ipv6_multicast_global_is_not_unicast_global() {
         assert!(!"ff0e::1".parse::<Address>().unwrap().is_unicast_global());
     }
+    #[test]
+    fn ipv4_is_not_ipv4_mapped_or_compatible() {
+        let addr = "192.0.2.1".parse::<Address>().unwrap();
+        assert!(!addr.is_ipv4_mapped());
+        assert!(!addr.is_ipv4_compatible());
+    }
+    #[test]
+    fn ipv6_ipv4_mapped_is_canonicalised() {
+        let addr = "::ffff:192.0.2.1".parse::<Address>().unwrap();
+        assert!(addr.is_ipv4_mapped());
+        assert!(addr.to_canonical().is_ipv4());
+    }
+    #[test]
+    fn ipv6_link_local_is_unicast_link_local() {
+        assert!("fe80::1"
+            .parse::<Address>()
+            .unwrap()
+            .is_unicast_link_local());
+    }
+    #[test]
+    fn ipv6_site_local_is_not_global_unicast() {
+        assert!(!"fec0::1".parse::<Address>().unwrap().is_global_unicast());
+    }
+    #[test]
+    fn ipv6_2000_is_global_unicast() {
+        assert!("2000::1".parse::<Address>().unwrap().is_global_unicast());
+        assert!("3fff:ffff::1"
+            .parse::<Address>()
+            .unwrap()
+            .is_global_unicast());
+    }
+    #[test]
+    fn ipv4_link_local_is_not_unicast_link_local() {
+        assert!(!"169.254.0.1"
+            .parse::<Address>()
+            .unwrap()
+            .is_unicast_link_local());
+    }
+    #[test]
+    fn octets_round_trip() {
+        for addr in ["192.0.2.1", "2001:db8::1"] {
+            let addr = addr.parse::<Address>().unwrap();
+            assert_eq!(Address::from(addr.octets()), addr);
+        }
+    }
+    #[test]
+    fn octets_are_length_tagged() {
+        let ipv4 = "192.0.2.1".parse::<Address>().unwrap().octets();
+        let ipv6 = "::ffff:192.0.2.1".parse::<Address>().unwrap().octets();
+        assert_eq!(ipv4.as_ref().len(), 4);
+        assert_eq!(ipv6.as_ref().len(), 16);
+        assert_eq!(ipv4.as_ref(), &ipv6.as_ref()[12..]);
+        assert_ne!(ipv4, ipv6);
+    }
+    #[test]
+    fn segments_round_trip() {
+        let addr = "2001:db8:f00::1".parse::<Address>().unwrap();
+        assert_eq!(addr.segments().map(Address::from), Some(addr));
+        assert_eq!("192.0.2.1".parse::<Address>().unwrap().segments(), None);
+    }
+
+    #[test]
+    fn ipv4_has_no_solicited_node() {
+        let addr = "192.0.2.1".parse::<Address>().unwrap();
+        assert_eq!(addr.solicited_node(), None);
+        assert!(!addr.is_solicited_node_multicast());
+    }
+
+    #[test]
+    fn ipv6_solicited_node_is_solicited_node_multicast() {
+        assert!("2001:db8::1:2:3"
+            .parse::<Address>()
+            .unwrap()
+            .solicited_node()
+            .unwrap()
+            .is_solicited_node_multicast());
+    }
+    #[test]
+    fn from_reverse_dns_dispatches_on_suffix() {
+        assert!(Address::from_reverse_dns("1.2.0.192.IN-ADDR.ARPA")
+            .unwrap()
+            .is_ipv4());
+        assert!(Address::from_reverse_dns(
+            "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa."
+        )
+        .unwrap()
+        .is_ipv6());
+    }
+    #[test]
+    fn from_reverse_dns_unknown_suffix() {
+        assert!(Address::from_reverse_dns("1.2.0.192.example.com.").is_err());
+    }
 }
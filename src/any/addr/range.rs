@@ -0,0 +1,265 @@
+use core::fmt;
+use core::str::FromStr;
+
+use super::{delegate, Address};
+use crate::{
+    any::Prefix,
+    concrete::{self, Ipv4, Ipv6},
+    error::Error,
+};
+
+/// Either an IPv4 or IPv6 address range.
+///
+/// See also: [`concrete::AddressRange`].
+///
+/// # Memory Use
+///
+/// Rust enums are sized to accommodate their largest variant, with smaller
+/// variants being padded to fill up any unused space.
+///
+/// As a result, users should avoid using this type in a context where only
+/// [`AddressRange::Ipv4`][Range::Ipv4] variants are expected.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{any::AddressRange, Address, Any};
+///
+/// let range = "2001:db8::1-2001:db8::ff".parse::<AddressRange>()?;
+///
+/// assert!(range.contains(&"2001:db8::10".parse::<Address<Any>>()?));
+/// assert!(!range.contains(&"192.0.2.1".parse::<Address<Any>>()?));
+/// # Ok::<(), ip::Error>(())
+/// ```
+#[allow(variant_size_differences)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Range {
+    /// IPv4 address range variant.
+    Ipv4(concrete::AddressRange<Ipv4>),
+    /// IPv6 address range variant.
+    Ipv6(concrete::AddressRange<Ipv6>),
+}
+
+impl Range {
+    /// Returns [`true`] if `addr` is contained in the range.
+    ///
+    /// Addresses of a different address family to `self` are never
+    /// contained in the range.
+    #[must_use]
+    pub fn contains(&self, addr: &Address) -> bool {
+        match (self, addr) {
+            (Self::Ipv4(range), Address::Ipv4(addr)) => range.contains(addr),
+            (Self::Ipv6(range), Address::Ipv6(addr)) => range.contains(addr),
+            _ => false,
+        }
+    }
+
+    /// Returns [`true`] if every address in `other` is contained in `self`.
+    ///
+    /// Ranges of different address families never contain one another.
+    ///
+    /// See also [`concrete::AddressRange::contains_range()`].
+    #[must_use]
+    pub fn contains_range(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Ipv4(range), Self::Ipv4(other)) => range.contains_range(other),
+            (Self::Ipv6(range), Self::Ipv6(other)) => range.contains_range(other),
+            _ => false,
+        }
+    }
+
+    /// Returns [`true`] if `self` and `other` do not overlap, but one begins
+    /// immediately after the other ends.
+    ///
+    /// Ranges of different address families are never adjacent.
+    ///
+    /// See also [`concrete::AddressRange::is_adjacent()`].
+    #[must_use]
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Ipv4(range), Self::Ipv4(other)) => range.is_adjacent(other),
+            (Self::Ipv6(range), Self::Ipv6(other)) => range.is_adjacent(other),
+            _ => false,
+        }
+    }
+
+    delegate! {
+        /// Returns the lower bound of the range.
+        ///
+        /// See also [`concrete::AddressRange::start()`].
+        #[must_use]
+        pub fn start(&self) -> Address;
+
+        /// Returns the upper bound of the range.
+        ///
+        /// See also [`concrete::AddressRange::end()`].
+        #[must_use]
+        pub fn end(&self) -> Address;
+
+        /// Returns the range of addresses contained in both `self` and
+        /// `other`, or [`None`] if they do not overlap or are of different
+        /// address families.
+        ///
+        /// See also [`concrete::AddressRange::intersection()`].
+        #[must_use]
+        pub fn intersection(&self, other: &Self) -> Option<Self>;
+
+        /// Returns the range of addresses contained in either `self` or
+        /// `other`, if `self` and `other` are of the same address family and
+        /// overlap or are adjacent.
+        ///
+        /// See also [`concrete::AddressRange::union()`].
+        #[must_use]
+        pub fn union(&self, other: &Self) -> Option<Self>;
+    }
+}
+
+impl From<concrete::AddressRange<Ipv4>> for Range {
+    fn from(range: concrete::AddressRange<Ipv4>) -> Self {
+        Self::Ipv4(range)
+    }
+}
+
+impl From<concrete::AddressRange<Ipv6>> for Range {
+    fn from(range: concrete::AddressRange<Ipv6>) -> Self {
+        Self::Ipv6(range)
+    }
+}
+
+impl From<Prefix> for Range {
+    fn from(prefix: Prefix) -> Self {
+        match prefix {
+            Prefix::Ipv4(prefix) => Self::Ipv4(prefix.into()),
+            Prefix::Ipv6(prefix) => Self::Ipv6(prefix.into()),
+        }
+    }
+}
+
+/// Parse an [`AddressRange`][Range] of either address family.
+///
+/// The input is parsed as an IPv6 range if it contains a `:`, and as an
+/// IPv4 range otherwise. See the [`FromStr`] implementation of
+/// [`concrete::AddressRange`] for the accepted syntax.
+///
+/// # Errors
+///
+/// Errors are those of the parser for the chosen address family.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{any::AddressRange, error::Kind};
+///
+/// let err = "10.0.0.9-10.0.0.1".parse::<AddressRange>().unwrap_err();
+/// assert_eq!(err.kind(), Kind::AddressRangeBounds);
+/// ```
+impl FromStr for Range {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            concrete::AddressRange::<Ipv6>::from_str(s).map(Self::from)
+        } else {
+            concrete::AddressRange::<Ipv4>::from_str(s).map(Self::from)
+        }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ipv4(range) => range.fmt(f),
+            Self::Ipv6(range) => range.fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString as _;
+
+    use super::*;
+    use crate::error::{Kind, TestResult};
+
+    #[test]
+    fn parse_either_family() -> TestResult {
+        assert!(matches!(
+            "192.0.2.1-192.0.2.10".parse::<Range>()?,
+            Range::Ipv4(_)
+        ));
+        assert!(matches!(
+            "2001:db8::1 - 2001:db8::a".parse::<Range>()?,
+            Range::Ipv6(_)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_mixed_families_fails() {
+        assert!("192.0.2.1-2001:db8::1".parse::<Range>().is_err());
+    }
+
+    #[test]
+    fn parse_keeps_ipv4_errors() {
+        let err = "10.0.0.9-10.0.0.1".parse::<Range>().unwrap_err();
+        assert_eq!(err.kind(), Kind::AddressRangeBounds);
+
+        let err = "10.0.0.1 - 10.0.0.x".parse::<Range>().unwrap_err();
+        assert_eq!(err.kind(), Kind::ParserError);
+        assert_eq!(err.position(), Some(18));
+    }
+
+    #[test]
+    fn parse_keeps_ipv6_errors() {
+        let err = "2001:db8::9-2001:db8::1".parse::<Range>().unwrap_err();
+        assert_eq!(err.kind(), Kind::AddressRangeBounds);
+    }
+
+    #[test]
+    fn display_round_trip() -> TestResult {
+        let range = "2001:db8::1-2001:db8::ff".parse::<Range>()?;
+        assert_eq!(range.to_string().parse::<Range>()?, range);
+        Ok(())
+    }
+
+    #[test]
+    fn contains_across_families_is_false() -> TestResult {
+        let range = "0.0.0.0-255.255.255.255".parse::<Range>()?;
+        assert!(range.contains(&"192.0.2.1".parse()?));
+        assert!(!range.contains(&"::ffff:192.0.2.1".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn range_ops_across_families() -> TestResult {
+        let ipv4 = "192.0.2.0-192.0.2.255".parse::<Range>()?;
+        let ipv6 = "2001:db8::-2001:db8::ff".parse::<Range>()?;
+        assert_eq!(ipv4.intersection(&ipv6), None);
+        assert_eq!(ipv4.union(&ipv6), None);
+        assert!(!ipv4.contains_range(&ipv6));
+        assert!(!ipv4.is_adjacent(&ipv6));
+        assert_eq!(ipv4.union(&ipv4), Some(ipv4.clone()));
+        Ok(())
+    }
+
+    #[test]
+    fn try_from_any() -> TestResult {
+        let range = "2001:db8::1-2001:db8::ff".parse::<Range>()?;
+        assert!(concrete::AddressRange::<Ipv6>::try_from(range.clone()).is_ok());
+        assert_eq!(
+            concrete::AddressRange::<Ipv4>::try_from(range)
+                .unwrap_err()
+                .kind(),
+            Kind::AfiMismatch
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_prefix() -> TestResult {
+        let range = Range::from("192.0.2.0/24".parse::<Prefix>()?);
+        assert_eq!(range.start(), "192.0.2.0".parse::<Address>()?);
+        assert_eq!(range.end(), "192.0.2.255".parse::<Address>()?);
+        Ok(())
+    }
+}
@@ -1,12 +1,14 @@
 use core::fmt;
+use core::str::FromStr;
 
-use super::PrefixLength;
+use super::{delegate, PrefixLength};
 use crate::{
     concrete::{
         self,
         mask_types::{Bit, Host, Net, Type},
         Ipv4, Ipv6,
     },
+    error::Error,
     traits,
 };
 
@@ -37,6 +39,43 @@ pub type Hostmask = Mask<Host>;
 /// Either an IPv4 or IPv6 bitmask.
 pub type Bitmask = Mask<Bit>;
 
+impl<T: Type> Mask<T> {
+    delegate! {
+        /// Get the number of ones in the binary representation of `self`.
+        ///
+        /// See also [`concrete::Mask::count_ones()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{traits::Prefix as _, Any, Prefix};
+        ///
+        /// let prefix = "2001:db8::/48".parse::<Prefix<Any>>()?;
+        ///
+        /// assert_eq!(prefix.netmask().count_ones(), 48);
+        /// assert_eq!(prefix.hostmask().count_ones(), 80);
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        pub fn count_ones(&self) -> u8;
+
+        /// Get the number of zeros in the binary representation of `self`.
+        ///
+        /// See also [`concrete::Mask::count_zeros()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{traits::Prefix as _, Any, Prefix};
+        ///
+        /// let prefix = "192.0.2.0/24".parse::<Prefix<Any>>()?;
+        ///
+        /// assert_eq!(prefix.netmask().count_zeros(), 8);
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        pub fn count_zeros(&self) -> u8;
+    }
+}
+
 impl<T: Type> traits::Mask for Mask<T> {}
 impl traits::Netmask for Netmask {}
 impl traits::Hostmask for Hostmask {}
@@ -72,6 +111,46 @@ impl From<PrefixLength> for Hostmask {
     }
 }
 
+/// Parse either an IPv4 or IPv6 mask from its address representation.
+///
+/// The address family of the result is determined by which of the IPv4 or
+/// IPv6 parsers accepts `s`.
+///
+/// # Errors
+///
+/// An error of kind [`ParserError`][crate::error::Kind::ParserError] is
+/// returned if `s` is not a valid mask of either address family.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{Any, Netmask};
+///
+/// assert!(matches!(
+///     "255.255.255.0".parse::<Netmask<Any>>()?,
+///     Netmask::<Any>::Ipv4(_),
+/// ));
+/// assert!(matches!(
+///     "ffff:ffff:ffff::".parse::<Netmask<Any>>()?,
+///     Netmask::<Any>::Ipv6(_),
+/// ));
+/// assert!("255.255.0.255".parse::<Netmask<Any>>().is_err());
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<T: Type> FromStr for Mask<T>
+where
+    concrete::Mask<T, Ipv4>: FromStr<Err = Error>,
+    concrete::Mask<T, Ipv6>: FromStr<Err = Error>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        concrete::Mask::<T, Ipv4>::from_str(s)
+            .map(Self::Ipv4)
+            .or_else(|_| concrete::Mask::<T, Ipv6>::from_str(s).map(Self::Ipv6))
+    }
+}
+
 impl<T: Type> fmt::Display for Mask<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -81,5 +160,49 @@ impl<T: Type> fmt::Display for Mask<T> {
     }
 }
 
-// TODO: impl FromStr
-// TODO: impl Arbitrary
+#[cfg(any(test, feature = "arbitrary"))]
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+#[cfg(any(test, feature = "arbitrary"))]
+impl<T> Arbitrary for Mask<T>
+where
+    T: Type + 'static,
+    concrete::Mask<T, Ipv4>: Arbitrary,
+    concrete::Mask<T, Ipv6>: Arbitrary,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<concrete::Mask<T, Ipv4>>().prop_map(Self::Ipv4),
+            any::<concrete::Mask<T, Ipv6>>().prop_map(Self::Ipv6),
+        ]
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString as _;
+
+    use proptest::proptest;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn netmask_display_round_trip(mask in any::<Netmask>()) {
+            assert_eq!(mask.to_string().parse::<Netmask>().unwrap(), mask);
+        }
+
+        #[test]
+        fn hostmask_display_round_trip(mask in any::<Hostmask>()) {
+            assert_eq!(mask.to_string().parse::<Hostmask>().unwrap(), mask);
+        }
+    }
+}
@@ -2,7 +2,7 @@ mod af;
 pub use self::af::{AfiClass, Any};
 
 mod addr;
-pub use self::addr::Address;
+pub use self::addr::{Address, Octets, Range as AddressRange};
 
 mod mask;
 pub use self::mask::{Bitmask, Hostmask, Mask, Netmask};
@@ -13,18 +13,245 @@ pub use self::interface::Interface;
 mod prefix;
 #[cfg(feature = "std")]
 pub use self::prefix::Set as PrefixSet;
-pub use self::prefix::{Length as PrefixLength, Prefix, Range as PrefixRange, Subprefixes};
+#[cfg(feature = "std")]
+pub use self::prefix::{map as prefix_map, Map as PrefixMap};
+pub use self::prefix::{
+    Length as PrefixLength, Prefix, Range as PrefixRange, SortablePrefix, Subprefixes,
+};
 
+/// Generate methods on an `any` enum that delegate to the same method of the
+/// wrapped `concrete` type.
+///
+/// The return value of the delegated method is converted into the return
+/// type of the generated method using [`Into`]. Where the return type is an
+/// [`Option`] or a [`Result`], the conversion is applied to the contained
+/// value.
+///
+/// The receiver may be either `&self` or `self`, and may be followed by at
+/// most one additional argument. An argument whose type is `Self`, `&Self`
+/// or `Self::Assoc` is matched against the receiver, and is passed through
+/// only if both are of the same address family. On a mis-match, a method
+/// returning a [`Result`] returns an error of kind
+/// [`AfiMismatch`][crate::error::Kind::AfiMismatch], and a method returning
+/// an [`Option`] returns [`None`]. Such an argument is rejected for any other
+/// return type, so methods such as `overlaps()` must be written out by hand,
+/// with an explicit (and documented) result for mixed address families.
+/// Arguments of any other type are passed through unchanged.
 macro_rules! delegate {
-    ( $( fn $fn:ident(&self) -> $ret_ty:ty; )* ) => {
-        $(
-            fn $fn(&self) -> $ret_ty {
-                match self {
-                    Self::Ipv4(inner) => inner.$fn().into(),
-                    Self::Ipv6(inner) => inner.$fn().into(),
+    () => {};
+    (
+        $( #[$attr:meta] )* $vis:vis fn $fn:ident(&$self:ident $($args:tt)*) -> $($rest:tt)*
+    ) => {
+        delegate!(@ret [$( #[$attr] )* $vis] $fn [&$self] $self [$($args)*] $($rest)*);
+    };
+    (
+        $( #[$attr:meta] )* $vis:vis fn $fn:ident($self:ident $($args:tt)*) -> $($rest:tt)*
+    ) => {
+        delegate!(@ret [$( #[$attr] )* $vis] $fn [$self] $self [$($args)*] $($rest)*);
+    };
+    (
+        @ret $head:tt $fn:ident $recv:tt $self:ident $args:tt
+        Option<$ret_ty:ty>; $($rest:tt)*
+    ) => {
+        delegate!(@arg $head $fn $recv $self $args [Option<$ret_ty>] map None);
+        delegate!($($rest)*);
+    };
+    (
+        @ret $head:tt $fn:ident $recv:tt $self:ident $args:tt
+        Result<$ret_ty:ty, $err_ty:ty>; $($rest:tt)*
+    ) => {
+        delegate!(
+            @arg $head $fn $recv $self $args [Result<$ret_ty, $err_ty>] map
+            (Err($crate::error::err!($crate::error::Kind::AfiMismatch)))
+        );
+        delegate!($($rest)*);
+    };
+    (
+        @ret $head:tt $fn:ident $recv:tt $self:ident $args:tt
+        $ret_ty:ty; $($rest:tt)*
+    ) => {
+        delegate!(
+            @arg $head $fn $recv $self $args [$ret_ty] into
+            (compile_error!("same-family arguments require an `Option` or `Result` return type"))
+        );
+        delegate!($($rest)*);
+    };
+    (@arg $head:tt $fn:ident $recv:tt $self:ident [] $ret:tt $conv:ident $mismatch:tt) => {
+        delegate!(@emit $head $fn $recv $self [] [] $ret $conv $mismatch);
+    };
+    (
+        @arg $head:tt $fn:ident $recv:tt $self:ident [, $arg:ident: &Self]
+        $ret:tt $conv:ident $mismatch:tt
+    ) => {
+        delegate!(@emit $head $fn $recv $self [$arg: &Self] [Self] $ret $conv $mismatch);
+    };
+    (
+        @arg $head:tt $fn:ident $recv:tt $self:ident [, $arg:ident: Self $(::$assoc:ident)?]
+        $ret:tt $conv:ident $mismatch:tt
+    ) => {
+        delegate!(
+            @emit $head $fn $recv $self [$arg: Self $(::$assoc)?] [Self $(::$assoc)?]
+            $ret $conv $mismatch
+        );
+    };
+    (
+        @arg $head:tt $fn:ident $recv:tt $self:ident [, $arg:ident: $arg_ty:ty]
+        $ret:tt $conv:ident $mismatch:tt
+    ) => {
+        delegate!(@emit $head $fn $recv $self [$arg: $arg_ty] [] $ret $conv $mismatch);
+    };
+    (
+        @emit [$($head:tt)*] $fn:ident [$($recv:tt)*] $self:ident [] []
+        [$($ret:tt)*] $conv:ident $mismatch:tt
+    ) => {
+        $($head)* fn $fn($($recv)*) -> $($ret)* {
+            match $self {
+                Self::Ipv4(inner) => delegate!(@conv $conv inner.$fn()),
+                Self::Ipv6(inner) => delegate!(@conv $conv inner.$fn()),
+            }
+        }
+    };
+    (
+        @emit [$($head:tt)*] $fn:ident [$($recv:tt)*] $self:ident [$arg:ident: $($arg_ty:tt)*] []
+        [$($ret:tt)*] $conv:ident $mismatch:tt
+    ) => {
+        $($head)* fn $fn($($recv)*, $arg: $($arg_ty)*) -> $($ret)* {
+            match $self {
+                Self::Ipv4(inner) => delegate!(@conv $conv inner.$fn($arg)),
+                Self::Ipv6(inner) => delegate!(@conv $conv inner.$fn($arg)),
+            }
+        }
+    };
+    (
+        @emit [$($head:tt)*] $fn:ident [$($recv:tt)*] $self:ident [$arg:ident: $($arg_ty:tt)*]
+        [$($family:tt)*] [$($ret:tt)*] $conv:ident $mismatch:tt
+    ) => {
+        $($head)* fn $fn($($recv)*, $arg: $($arg_ty)*) -> $($ret)* {
+            match ($self, $arg) {
+                (Self::Ipv4(inner), $($family)*::Ipv4($arg)) => {
+                    delegate!(@conv $conv inner.$fn($arg))
+                }
+                (Self::Ipv6(inner), $($family)*::Ipv6($arg)) => {
+                    delegate!(@conv $conv inner.$fn($arg))
                 }
+                _ => $mismatch,
             }
-        )*
+        }
+    };
+    (@conv into $call:expr) => {
+        $call.into()
+    };
+    (@conv map $call:expr) => {
+        $call.map(Into::into)
     };
 }
 use delegate;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        concrete::{self, Ipv4, Ipv6},
+        error::TestResult,
+        traits::{Address as _, Prefix as _, PrefixRange as _},
+    };
+
+    // Checks that the delegated methods of the `any` types give the same
+    // results as the concrete types they wrap.
+    macro_rules! matches_concrete {
+        ( $( $name:ident: $af:ty => $addr:literal, $prefix:literal, $other:literal, $range:literal; )* ) => {
+            $(
+                #[test]
+                fn $name() -> TestResult {
+                    let addr = $addr.parse::<concrete::Address<$af>>()?;
+                    let prefix = $prefix.parse::<concrete::Prefix<$af>>()?;
+                    let other = $other.parse::<concrete::Prefix<$af>>()?;
+                    let range = $range.parse::<concrete::PrefixRange<$af>>()?;
+                    let any_addr = Address::from(addr);
+                    let any_prefix = Prefix::from(prefix);
+                    let any_other = Prefix::from(other);
+                    let any_range = PrefixRange::from(range.clone());
+
+                    assert_eq!(any_addr.afi(), addr.afi());
+                    assert_eq!(any_addr.is_documentation(), addr.is_documentation());
+                    assert_eq!(any_addr.is_global(), addr.is_global());
+
+                    assert_eq!(any_prefix.network(), Address::from(prefix.network()));
+                    assert_eq!(any_prefix.broadcast(), Address::from(prefix.broadcast()));
+                    assert_eq!(any_prefix.prefix_len(), PrefixLength::from(prefix.prefix_len()));
+                    assert_eq!(any_prefix.max_prefix_len(), PrefixLength::from(prefix.max_prefix_len()));
+                    assert_eq!(any_prefix.supernet(), prefix.supernet().map(Prefix::from));
+                    assert_eq!(any_prefix.contains(&any_other), prefix.contains(&other));
+                    assert_eq!(any_prefix.overlaps(&any_other), prefix.overlaps(&other));
+                    assert_eq!(any_prefix.is_sibling(&any_other), prefix.is_sibling(&other));
+                    assert_eq!(
+                        any_prefix.intersection(&any_other),
+                        prefix.intersection(&other).map(Prefix::from)
+                    );
+                    assert!(any_prefix
+                        .subprefixes(any_other.prefix_len())?
+                        .eq(prefix.subprefixes(other.prefix_len())?.map(Prefix::from)));
+
+                    assert_eq!(any_range.prefix(), Prefix::from(range.prefix()));
+                    assert_eq!(any_range.lower(), PrefixLength::from(range.lower()));
+                    assert_eq!(any_range.upper(), PrefixLength::from(range.upper()));
+                    assert_eq!(
+                        any_range.clone().or_longer(),
+                        PrefixRange::from(range.clone().or_longer())
+                    );
+                    assert_eq!(
+                        any_range.contains_prefix(any_other),
+                        range.contains_prefix(other)
+                    );
+                    assert_eq!(any_range.contains_address(any_addr), range.contains_address(addr));
+                    Ok(())
+                }
+            )*
+        };
+    }
+
+    matches_concrete! {
+        ipv4_matches_concrete: Ipv4 => "192.0.2.1", "192.0.2.0/24", "192.0.2.128/25", "192.0.2.0/24,24,28";
+        ipv6_matches_concrete: Ipv6 => "2001:db8::1", "2001:db8::/32", "2001:db8::/36", "2001:db8::/32,32,40";
+    }
+
+    #[test]
+    fn delegated_family_mismatch() {
+        let ipv4 = "192.0.2.0/24".parse::<Prefix>().unwrap();
+        let ipv6 = "2001:db8::/32".parse::<Prefix>().unwrap();
+        assert!(!ipv4.is_sibling(&ipv6));
+        assert!(!ipv4.overlaps(&ipv6));
+        assert_eq!(ipv4.intersection(&ipv6), None);
+        assert_eq!(
+            ipv4.subprefixes(ipv6.prefix_len()).unwrap_err().kind(),
+            crate::error::Kind::AfiMismatch
+        );
+    }
+
+    #[test]
+    fn delegated_family_match() {
+        let p = "2001:db8::/32".parse::<Prefix>().unwrap();
+        let q = "2001:db9::/32".parse::<Prefix>().unwrap();
+        assert!(p.is_sibling(&q));
+        assert_eq!(p.subprefixes(p.prefix_len()).unwrap().next(), Some(p));
+        assert_eq!(
+            p.prefix_len().checked_add(96).unwrap(),
+            concrete::PrefixLength::<Ipv6>::MAX.into()
+        );
+        assert_eq!(q.supernet(), "2001:db8::/31".parse().ok());
+    }
+
+    #[test]
+    fn whole_space_sentinels() {
+        for afi in [concrete::Afi::Ipv4, concrete::Afi::Ipv6] {
+            let default = Prefix::default(afi);
+            let all = PrefixRange::all(afi);
+            assert_eq!(default.afi(), afi);
+            assert_eq!(default.prefix_len(), PrefixLength::min(afi));
+            assert_eq!(default.max_prefix_len(), PrefixLength::max(afi));
+            assert_eq!(all.prefix(), default);
+            assert_eq!(all.lower(), PrefixLength::min(afi));
+            assert_eq!(all.upper(), PrefixLength::max(afi));
+        }
+    }
+}
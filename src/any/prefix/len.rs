@@ -10,7 +10,8 @@ use proptest::{
 use super::delegate;
 use crate::{
     concrete::{self, Ipv4, Ipv6},
-    traits, Error,
+    traits::{self, Afi},
+    Error,
 };
 
 /// The length of either an IPv4 or IPv6 prefix.
@@ -25,6 +26,26 @@ use crate::{
 /// assert!(matches!(prefix.prefix_len(), PrefixLength::<Any>::Ipv6(_)));
 /// # Ok::<(), ip::Error>(())
 /// ```
+///
+/// # Parsing
+///
+/// Unlike most of the types in [`any`][crate::any], [`Length`] does not
+/// implement [`FromStr`][core::str::FromStr]: a bare length such as `"24"`
+/// is valid for both address families, so there is no way to choose a
+/// variant from the string alone.
+///
+/// Instead, parse a [`concrete::PrefixLength<A>`] for the required address
+/// family and convert it:
+///
+/// ``` rust
+/// use ip::{Any, Ipv4, Ipv6, PrefixLength};
+///
+/// let ipv4 = PrefixLength::<Any>::from("/24".parse::<PrefixLength<Ipv4>>()?);
+/// let ipv6 = PrefixLength::<Any>::from("/24".parse::<PrefixLength<Ipv6>>()?);
+///
+/// assert_ne!(ipv4, ipv6);
+/// # Ok::<(), ip::Error>(())
+/// ```
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd)]
 pub enum Length {
     /// IPv4 prefix length variant.
@@ -33,20 +54,123 @@ pub enum Length {
     Ipv6(concrete::PrefixLength<Ipv6>),
 }
 
-impl traits::PrefixLength for Length {
-    fn increment(self) -> Result<Self, Error> {
-        match self {
-            Self::Ipv4(length) => length.increment().map(Self::from),
-            Self::Ipv6(length) => length.increment().map(Self::from),
+impl Length {
+    /// Returns the minimum [`Length`] of the address family `afi`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::Afi, Any, PrefixLength};
+    ///
+    /// assert_eq!(PrefixLength::<Any>::min(Afi::Ipv6).to_string(), "0");
+    /// ```
+    #[must_use]
+    pub const fn min(afi: concrete::Afi) -> Self {
+        match afi {
+            concrete::Afi::Ipv4 => Self::Ipv4(concrete::PrefixLength::MIN),
+            concrete::Afi::Ipv6 => Self::Ipv6(concrete::PrefixLength::MIN),
         }
     }
 
-    fn decrement(self) -> Result<Self, Error> {
-        match self {
-            Self::Ipv4(length) => length.decrement().map(Self::from),
-            Self::Ipv6(length) => length.decrement().map(Self::from),
+    /// Returns the maximum [`Length`] of the address family `afi`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::Afi, Any, PrefixLength};
+    ///
+    /// assert_eq!(PrefixLength::<Any>::max(Afi::Ipv4).to_string(), "32");
+    /// assert_eq!(PrefixLength::<Any>::max(Afi::Ipv6).to_string(), "128");
+    /// ```
+    #[must_use]
+    pub const fn max(afi: concrete::Afi) -> Self {
+        match afi {
+            concrete::Afi::Ipv4 => Self::Ipv4(concrete::PrefixLength::MAX),
+            concrete::Afi::Ipv6 => Self::Ipv6(concrete::PrefixLength::MAX),
         }
     }
+
+    delegate! {
+        /// Add `n` to `self`, within the bounds of the address family of
+        /// `self`.
+        ///
+        /// See also [`concrete::PrefixLength::checked_add()`].
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`PrefixLength`][crate::error::Kind::PrefixLength]
+        /// is returned if the result would exceed the maximum prefix length of
+        /// the address family.
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{traits::Prefix as _, Any, Prefix};
+        ///
+        /// let ipv4 = "192.0.2.0/24".parse::<Prefix<Any>>()?.prefix_len();
+        /// let ipv6 = "2001:db8::/24".parse::<Prefix<Any>>()?.prefix_len();
+        ///
+        /// assert!(ipv4.checked_add(16).is_err());
+        /// assert!(ipv6.checked_add(16).is_ok());
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        pub fn checked_add(self, n: u8) -> Result<Self, Error>;
+
+        /// Subtract `n` from `self`.
+        ///
+        /// See also [`concrete::PrefixLength::checked_sub()`].
+        ///
+        /// # Errors
+        ///
+        /// An error of kind [`PrefixLength`][crate::error::Kind::PrefixLength]
+        /// is returned if the result would be less than zero.
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{traits::Prefix as _, Any, Prefix};
+        ///
+        /// let length = "2001:db8::/32".parse::<Prefix<Any>>()?.prefix_len();
+        ///
+        /// assert_eq!(length.checked_sub(8)?.to_string(), "24");
+        /// assert!(length.checked_sub(33).is_err());
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        pub fn checked_sub(self, n: u8) -> Result<Self, Error>;
+    }
+
+    /// Convert `self` into a [`concrete::PrefixLength<A>`], returning [`None`] if
+    /// `self` is not of address family `A`.
+    ///
+    /// This is the inverse of [`concrete::PrefixLength::into_any()`]. See
+    /// [Address-families](crate#address-families) for why these methods
+    /// are provided alongside [`TryFrom`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{traits::Prefix as _, Any, Ipv4, Ipv6, Prefix, PrefixLength};
+    ///
+    /// let length = "2001:db8::/48".parse::<Prefix<Any>>()?.prefix_len();
+    ///
+    /// assert_eq!(length.into_concrete(), Some(PrefixLength::<Ipv6>::from_primitive(48)?));
+    /// assert_eq!(length.into_concrete::<Ipv4>(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn into_concrete<A: Afi>(self) -> Option<concrete::PrefixLength<A>>
+    where
+        concrete::PrefixLength<A>: TryFrom<Self>,
+    {
+        concrete::PrefixLength::<A>::try_from(self).ok()
+    }
+}
+
+impl traits::PrefixLength for Length {
+    delegate! {
+        fn increment(self) -> Result<Self, Error>;
+        fn decrement(self) -> Result<Self, Error>;
+    }
 }
 
 impl From<concrete::PrefixLength<Ipv4>> for Length {
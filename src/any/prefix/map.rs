@@ -0,0 +1,615 @@
+//! Types associated with [`any::PrefixMap<V>`][Map].
+use core::iter::FusedIterator;
+
+use super::{Address, Prefix};
+use crate::concrete::{self, prefix_map, Ipv4, Ipv6};
+
+/// A map from mixed IPv4 and IPv6 prefixes to values of type `V`.
+///
+/// This holds a [`concrete::PrefixMap`] for each address family, and
+/// dispatches each operation to the map for the family of its [`Prefix`].
+/// As for the concrete maps, prefixes are never aggregated.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{any::PrefixMap, Any, Prefix};
+///
+/// let mut map = PrefixMap::<u32>::new();
+/// map.insert("2001:db8::/32".parse::<Prefix<Any>>()?, 64496);
+/// map.insert("192.0.2.0/24".parse::<Prefix<Any>>()?, 64497);
+/// map.insert("192.0.2.0/25".parse::<Prefix<Any>>()?, 64498);
+///
+/// assert_eq!(map.get("192.0.2.0/24".parse()?), Some(&64497));
+/// assert_eq!(map.len(), 3);
+/// # Ok::<(), ip::Error>(())
+/// ```
+///
+/// Iteration yields all IPv4 entries, in prefix order, before any IPv6
+/// entries:
+///
+/// ``` rust
+/// # use ip::{any::PrefixMap, Any, Prefix};
+/// let map = ["2001:db8::/32", "192.0.2.0/25", "192.0.2.0/24"]
+///     .into_iter()
+///     .map(|s| Ok((s.parse::<Prefix<Any>>()?, s.len())))
+///     .collect::<Result<PrefixMap<_>, ip::Error>>()?;
+/// assert_eq!(
+///     map.keys().map(|p| p.to_string()).collect::<Vec<_>>(),
+///     ["192.0.2.0/24", "192.0.2.0/25", "2001:db8::/32"],
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Map<V> {
+    ipv4: concrete::PrefixMap<Ipv4, V>,
+    ipv6: concrete::PrefixMap<Ipv6, V>,
+}
+
+impl<V> Map<V> {
+    /// Construct a new, empty [`PrefixMap<V>`][Self].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ipv4: concrete::PrefixMap::new(),
+            ipv6: concrete::PrefixMap::new(),
+        }
+    }
+
+    /// Get the number of entries in `self`, across both address families.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.ipv4.len() + self.ipv6.len()
+    }
+
+    /// Returns [`true`] if `self` contains no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ipv4.is_empty() && self.ipv6.is_empty()
+    }
+
+    /// Remove all entries from `self`.
+    pub fn clear(&mut self) {
+        self.ipv4.clear();
+        self.ipv6.clear();
+    }
+
+    /// Insert `value` into `self` at `prefix`, returning the old value
+    /// associated with `prefix`, if any.
+    ///
+    /// See [`concrete::PrefixMap::insert()`].
+    pub fn insert(&mut self, prefix: Prefix, value: V) -> Option<V> {
+        match prefix {
+            Prefix::Ipv4(prefix) => self.ipv4.insert(prefix, value),
+            Prefix::Ipv6(prefix) => self.ipv6.insert(prefix, value),
+        }
+    }
+
+    /// Get a reference to the value associated with `prefix`, if any.
+    ///
+    /// See [`concrete::PrefixMap::get()`].
+    #[must_use]
+    pub fn get(&self, prefix: Prefix) -> Option<&V> {
+        match prefix {
+            Prefix::Ipv4(prefix) => self.ipv4.get(prefix),
+            Prefix::Ipv6(prefix) => self.ipv6.get(prefix),
+        }
+    }
+
+    /// Get a mutable reference to the value associated with `prefix`, if
+    /// any.
+    ///
+    /// See [`concrete::PrefixMap::get_mut()`].
+    pub fn get_mut(&mut self, prefix: Prefix) -> Option<&mut V> {
+        match prefix {
+            Prefix::Ipv4(prefix) => self.ipv4.get_mut(prefix),
+            Prefix::Ipv6(prefix) => self.ipv6.get_mut(prefix),
+        }
+    }
+
+    /// Returns [`true`] if `self` contains a value for `prefix`.
+    #[must_use]
+    pub fn contains_key(&self, prefix: Prefix) -> bool {
+        self.get(prefix).is_some()
+    }
+
+    /// Remove the value associated with `prefix` from `self`, returning it
+    /// if it was present.
+    ///
+    /// See [`concrete::PrefixMap::remove()`].
+    pub fn remove(&mut self, prefix: Prefix) -> Option<V> {
+        match prefix {
+            Prefix::Ipv4(prefix) => self.ipv4.remove(prefix),
+            Prefix::Ipv6(prefix) => self.ipv6.remove(prefix),
+        }
+    }
+
+    /// Get the [`Entry`] for `prefix`, for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{any::PrefixMap, Any, Prefix};
+    /// let mut map = PrefixMap::<Vec<&str>>::new();
+    /// for (prefix, peer) in [
+    ///     ("192.0.2.0/24", "a"),
+    ///     ("2001:db8::/32", "a"),
+    ///     ("192.0.2.0/24", "b"),
+    /// ] {
+    ///     map.entry(prefix.parse()?).or_default().push(peer);
+    /// }
+    /// assert_eq!(map.get("192.0.2.0/24".parse()?), Some(&vec!["a", "b"]));
+    /// assert_eq!(map.get("2001:db8::/32".parse()?), Some(&vec!["a"]));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn entry(&mut self, prefix: Prefix) -> Entry<'_, V> {
+        match prefix {
+            Prefix::Ipv4(prefix) => Entry::Ipv4(self.ipv4.entry(prefix)),
+            Prefix::Ipv6(prefix) => Entry::Ipv6(self.ipv6.entry(prefix)),
+        }
+    }
+
+    /// Get an iterator over the entries of `self`.
+    ///
+    /// All IPv4 entries are yielded, in prefix order, before any IPv6
+    /// entries.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter {
+            ipv4: self.ipv4.iter(),
+            ipv6: self.ipv6.iter(),
+        }
+    }
+
+    /// Get an iterator over the entries of `self`, with mutable references
+    /// to the values.
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut {
+            ipv4: self.ipv4.iter_mut(),
+            ipv6: self.ipv6.iter_mut(),
+        }
+    }
+
+    /// Get an iterator over the prefixes of `self`.
+    #[must_use]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = Prefix> + '_ {
+        self.iter().map(|(prefix, _)| prefix)
+    }
+
+    /// Get an iterator over the values of `self`.
+    #[must_use]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Find the entry for the longest prefix in `self` that contains `addr`,
+    /// if any.
+    ///
+    /// See [`concrete::PrefixMap::longest_match()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{any::PrefixMap, Address, Any, Prefix};
+    /// let map = PrefixMap::from_iter([
+    ///     ("0.0.0.0/0".parse::<Prefix<Any>>()?, 'a'),
+    ///     ("2001:db8::/32".parse()?, 'b'),
+    /// ]);
+    /// assert_eq!(
+    ///     map.longest_match("2001:db8::1".parse::<Address<Any>>()?),
+    ///     Some(("2001:db8::/32".parse()?, &'b')),
+    /// );
+    /// assert_eq!(map.longest_match("2001:db9::1".parse()?), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn longest_match(&self, addr: Address) -> Option<(Prefix, &V)> {
+        match addr {
+            Address::Ipv4(addr) => self
+                .ipv4
+                .longest_match(addr)
+                .map(|(prefix, value)| (Prefix::Ipv4(prefix), value)),
+            Address::Ipv6(addr) => self
+                .ipv6
+                .longest_match(addr)
+                .map(|(prefix, value)| (Prefix::Ipv6(prefix), value)),
+        }
+    }
+
+    /// Get an iterator over the entries for all of the prefixes in `self`
+    /// that contain `addr`, from shortest to longest.
+    ///
+    /// See [`concrete::PrefixMap::matches()`].
+    pub fn matches(&self, addr: Address) -> impl Iterator<Item = (Prefix, &V)> + '_ {
+        let (ipv4, ipv6) = match addr {
+            Address::Ipv4(addr) => (Some(self.ipv4.matches(addr)), None),
+            Address::Ipv6(addr) => (None, Some(self.ipv6.matches(addr))),
+        };
+        ipv4.into_iter()
+            .flatten()
+            .map(|(prefix, value)| (Prefix::Ipv4(prefix), value))
+            .chain(
+                ipv6.into_iter()
+                    .flatten()
+                    .map(|(prefix, value)| (Prefix::Ipv6(prefix), value)),
+            )
+    }
+
+    /// Get an iterator over the entries for all of the prefixes in `self`
+    /// that are covered by `cover`, including `cover` itself.
+    ///
+    /// See [`concrete::PrefixMap::covered_by()`].
+    pub fn covered_by(&self, cover: Prefix) -> impl Iterator<Item = (Prefix, &V)> + '_ {
+        let (ipv4, ipv6) = match cover {
+            Prefix::Ipv4(cover) => (Some(self.ipv4.covered_by(cover)), None),
+            Prefix::Ipv6(cover) => (None, Some(self.ipv6.covered_by(cover))),
+        };
+        ipv4.into_iter()
+            .flatten()
+            .map(|(prefix, value)| (Prefix::Ipv4(prefix), value))
+            .chain(
+                ipv6.into_iter()
+                    .flatten()
+                    .map(|(prefix, value)| (Prefix::Ipv6(prefix), value)),
+            )
+    }
+
+    /// Split `self` into its address-family partitions.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{any::PrefixMap, Any, Prefix};
+    /// let map = PrefixMap::from_iter([
+    ///     ("192.0.2.0/24".parse::<Prefix<Any>>()?, 'a'),
+    ///     ("2001:db8::/32".parse()?, 'b'),
+    /// ]);
+    /// let (ipv4, ipv6) = map.partition();
+    /// assert_eq!(ipv4.values().collect::<Vec<_>>(), [&'a']);
+    /// assert_eq!(ipv6.values().collect::<Vec<_>>(), [&'b']);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn partition(self) -> (concrete::PrefixMap<Ipv4, V>, concrete::PrefixMap<Ipv6, V>) {
+        (self.ipv4, self.ipv6)
+    }
+
+    /// Borrow the address-family partitions of `self`.
+    #[must_use]
+    pub const fn as_partitions(
+        &self,
+    ) -> (&concrete::PrefixMap<Ipv4, V>, &concrete::PrefixMap<Ipv6, V>) {
+        (&self.ipv4, &self.ipv6)
+    }
+
+    /// Mutably borrow the address-family partitions of `self`.
+    #[must_use]
+    pub const fn as_mut_partitions(
+        &mut self,
+    ) -> (
+        &mut concrete::PrefixMap<Ipv4, V>,
+        &mut concrete::PrefixMap<Ipv6, V>,
+    ) {
+        (&mut self.ipv4, &mut self.ipv6)
+    }
+}
+
+impl<V> Default for Map<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> From<concrete::PrefixMap<Ipv4, V>> for Map<V> {
+    fn from(value: concrete::PrefixMap<Ipv4, V>) -> Self {
+        Self {
+            ipv4: value,
+            ipv6: concrete::PrefixMap::new(),
+        }
+    }
+}
+
+impl<V> From<concrete::PrefixMap<Ipv6, V>> for Map<V> {
+    fn from(value: concrete::PrefixMap<Ipv6, V>) -> Self {
+        Self {
+            ipv4: concrete::PrefixMap::new(),
+            ipv6: value,
+        }
+    }
+}
+
+impl<V> Extend<(Prefix, V)> for Map<V> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = (Prefix, V)>,
+    {
+        iter.into_iter().for_each(|(prefix, value)| {
+            _ = self.insert(prefix, value);
+        });
+    }
+}
+
+impl<V> FromIterator<(Prefix, V)> for Map<V> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Prefix, V)>,
+    {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+/// A view into a single entry of a [`PrefixMap<V>`][Map], wrapping the
+/// [`Entry`][prefix_map::Entry] of the map for the address family of its
+/// prefix.
+///
+/// Returned by [`PrefixMap::entry()`][Map::entry].
+#[derive(Debug)]
+pub enum Entry<'a, V> {
+    /// An entry in the IPv4 partition of the map.
+    Ipv4(prefix_map::Entry<'a, Ipv4, V>),
+    /// An entry in the IPv6 partition of the map.
+    Ipv6(prefix_map::Entry<'a, Ipv6, V>),
+}
+
+impl<'a, V> Entry<'a, V> {
+    /// Get the prefix of this entry.
+    #[must_use]
+    pub const fn key(&self) -> Prefix {
+        match self {
+            Self::Ipv4(entry) => Prefix::Ipv4(*entry.key()),
+            Self::Ipv6(entry) => Prefix::Ipv6(*entry.key()),
+        }
+    }
+
+    /// Insert `default` if the entry is vacant, and return a mutable
+    /// reference to the value of the entry.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Self::Ipv4(entry) => entry.or_insert(default),
+            Self::Ipv6(entry) => entry.or_insert(default),
+        }
+    }
+
+    /// Insert the result of calling `f` if the entry is vacant, and return a
+    /// mutable reference to the value of the entry.
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Self::Ipv4(entry) => entry.or_insert_with(f),
+            Self::Ipv6(entry) => entry.or_insert_with(f),
+        }
+    }
+
+    /// Insert [`V::default()`][Default::default] if the entry is vacant,
+    /// and return a mutable reference to the value of the entry.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Call `f` with a mutable reference to the value of the entry if it is
+    /// occupied, leaving a vacant entry untouched.
+    #[must_use]
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Self::Ipv4(entry) => Self::Ipv4(entry.and_modify(f)),
+            Self::Ipv6(entry) => Self::Ipv6(entry.and_modify(f)),
+        }
+    }
+}
+
+/// Non-consuming iterator returned by [`PrefixMap<V>::iter()`][Map::iter].
+#[derive(Clone, Debug)]
+pub struct Iter<'a, V> {
+    ipv4: prefix_map::Iter<'a, Ipv4, V>,
+    ipv6: prefix_map::Iter<'a, Ipv6, V>,
+}
+
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Prefix, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ipv4
+            .next()
+            .map(|(prefix, value)| (Prefix::Ipv4(prefix), value))
+            .or_else(|| {
+                self.ipv6
+                    .next()
+                    .map(|(prefix, value)| (Prefix::Ipv6(prefix), value))
+            })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<V> ExactSizeIterator for Iter<'_, V> {
+    fn len(&self) -> usize {
+        self.ipv4.len() + self.ipv6.len()
+    }
+}
+
+impl<V> FusedIterator for Iter<'_, V> {}
+
+/// Mutable iterator returned by
+/// [`PrefixMap<V>::iter_mut()`][Map::iter_mut].
+#[derive(Debug)]
+pub struct IterMut<'a, V> {
+    ipv4: prefix_map::IterMut<'a, Ipv4, V>,
+    ipv6: prefix_map::IterMut<'a, Ipv6, V>,
+}
+
+impl<'a, V> Iterator for IterMut<'a, V> {
+    type Item = (Prefix, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ipv4
+            .next()
+            .map(|(prefix, value)| (Prefix::Ipv4(prefix), value))
+            .or_else(|| {
+                self.ipv6
+                    .next()
+                    .map(|(prefix, value)| (Prefix::Ipv6(prefix), value))
+            })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<V> ExactSizeIterator for IterMut<'_, V> {
+    fn len(&self) -> usize {
+        self.ipv4.len() + self.ipv6.len()
+    }
+}
+
+impl<V> FusedIterator for IterMut<'_, V> {}
+
+/// Consuming iterator returned by [`PrefixMap<V>::into_iter()`][Map].
+#[derive(Debug)]
+pub struct IntoIter<V> {
+    ipv4: prefix_map::IntoIter<Ipv4, V>,
+    ipv6: prefix_map::IntoIter<Ipv6, V>,
+}
+
+impl<V> Iterator for IntoIter<V> {
+    type Item = (Prefix, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ipv4
+            .next()
+            .map(|(prefix, value)| (Prefix::Ipv4(prefix), value))
+            .or_else(|| {
+                self.ipv6
+                    .next()
+                    .map(|(prefix, value)| (Prefix::Ipv6(prefix), value))
+            })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<V> ExactSizeIterator for IntoIter<V> {
+    fn len(&self) -> usize {
+        self.ipv4.len() + self.ipv6.len()
+    }
+}
+
+impl<V> FusedIterator for IntoIter<V> {}
+
+/// Borrow a [`PrefixMap<V>`][Map], iterating over its entries.
+///
+/// This is equivalent to [`PrefixMap::iter()`][Map::iter].
+impl<'a, V> IntoIterator for &'a Map<V> {
+    type Item = (Prefix, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mutably borrow a [`PrefixMap<V>`][Map], iterating over its entries.
+///
+/// This is equivalent to [`PrefixMap::iter_mut()`][Map::iter_mut].
+impl<'a, V> IntoIterator for &'a mut Map<V> {
+    type Item = (Prefix, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Consume a [`PrefixMap<V>`][Map], iterating over its entries.
+///
+/// All IPv4 entries are yielded before any IPv6 entries.
+impl<V> IntoIterator for Map<V> {
+    type Item = (Prefix, V);
+    type IntoIter = IntoIter<V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            ipv4: self.ipv4.into_iter(),
+            ipv6: self.ipv6.into_iter(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TestResult;
+
+    #[test]
+    fn families_are_partitioned() -> TestResult {
+        let mut map = Map::new();
+        assert_eq!(map.insert("0.0.0.0/0".parse()?, 4), None);
+        assert_eq!(map.insert("::/0".parse()?, 6), None);
+        assert_eq!(map.insert("::/0".parse()?, 66), Some(6));
+        let (ipv4, ipv6) = map.as_partitions();
+        assert_eq!(ipv4.get("0.0.0.0/0".parse()?), Some(&4));
+        assert_eq!(ipv6.get("::/0".parse()?), Some(&66));
+        assert_eq!(map.remove("0.0.0.0/0".parse()?), Some(4));
+        assert_eq!(map.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn iter_mut_reaches_both_families() -> TestResult {
+        let mut map = Map::from_iter([("192.0.2.0/24".parse()?, 1), ("2001:db8::/32".parse()?, 2)]);
+        map.iter_mut().for_each(|(_, value)| *value += 10);
+        assert_eq!(
+            map.into_iter().collect::<std::vec::Vec<_>>(),
+            [
+                ("192.0.2.0/24".parse()?, 11),
+                ("2001:db8::/32".parse()?, 12)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn lookups_stay_within_family() -> TestResult {
+        let map = Map::from_iter([("::/0".parse()?, 6), ("0.0.0.0/0".parse()?, 4)]);
+        assert_eq!(
+            map.matches("192.0.2.1".parse()?)
+                .collect::<std::vec::Vec<_>>(),
+            [("0.0.0.0/0".parse()?, &4)]
+        );
+        assert_eq!(map.covered_by("::/0".parse()?).count(), 1);
+        assert_eq!(map.covered_by("::/1".parse()?).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn entry_dispatches_by_family() -> TestResult {
+        let mut map = Map::<u8>::new();
+        let prefix: Prefix = "2001:db8::/32".parse()?;
+        assert_eq!(map.entry(prefix).key(), prefix);
+        *map.entry(prefix).and_modify(|v| *v = 0).or_insert(1) += 1;
+        assert_eq!(
+            map.as_partitions().1.get("2001:db8::/32".parse()?),
+            Some(&2)
+        );
+        assert!(map.as_partitions().0.is_empty());
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use core::cmp::Ordering;
 use core::fmt;
 use core::str::FromStr;
 
@@ -8,11 +9,11 @@ use proptest::{
     strategy::{BoxedStrategy, Strategy},
 };
 
-use super::{delegate, Address, Hostmask, Netmask};
+use super::{delegate, Address, Hostmask, Netmask, Octets};
 use crate::{
     concrete::{self, Ipv4, Ipv6},
-    error::{err, Error, Kind},
-    traits,
+    error::Error,
+    traits::{self, Afi},
 };
 
 mod len;
@@ -21,11 +22,19 @@ pub use self::len::Length;
 mod range;
 pub use self::range::Range;
 
+#[cfg(feature = "std")]
+pub mod map;
+#[cfg(feature = "std")]
+pub use self::map::Map;
+
 #[cfg(feature = "std")]
 mod set;
 #[cfg(feature = "std")]
 pub use self::set::Set;
 
+mod sortable;
+pub use self::sortable::SortablePrefix;
+
 mod subprefixes;
 pub use self::subprefixes::Subprefixes;
 
@@ -39,6 +48,15 @@ pub use self::subprefixes::Subprefixes;
 /// As a result, users should avoid using this type in a context where only
 /// [`Prefix::Ipv4`] variants are expected.
 ///
+/// # Ordering
+///
+/// The [`PartialOrd`] implementation orders all IPv4 prefixes before all
+/// IPv6 prefixes, and prefixes of the same address family by containment.
+/// Since prefixes of the same address family that do not overlap are not
+/// comparable, [`Prefix`] does not implement [`Ord`]. Use
+/// [`Prefix::cmp_canonical()`] to sort prefixes of either address family,
+/// or [`SortablePrefix`] to key ordered collections.
+///
 /// # Examples
 ///
 /// ``` rust
@@ -61,6 +79,329 @@ pub enum Prefix {
     Ipv6(concrete::Prefix<Ipv6>),
 }
 
+impl Prefix {
+    /// Returns the "default" [`Prefix`] containing all addresses of the
+    /// address family `afi`.
+    ///
+    /// See also [`concrete::Prefix::DEFAULT`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::Afi, traits::Prefix as _, Any, Prefix};
+    ///
+    /// let default = Prefix::<Any>::default(Afi::Ipv4);
+    ///
+    /// assert_eq!(default, "0.0.0.0/0".parse::<Prefix<Any>>()?);
+    /// assert!(default.contains(&"192.0.2.0/24".parse::<Prefix<Any>>()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn default(afi: concrete::Afi) -> Self {
+        match afi {
+            concrete::Afi::Ipv4 => Self::Ipv4(concrete::Prefix::DEFAULT),
+            concrete::Afi::Ipv6 => Self::Ipv6(concrete::Prefix::DEFAULT),
+        }
+    }
+
+    /// Parse a [`Prefix`] from the name of a reverse DNS zone, with the
+    /// address family determined by whether the name is within
+    /// `in-addr.arpa` or `ip6.arpa`.
+    ///
+    /// See [`concrete::Prefix::from_reverse_dns()`].
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`ParserError`][crate::error::Kind::ParserError] is returned if
+    /// the name is not a valid reverse DNS name.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Prefix};
+    ///
+    /// assert_eq!(
+    ///     Prefix::<Any>::from_reverse_dns("8.b.d.0.1.0.0.2.ip6.arpa.")?,
+    ///     "2001:db8::/32".parse::<Prefix<Any>>()?,
+    /// );
+    /// assert_eq!(
+    ///     Prefix::<Any>::from_reverse_dns("in-addr.arpa")?,
+    ///     "0.0.0.0/0".parse::<Prefix<Any>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn from_reverse_dns(name: &str) -> Result<Self, Error> {
+        match concrete::reverse_dns_afi(name)? {
+            concrete::Afi::Ipv4 => concrete::Prefix::from_reverse_dns(name).map(Self::Ipv4),
+            concrete::Afi::Ipv6 => concrete::Prefix::from_reverse_dns(name).map(Self::Ipv6),
+        }
+    }
+
+    /// Returns the big-endian [`Octets`] representing the network address of
+    /// `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Prefix};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.0/24".parse::<Prefix<Any>>()?.network_octets().as_ref(),
+    ///     &[192, 0, 2, 0],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn network_octets(&self) -> Octets {
+        traits::Prefix::network(self).octets()
+    }
+
+    /// Returns the longest [`Prefix`] that contains both `self` and `other`,
+    /// or [`None`] if `self` and `other` are of different address families.
+    ///
+    /// See also [`concrete::Prefix::common()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Prefix};
+    ///
+    /// let p = "2001:db8:f00::/48".parse::<Prefix<Any>>()?;
+    ///
+    /// assert_eq!(
+    ///     p.common(&"2001:db8:f01::/48".parse()?),
+    ///     Some("2001:db8:f00::/47".parse::<Prefix<Any>>()?),
+    /// );
+    /// assert_eq!(p.common(&"192.0.2.0/24".parse()?), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn common(&self, other: &Self) -> Option<Self> {
+        match (self, other) {
+            (Self::Ipv4(prefix), Self::Ipv4(other)) => Some(Self::Ipv4(prefix.common(other))),
+            (Self::Ipv6(prefix), Self::Ipv6(other)) => Some(Self::Ipv6(prefix.common(other))),
+            _ => None,
+        }
+    }
+
+    /// Returns [`true`] if `self` and `other` have any addresses in common.
+    ///
+    /// Prefixes of different address families never overlap.
+    ///
+    /// See also [`concrete::Prefix::overlaps()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Prefix};
+    ///
+    /// let p = "2001:db8::/32".parse::<Prefix<Any>>()?;
+    ///
+    /// assert!(p.overlaps(&"2001:db8:f00::/48".parse()?));
+    /// assert!(!p.overlaps(&"192.0.2.0/24".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Ipv4(prefix), Self::Ipv4(other)) => prefix.overlaps(other),
+            (Self::Ipv6(prefix), Self::Ipv6(other)) => prefix.overlaps(other),
+            _ => false,
+        }
+    }
+
+    delegate! {
+        /// Returns the more specific of `self` and `other` if they overlap, or
+        /// [`None`] if they are disjoint or of different address families.
+        ///
+        /// See also [`concrete::Prefix::intersection()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{Any, Prefix};
+        ///
+        /// let p = "192.0.0.0/16".parse::<Prefix<Any>>()?;
+        /// let q = "192.0.2.0/24".parse::<Prefix<Any>>()?;
+        ///
+        /// assert_eq!(p.intersection(&q), Some(q));
+        /// assert_eq!(p.intersection(&"2001:db8::/32".parse()?), None);
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        #[must_use]
+        pub fn intersection(&self, other: &Self) -> Option<Self>;
+
+        /// Returns the number of leading bits of `self` that identify the
+        /// network.
+        ///
+        /// See also [`concrete::Prefix::network_bits()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{Any, Prefix};
+        ///
+        /// assert_eq!("192.0.2.0/24".parse::<Prefix<Any>>()?.network_bits(), 24);
+        /// assert_eq!("2001:db8::/32".parse::<Prefix<Any>>()?.network_bits(), 32);
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        #[must_use]
+        pub fn network_bits(&self) -> u8;
+
+        /// Returns the number of trailing bits of `self` that identify a host
+        /// within the network.
+        ///
+        /// See also [`concrete::Prefix::host_bits()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{Any, Prefix};
+        ///
+        /// assert_eq!("192.0.2.0/24".parse::<Prefix<Any>>()?.host_bits(), 8);
+        /// assert_eq!("2001:db8::/32".parse::<Prefix<Any>>()?.host_bits(), 96);
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        #[must_use]
+        pub fn host_bits(&self) -> u8;
+
+        /// Returns the total number of addresses covered by `self`,
+        /// including the network and broadcast addresses.
+        ///
+        /// See also [`concrete::Prefix::num_addresses()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{Any, Prefix};
+        ///
+        /// assert_eq!("192.0.2.0/24".parse::<Prefix<Any>>()?.num_addresses(), 256);
+        /// assert_eq!("::/0".parse::<Prefix<Any>>()?.num_addresses(), u128::MAX);
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        #[must_use]
+        pub fn num_addresses(&self) -> u128;
+
+        /// Returns [`true`] if `self` contains exactly one address.
+        ///
+        /// See also [`concrete::Prefix::is_host()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{Any, Prefix};
+        ///
+        /// assert!("192.0.2.1/32".parse::<Prefix<Any>>()?.is_host());
+        /// assert!("2001:db8::1/128".parse::<Prefix<Any>>()?.is_host());
+        /// assert!(!"2001:db8::/32".parse::<Prefix<Any>>()?.is_host());
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        #[must_use]
+        pub fn is_host(&self) -> bool;
+
+        /// Returns an iterator over the equal-sized subprefixes of `self`
+        /// obtained by splitting it into at least `count` subnets.
+        ///
+        /// See also [`concrete::Prefix::subnets()`].
+        ///
+        /// # Errors
+        ///
+        /// An [`Error`] is returned if `self` does not have enough host bits
+        /// to be split into `count` subnets.
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{Any, Prefix};
+        ///
+        /// let prefix = "2001:db8::/32".parse::<Prefix<Any>>()?;
+        ///
+        /// assert_eq!(prefix.subnets(1000)?.count(), 1024);
+        /// assert!("192.0.2.0/31".parse::<Prefix<Any>>()?.subnets(3).is_err());
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        pub fn subnets(&self, count: usize) -> Result<Subprefixes, Error>;
+
+        /// Returns [`true`] if `self` is the "default" prefix of its address
+        /// family.
+        ///
+        /// See also [`concrete::Prefix::is_default()`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{concrete::Afi, Any, Prefix};
+        ///
+        /// assert!(Prefix::<Any>::default(Afi::Ipv4).is_default());
+        /// assert!("::/0".parse::<Prefix<Any>>()?.is_default());
+        /// assert!(!"192.0.2.0/24".parse::<Prefix<Any>>()?.is_default());
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        #[must_use]
+        pub fn is_default(&self) -> bool;
+    }
+
+    /// Compare `self` and `other` in canonical "CIDR order": all IPv4
+    /// prefixes before all IPv6 prefixes, and prefixes of the same address
+    /// family by network address, and then by prefix length.
+    ///
+    /// This is the order used by [`SortablePrefix`]. See also
+    /// [`concrete::Prefix::cmp_canonical()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Prefix};
+    ///
+    /// let mut prefixes = ["2001:db8::/32", "192.0.2.0/24", "::/0", "10.0.0.0/8"]
+    ///     .into_iter()
+    ///     .map(str::parse::<Prefix<Any>>)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// prefixes.sort_by(Prefix::<Any>::cmp_canonical);
+    ///
+    /// assert_eq!(
+    ///     prefixes.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+    ///     ["10.0.0.0/8", "192.0.2.0/24", "::/0", "2001:db8::/32"],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn cmp_canonical(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Ipv4(prefix), Self::Ipv4(other)) => prefix.cmp_canonical(other),
+            (Self::Ipv6(prefix), Self::Ipv6(other)) => prefix.cmp_canonical(other),
+            (Self::Ipv4(_), Self::Ipv6(_)) => Ordering::Less,
+            (Self::Ipv6(_), Self::Ipv4(_)) => Ordering::Greater,
+        }
+    }
+
+    /// Convert `self` into a [`concrete::Prefix<A>`], returning [`None`] if
+    /// `self` is not of address family `A`.
+    ///
+    /// This is the inverse of [`concrete::Prefix::into_any()`]. See
+    /// [Address-families](crate#address-families) for why these methods
+    /// are provided alongside [`TryFrom`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Ipv4, Ipv6, Prefix};
+    ///
+    /// let prefix = "2001:db8::/32".parse::<Prefix<Any>>()?;
+    ///
+    /// assert_eq!(prefix.into_concrete(), Some("2001:db8::/32".parse::<Prefix<Ipv6>>()?));
+    /// assert_eq!(prefix.into_concrete::<Ipv4>(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn into_concrete<A: Afi>(self) -> Option<concrete::Prefix<A>>
+    where
+        concrete::Prefix<A>: TryFrom<Self>,
+    {
+        concrete::Prefix::<A>::try_from(self).ok()
+    }
+}
+
 impl traits::Prefix for Prefix {
     type Address = Address;
     type Length = Length;
@@ -75,13 +416,10 @@ impl traits::Prefix for Prefix {
         fn max_prefix_len(&self) -> Self::Length;
         fn prefix_len(&self) -> Self::Length;
         fn broadcast(&self) -> Self::Address;
-    }
-
-    fn supernet(&self) -> Option<Self> {
-        match self {
-            Self::Ipv4(prefix) => prefix.supernet().map(Self::Ipv4),
-            Self::Ipv6(prefix) => prefix.supernet().map(Self::Ipv6),
-        }
+        fn supernet(&self) -> Option<Self>;
+        fn subprefixes(&self, new_prefix_length: Self::Length) -> Result<Self::Subprefixes, Error>;
+        #[cfg(feature = "std")]
+        fn to_reverse_dns(&self) -> Result<std::string::String, Error>;
     }
 
     fn is_sibling(&self, other: &Self) -> bool {
@@ -92,18 +430,6 @@ impl traits::Prefix for Prefix {
         }
     }
 
-    fn subprefixes(&self, new_prefix_length: Self::Length) -> Result<Self::Subprefixes, Error> {
-        match (self, new_prefix_length) {
-            (Self::Ipv4(prefix), Self::Length::Ipv4(length)) => {
-                prefix.subprefixes(length).map(Self::Subprefixes::Ipv4)
-            }
-            (Self::Ipv6(prefix), Self::Length::Ipv6(length)) => {
-                prefix.subprefixes(length).map(Self::Subprefixes::Ipv6)
-            }
-            _ => Err(err!(Kind::AfiMismatch)),
-        }
-    }
-
     fn new_prefix_length(&self, length: u8) -> Result<Self::Length, Error> {
         self.afi().new_prefix_length(length)
     }
@@ -1,4 +1,5 @@
 use core::fmt;
+use core::iter::FusedIterator;
 use core::ops::RangeInclusive;
 use core::str::FromStr;
 
@@ -9,7 +10,7 @@ use proptest::{
     strategy::{BoxedStrategy, Strategy},
 };
 
-use super::{delegate, Length, Prefix};
+use super::{delegate, Address, Length, Prefix};
 use crate::{
     concrete::{self, Ipv4, Ipv6},
     traits, Error,
@@ -49,6 +50,36 @@ pub enum Range {
     Ipv6(concrete::PrefixRange<Ipv6>),
 }
 
+impl Range {
+    /// Returns the [`Range`] containing all prefixes of the address family
+    /// `afi`.
+    ///
+    /// See also [`concrete::PrefixRange::ALL`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::Afi, traits::PrefixRange as _, Any, PrefixLength, PrefixRange};
+    ///
+    /// assert_eq!(
+    ///     PrefixRange::<Any>::all(Afi::Ipv6),
+    ///     "::/0,0,128".parse::<PrefixRange<Any>>()?,
+    /// );
+    /// assert_eq!(
+    ///     PrefixRange::<Any>::all(Afi::Ipv4).upper(),
+    ///     PrefixLength::<Any>::max(Afi::Ipv4),
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn all(afi: concrete::Afi) -> Self {
+        match afi {
+            concrete::Afi::Ipv4 => Self::Ipv4(concrete::PrefixRange::ALL),
+            concrete::Afi::Ipv6 => Self::Ipv6(concrete::PrefixRange::ALL),
+        }
+    }
+}
+
 impl traits::PrefixRange for Range {
     type Prefix = Prefix;
     type Length = Length;
@@ -59,6 +90,14 @@ impl traits::PrefixRange for Range {
         fn upper(&self) -> Self::Length;
     }
 
+    fn contains_address(&self, addr: Address) -> bool {
+        match (self, addr) {
+            (Self::Ipv4(range), Address::Ipv4(addr)) => range.contains_address(addr),
+            (Self::Ipv6(range), Address::Ipv6(addr)) => range.contains_address(addr),
+            _ => false,
+        }
+    }
+
     fn with_intersection(self, len_range: RangeInclusive<Self::Length>) -> Option<Self> {
         match (self, *len_range.start(), *len_range.end()) {
             (Self::Ipv4(range), Length::Ipv4(lower), Length::Ipv4(upper)) => {
@@ -96,6 +135,18 @@ impl From<concrete::PrefixRange<Ipv6>> for Range {
     }
 }
 
+impl From<concrete::Prefix<Ipv4>> for Range {
+    fn from(prefix: concrete::Prefix<Ipv4>) -> Self {
+        Self::Ipv4(prefix.into())
+    }
+}
+
+impl From<concrete::Prefix<Ipv6>> for Range {
+    fn from(prefix: concrete::Prefix<Ipv6>) -> Self {
+        Self::Ipv6(prefix.into())
+    }
+}
+
 #[allow(clippy::fallible_impl_from)]
 impl From<Prefix> for Range {
     fn from(prefix: Prefix) -> Self {
@@ -143,6 +194,21 @@ pub enum IntoIter {
     Ipv6(<concrete::PrefixRange<Ipv6> as IntoIterator>::IntoIter),
 }
 
+impl IntoIter {
+    /// Returns the number of prefixes remaining in the iterator, or [`None`]
+    /// if that number exceeds [`usize::MAX`].
+    ///
+    /// This type does not implement [`ExactSizeIterator`], because the number
+    /// of prefixes in an IPv6 range may exceed [`usize::MAX`].
+    #[must_use]
+    pub fn checked_len(&self) -> Option<usize> {
+        match self {
+            Self::Ipv4(iter) => iter.checked_len(),
+            Self::Ipv6(iter) => iter.checked_len(),
+        }
+    }
+}
+
 impl Iterator for IntoIter {
     type Item = Prefix;
 
@@ -152,8 +218,17 @@ impl Iterator for IntoIter {
             Self::Ipv6(iter) => iter.next().map(Prefix::Ipv6),
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Ipv4(iter) => iter.size_hint(),
+            Self::Ipv6(iter) => iter.size_hint(),
+        }
+    }
 }
 
+impl FusedIterator for IntoIter {}
+
 #[cfg(any(test, feature = "arbitrary"))]
 impl Arbitrary for Range {
     type Parameters = ();
@@ -1,11 +1,26 @@
 use core::cmp::Ordering;
-use core::ops::{Add, BitAnd, BitOr, BitXor, Mul, Not, Sub};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign,
+    Not, Sub, SubAssign,
+};
+use core::str::FromStr;
+#[cfg(any(test, feature = "arbitrary"))]
+use std::vec::Vec;
 
 use num_traits::{One, Zero};
+#[cfg(any(test, feature = "arbitrary"))]
+use proptest::{
+    arbitrary::{any_with, Arbitrary, ParamsFor},
+    strategy::{BoxedStrategy, Strategy},
+};
 
 use super::{Prefix, Range};
 use crate::{
+    any::Address,
     concrete::{self, Ipv4, Ipv6},
+    error::Error,
     traits,
 };
 
@@ -25,19 +40,308 @@ use crate::{
 /// assert_eq!(set.len(), 6 + 16);
 /// # Ok::<_, Error>(())
 /// ```
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+///
+/// Borrowing a set iterates over the prefixes that it contains, with all
+/// IPv4 prefixes yielded before any IPv6 prefixes:
+///
+/// ```
+/// # use ip::{Any, Error, Prefix, PrefixSet};
+/// let set = PrefixSet::<Any>::from_iter([
+///     "2001:db8::/32".parse::<Prefix<Any>>()?,
+///     "192.0.2.0/24".parse()?,
+/// ]);
+/// let mut prefixes = Vec::new();
+/// for prefix in &set {
+///     prefixes.push(prefix.to_string());
+/// }
+/// assert_eq!(prefixes, ["192.0.2.0/24", "2001:db8::/32"]);
+/// # Ok::<_, Error>(())
+/// ```
+///
+/// The set operators are also implemented for borrowed sets, leaving both
+/// operands intact:
+///
+/// ```
+/// # use ip::{Any, Error, Prefix, PrefixSet, traits::PrefixSet as _};
+/// let x = PrefixSet::<Any>::from_iter([
+///     "192.0.2.0/24".parse::<Prefix<Any>>()?,
+///     "2001:db8::/32".parse()?,
+/// ]);
+/// let y = PrefixSet::<Any>::from_iter(["2001:db8::/32".parse::<Prefix<Any>>()?]);
+/// assert_eq!((&x - &y).len(), 1);
+/// assert_eq!((&x & &y).len(), 1);
+/// assert_eq!((&x | &y), x);
+/// assert_eq!((&x ^ &y), &x - &y);
+/// # Ok::<_, Error>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Set {
     ipv4: concrete::PrefixSet<Ipv4>,
     ipv6: concrete::PrefixSet<Ipv6>,
 }
 
 impl Set {
+    /// Construct a new, empty [`PrefixSet<Any>`][Self].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ipv4: concrete::PrefixSet::new(),
+            ipv6: concrete::PrefixSet::new(),
+        }
+    }
+
     fn aggregate(&mut self) -> &mut Self {
         _ = self.ipv4.aggregate();
         _ = self.ipv6.aggregate();
         self
     }
 
+    fn insert_only(&mut self, range: Range) -> &mut Self {
+        match range {
+            Range::Ipv4(range) => _ = self.ipv4.insert_only(range),
+            Range::Ipv6(range) => _ = self.ipv6.insert_only(range),
+        }
+        self
+    }
+
+    fn remove_only(&mut self, range: Range) -> &mut Self {
+        match range {
+            Range::Ipv4(range) => _ = self.ipv4.remove_only(range),
+            Range::Ipv6(range) => _ = self.ipv6.remove_only(range),
+        }
+        self
+    }
+
+    /// Insert a new `item` into `self`.
+    ///
+    /// `T` can be any type convertible into a [`PrefixRange<Any>`][Range],
+    /// including [`Prefix<Any>`][Prefix] and the concrete prefix and prefix
+    /// range types of either address family. The `item` is inserted into
+    /// the set for its address family.
+    ///
+    /// See [`concrete::PrefixSet::insert()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Ipv4, Prefix, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Any>::new()
+    ///     .insert("2001:db8:f00::/48,64,64".parse::<PrefixRange<Any>>()?)
+    ///     .insert("192.0.2.0/24".parse::<Prefix<Any>>()?)
+    ///     .insert("198.51.100.0/24".parse::<Prefix<Ipv4>>()?)
+    ///     .to_owned();
+    /// assert_eq!(set.len(), (1 << 16) + 2);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn insert<T>(&mut self, item: T) -> &mut Self
+    where
+        T: Into<Range>,
+    {
+        match item.into() {
+            Range::Ipv4(range) => _ = self.ipv4.insert(range),
+            Range::Ipv6(range) => _ = self.ipv6.insert(range),
+        }
+        self
+    }
+
+    /// Insert a new `item` into `self`, returning [`true`] if the set of
+    /// prefixes contained in `self` changed as a result.
+    ///
+    /// See [`concrete::PrefixSet::insert_changed()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixRange, PrefixSet};
+    /// let mut set = PrefixSet::<Any>::new();
+    /// assert!(set.insert_changed("192.0.2.0/24,25,26".parse::<PrefixRange<Any>>()?));
+    /// assert!(!set.insert_changed("192.0.2.128/25".parse::<Prefix<Any>>()?));
+    /// assert!(set.insert_changed("2001:db8::/32".parse::<Prefix<Any>>()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn insert_changed<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Range>,
+    {
+        match item.into() {
+            Range::Ipv4(range) => self.ipv4.insert_changed(range),
+            Range::Ipv6(range) => self.ipv6.insert_changed(range),
+        }
+    }
+
+    /// Insert a new `item` into `self`, returning [`true`] if the address
+    /// space covered by `self` grew as a result.
+    ///
+    /// See [`concrete::PrefixSet::insert_reporting()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixRange, PrefixSet};
+    /// let mut set = PrefixSet::<Any>::new();
+    /// assert!(set.insert_reporting("192.0.2.0/24,25,25".parse::<PrefixRange<Any>>()?));
+    /// assert!(!set.insert_reporting("192.0.2.0/24".parse::<Prefix<Any>>()?));
+    /// assert!(set.insert_reporting("2001:db8::/32".parse::<Prefix<Any>>()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn insert_reporting<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Range>,
+    {
+        match item.into() {
+            Range::Ipv4(range) => self.ipv4.insert_reporting(range),
+            Range::Ipv6(range) => self.ipv6.insert_reporting(range),
+        }
+    }
+
+    /// Insert items into `self` from an iterator yielding any type
+    /// convertible into a [`PrefixRange<Any>`][Range].
+    ///
+    /// Aggregation occurs after all items are inserted, making this far more
+    /// efficient than calling [`PrefixSet::insert()`][Self::insert] repeatedly.
+    ///
+    /// See [`concrete::PrefixSet::insert_from()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixSet};
+    /// let prefixes: Vec<_> = ["192.0.2.0/26", "192.0.2.64/26", "2001:db8::/32"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Any>>())
+    ///     .collect::<Result<_, _>>()?;
+    /// let set = PrefixSet::<Any>::new().insert_from(prefixes).to_owned();
+    /// assert_eq!(set.len(), 3);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn insert_from<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Range>,
+    {
+        iter.into_iter()
+            .fold(self, |set, item| set.insert_only(item.into()))
+            .aggregate()
+    }
+
+    /// Remove an `item` from `self`.
+    ///
+    /// `T` can be any type convertible into a [`PrefixRange<Any>`][Range].
+    /// The `item` is removed from the set for its address family.
+    ///
+    /// See [`concrete::PrefixSet::remove()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Ipv6, Prefix, PrefixSet};
+    /// let set = ["2001:db8:f00::/48", "2001:db8:baa::/48", "192.0.2.0/24"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Any>>())
+    ///     .collect::<Result<PrefixSet<Any>, _>>()?
+    ///     .remove("2001:db8:f00::/48".parse::<Prefix<Ipv6>>()?)
+    ///     .remove("192.0.2.0/24".parse::<Prefix<Any>>()?)
+    ///     .to_owned();
+    /// assert_eq!(set.len(), 1);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn remove<T>(&mut self, item: T) -> &mut Self
+    where
+        T: Into<Range>,
+    {
+        match item.into() {
+            Range::Ipv4(range) => _ = self.ipv4.remove(range),
+            Range::Ipv6(range) => _ = self.ipv6.remove(range),
+        }
+        self
+    }
+
+    /// Remove an `item` from `self`, returning [`true`] if the set of
+    /// prefixes contained in `self` changed as a result.
+    ///
+    /// See [`concrete::PrefixSet::remove_changed()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixSet};
+    /// let mut set = ["2001:db8:f00::/48", "192.0.2.0/24"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Any>>())
+    ///     .collect::<Result<PrefixSet<Any>, _>>()?;
+    /// assert!(set.remove_changed("2001:db8:f00::/48".parse::<Prefix<Any>>()?));
+    /// assert!(!set.remove_changed("2001:db8:f00::/48".parse::<Prefix<Any>>()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn remove_changed<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Range>,
+    {
+        match item.into() {
+            Range::Ipv4(range) => self.ipv4.remove_changed(range),
+            Range::Ipv6(range) => self.ipv6.remove_changed(range),
+        }
+    }
+
+    /// Remove an `item` from `self`, returning [`true`] if the address space
+    /// covered by `self` shrank as a result.
+    ///
+    /// See [`concrete::PrefixSet::remove_reporting()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixSet};
+    /// let mut set = ["2001:db8::/32", "2001:db8:f00::/48", "192.0.2.0/24"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Any>>())
+    ///     .collect::<Result<PrefixSet<Any>, _>>()?;
+    /// assert!(!set.remove_reporting("2001:db8:f00::/48".parse::<Prefix<Any>>()?));
+    /// assert!(set.remove_reporting("192.0.2.0/24".parse::<Prefix<Any>>()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn remove_reporting<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Range>,
+    {
+        match item.into() {
+            Range::Ipv4(range) => self.ipv4.remove_reporting(range),
+            Range::Ipv6(range) => self.ipv6.remove_reporting(range),
+        }
+    }
+
+    /// Remove items from `self` from an iterator yielding any type
+    /// convertible into a [`PrefixRange<Any>`][Range].
+    ///
+    /// See [`concrete::PrefixSet::remove_from()`].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixRange, PrefixSet};
+    /// let prefixes: Vec<_> = ["192.0.2.0/26", "192.0.2.64/26", "2001:db8::/32"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Any>>())
+    ///     .collect::<Result<_, _>>()?;
+    /// let mut set = PrefixSet::<Any>::new()
+    ///     .insert("192.0.2.0/24,26,26".parse::<PrefixRange<Any>>()?)
+    ///     .insert("2001:db8::/32".parse::<Prefix<Any>>()?)
+    ///     .to_owned();
+    /// assert_eq!(set.remove_from(prefixes).len(), 2);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn remove_from<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Range>,
+    {
+        iter.into_iter()
+            .fold(self, |set, item| set.remove_only(item.into()))
+            .aggregate()
+    }
+
+    /// Clear the contents of `self`.
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixSet};
+    /// let mut set = PrefixSet::<Any>::new()
+    ///     .insert("2001:db8::/32".parse::<Prefix<Any>>()?)
+    ///     .insert("192.0.2.0/24".parse::<Prefix<Any>>()?)
+    ///     .to_owned();
+    /// assert!(!set.is_empty());
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn clear(&mut self) {
+        self.ipv4.clear();
+        self.ipv6.clear();
+    }
+
     /// Partition the prefix set by address family.
     ///
     /// # Examples
@@ -109,7 +413,7 @@ impl Set {
     /// # Ok::<_, Error>(())
     /// ```
     #[must_use]
-    pub fn as_mut_partitions(
+    pub const fn as_mut_partitions(
         &mut self,
     ) -> (
         &mut concrete::PrefixSet<Ipv4>,
@@ -117,6 +421,267 @@ impl Set {
     ) {
         (&mut self.ipv4, &mut self.ipv6)
     }
+
+    /// Consume `self`, returning an iterator over its [`Range`]s.
+    ///
+    /// See also [`concrete::PrefixSet::into_ranges()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ip::{Any, Error, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Any>::from_iter([
+    ///     "192.0.2.0/24,26,26".parse::<PrefixRange<Any>>()?,
+    ///     "2001:db8::/32,48,48".parse()?,
+    /// ]);
+    /// assert_eq!(
+    ///     set.into_ranges().map(|range| range.to_string()).collect::<Vec<_>>(),
+    ///     ["192.0.2.0/24^26-26", "2001:db8::/32^48-48"],
+    /// );
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn into_ranges(self) -> impl Iterator<Item = Range> {
+        self.ipv4
+            .into_ranges()
+            .map(Range::Ipv4)
+            .chain(self.ipv6.into_ranges().map(Range::Ipv6))
+    }
+
+    /// Returns an iterator over the [`Range`]s in `self` that are covered by
+    /// `cover`.
+    ///
+    /// The iterator is empty if `self` contains no prefixes of the same
+    /// address family as `cover`.
+    ///
+    /// See also [`concrete::PrefixSet::ranges_within()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ip::{Any, Error, Prefix, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Any>::from_iter([
+    ///     "192.0.2.0/24,26,26".parse::<PrefixRange<Any>>()?,
+    ///     "2001:db8::/32,48,48".parse()?,
+    /// ]);
+    /// let ranges = set.ranges_within("192.0.2.128/25".parse::<Prefix<Any>>()?);
+    /// assert_eq!(
+    ///     ranges.collect::<Vec<_>>(),
+    ///     ["192.0.2.128/25,26,26".parse::<PrefixRange<Any>>()?],
+    /// );
+    /// assert_eq!(set.ranges_within("::/0".parse()?).count(), 1);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn ranges_within(&self, cover: Prefix) -> impl Iterator<Item = Range> + '_ {
+        let (ipv4, ipv6) = match cover {
+            Prefix::Ipv4(cover) => (Some(self.ipv4.ranges_within(cover)), None),
+            Prefix::Ipv6(cover) => (None, Some(self.ipv6.ranges_within(cover))),
+        };
+        ipv4.into_iter()
+            .flatten()
+            .map(Range::Ipv4)
+            .chain(ipv6.into_iter().flatten().map(Range::Ipv6))
+    }
+
+    /// Returns an iterator over the [`Prefix`]es in `self` that are covered
+    /// by `cover`.
+    ///
+    /// The iterator is empty if `self` contains no prefixes of the same
+    /// address family as `cover`.
+    ///
+    /// See also [`concrete::PrefixSet::prefixes_within()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ip::{Any, Error, Prefix, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Any>::from_iter([
+    ///     "192.0.2.0/24,26,26".parse::<PrefixRange<Any>>()?,
+    ///     "2001:db8::/32,48,48".parse()?,
+    /// ]);
+    /// assert_eq!(set.prefixes_within("192.0.2.0/25".parse()?).count(), 2);
+    /// assert_eq!(set.prefixes_within("2001:db8::/47".parse()?).count(), 2);
+    /// assert_eq!(set.prefixes_within("198.51.100.0/24".parse()?).count(), 0);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn prefixes_within(&self, cover: Prefix) -> impl Iterator<Item = Prefix> + '_ {
+        let (ipv4, ipv6) = match cover {
+            Prefix::Ipv4(cover) => (Some(self.ipv4.prefixes_within(cover)), None),
+            Prefix::Ipv6(cover) => (None, Some(self.ipv6.prefixes_within(cover))),
+        };
+        ipv4.into_iter()
+            .flatten()
+            .map(Prefix::Ipv4)
+            .chain(ipv6.into_iter().flatten().map(Prefix::Ipv6))
+    }
+
+    /// Returns the complement of `self` within `cover`.
+    ///
+    /// The result contains no prefixes of the other address family to
+    /// `cover`.
+    ///
+    /// See also [`concrete::PrefixSet::complement_within()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ip::{Any, Error, Prefix, PrefixRange, PrefixSet, traits::PrefixSet as _};
+    /// let set = PrefixSet::<Any>::from_iter([
+    ///     "192.0.2.0/24,25,32".parse::<PrefixRange<Any>>()?,
+    ///     "2001:db8::/32,48,48".parse()?,
+    /// ]);
+    /// let complement = set.complement_within("192.0.2.0/24".parse()?);
+    /// assert_eq!(complement.len(), 1);
+    /// assert!(complement.contains("192.0.2.0/24".parse()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn complement_within(&self, cover: Prefix) -> Self {
+        match cover {
+            Prefix::Ipv4(cover) => self.ipv4.complement_within(cover).into(),
+            Prefix::Ipv6(cover) => self.ipv6.complement_within(cover).into(),
+        }
+    }
+
+    /// Returns the number of IPv4 and IPv6 addresses covered by the prefixes
+    /// in the set, respectively.
+    ///
+    /// See [`concrete::PrefixSet::address_count()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::str::FromStr;
+    /// # use ip::{Any, Error, PrefixRange, PrefixSet};
+    /// let set: PrefixSet<Any> = ["192.0.2.0/24,25,26", "2001:db8::/48,52,52"]
+    ///     .into_iter()
+    ///     .map(PrefixRange::<Any>::from_str)
+    ///     .collect::<Result<_, _>>()?;
+    /// assert_eq!(set.address_count(), (256, 1 << 80));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn address_count(&self) -> (u128, u128) {
+        (self.ipv4.address_count(), self.ipv6.address_count())
+    }
+
+    /// Returns iterators over the number of prefixes of each prefix length
+    /// in the IPv4 and IPv6 parts of `self`, respectively.
+    ///
+    /// See [`concrete::PrefixSet::length_histogram()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::str::FromStr;
+    /// # use ip::{Any, Error, Ipv4, Ipv6, PrefixLength, PrefixRange, PrefixSet};
+    /// let set: PrefixSet<Any> = ["192.0.2.0/24,25,26", "2001:db8::/48,52,52"]
+    ///     .into_iter()
+    ///     .map(PrefixRange::<Any>::from_str)
+    ///     .collect::<Result<_, _>>()?;
+    /// let (ipv4, ipv6) = set.length_histogram();
+    /// assert_eq!(
+    ///     ipv4.collect::<Vec<_>>(),
+    ///     [
+    ///         (PrefixLength::<Ipv4>::from_primitive(25)?, 2),
+    ///         (PrefixLength::<Ipv4>::from_primitive(26)?, 4),
+    ///     ],
+    /// );
+    /// assert_eq!(ipv6.collect::<Vec<_>>(), [(PrefixLength::<Ipv6>::from_primitive(52)?, 16)]);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn length_histogram(
+        &self,
+    ) -> (
+        impl Iterator<Item = (concrete::PrefixLength<Ipv4>, usize)>,
+        impl Iterator<Item = (concrete::PrefixLength<Ipv6>, usize)>,
+    ) {
+        (self.ipv4.length_histogram(), self.ipv6.length_histogram())
+    }
+
+    /// Move all of the prefixes contained in `other` into `self`, merging
+    /// each address family separately.
+    ///
+    /// See [`concrete::PrefixSet::append()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ip::{Any, Error, Prefix, PrefixSet, traits::PrefixSet as _};
+    /// let mut set = PrefixSet::<Any>::from_iter(["192.0.2.0/25".parse::<Prefix<Any>>()?]);
+    /// set.append(PrefixSet::<Any>::from_iter([
+    ///     "192.0.2.128/25".parse::<Prefix<Any>>()?,
+    ///     "2001:db8::/32".parse()?,
+    /// ]));
+    /// assert_eq!(set.len(), 3);
+    /// assert_eq!(set.ranges().count(), 2);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn append(&mut self, other: Self) -> &mut Self {
+        _ = self.ipv4.append(other.ipv4);
+        _ = self.ipv6.append(other.ipv6);
+        self
+    }
+
+    /// Returns [`true`] if every prefix in `self` is also contained in
+    /// `other`.
+    ///
+    /// See [`concrete::PrefixSet::is_subset()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::str::FromStr;
+    /// # use ip::{Any, Error, PrefixRange, PrefixSet};
+    /// let set: PrefixSet<Any> = ["192.0.2.0/24,24,26", "2001:db8::/32,48,48"]
+    ///     .into_iter()
+    ///     .map(PrefixRange::<Any>::from_str)
+    ///     .collect::<Result<_, _>>()?;
+    /// let subset: PrefixSet<Any> = ["192.0.2.0/25,26,26"]
+    ///     .into_iter()
+    ///     .map(PrefixRange::<Any>::from_str)
+    ///     .collect::<Result<_, _>>()?;
+    /// assert!(subset.is_subset(&set));
+    /// assert!(!set.is_subset(&subset));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.ipv4.is_subset(&other.ipv4) && self.ipv6.is_subset(&other.ipv6)
+    }
+
+    /// Returns [`true`] if every prefix in `other` is also contained in
+    /// `self`.
+    ///
+    /// See [`concrete::PrefixSet::is_superset()`].
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns [`true`] if `self` and `other` have no prefixes in common.
+    ///
+    /// See [`concrete::PrefixSet::is_disjoint()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::str::FromStr;
+    /// # use ip::{Any, Error, PrefixRange, PrefixSet};
+    /// let ipv4: PrefixSet<Any> = ["0.0.0.0/0,0,32"]
+    ///     .into_iter()
+    ///     .map(PrefixRange::<Any>::from_str)
+    ///     .collect::<Result<_, _>>()?;
+    /// let ipv6: PrefixSet<Any> = ["::/0,0,128"]
+    ///     .into_iter()
+    ///     .map(PrefixRange::<Any>::from_str)
+    ///     .collect::<Result<_, _>>()?;
+    /// assert!(ipv4.is_disjoint(&ipv6));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.ipv4.is_disjoint(&other.ipv4) && self.ipv6.is_disjoint(&other.ipv6)
+    }
 }
 
 #[derive(Debug)]
@@ -134,8 +699,14 @@ impl Iterator for Prefixes<'_> {
             .map(Prefix::Ipv4)
             .or_else(|| self.ipv6.next().map(Prefix::Ipv6))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        add_size_hints(self.ipv4.size_hint(), self.ipv6.size_hint())
+    }
 }
 
+impl FusedIterator for Prefixes<'_> {}
+
 #[derive(Debug)]
 pub struct Ranges<'a> {
     ipv4: <concrete::PrefixSet<Ipv4> as traits::PrefixSet<'a>>::Ranges,
@@ -151,6 +722,85 @@ impl Iterator for Ranges<'_> {
             .map(Self::Item::Ipv4)
             .or_else(|| self.ipv6.next().map(Self::Item::Ipv6))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        add_size_hints(self.ipv4.size_hint(), self.ipv6.size_hint())
+    }
+}
+
+impl FusedIterator for Ranges<'_> {}
+
+fn add_size_hints(
+    (lower, upper): (usize, Option<usize>),
+    (other_lower, other_upper): (usize, Option<usize>),
+) -> (usize, Option<usize>) {
+    (
+        lower.saturating_add(other_lower),
+        upper
+            .zip(other_upper)
+            .and_then(|(upper, other)| upper.checked_add(other)),
+    )
+}
+
+/// Borrow a [`Set`], iterating over its [`Prefix`]es.
+///
+/// This is equivalent to [`prefixes()`][traits::PrefixSet::prefixes].
+#[allow(clippy::into_iter_without_iter)]
+impl<'a> IntoIterator for &'a Set {
+    type Item = Prefix;
+    type IntoIter = Prefixes<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        traits::PrefixSet::prefixes(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct IntoIter {
+    ipv4: <concrete::PrefixSet<Ipv4> as IntoIterator>::IntoIter,
+    ipv6: <concrete::PrefixSet<Ipv6> as IntoIterator>::IntoIter,
+}
+
+impl Iterator for IntoIter {
+    type Item = Prefix;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ipv4
+            .next()
+            .map(Prefix::Ipv4)
+            .or_else(|| self.ipv6.next().map(Prefix::Ipv6))
+    }
+}
+
+/// Consume a [`Set`], iterating over its [`Prefix`]es.
+///
+/// All IPv4 prefixes are yielded before any IPv6 prefixes.
+///
+/// # Examples
+///
+/// ```
+/// # use ip::{Any, Error, Prefix, PrefixSet};
+/// let set = PrefixSet::<Any>::from_iter([
+///     "2001:db8::/32".parse::<Prefix<Any>>()?,
+///     "192.0.2.0/24".parse()?,
+/// ]);
+/// let mut prefixes = Vec::new();
+/// for p in set {
+///     prefixes.push(p.to_string());
+/// }
+/// assert_eq!(prefixes, ["192.0.2.0/24", "2001:db8::/32"]);
+/// # Ok::<_, Error>(())
+/// ```
+impl IntoIterator for Set {
+    type Item = Prefix;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            ipv4: self.ipv4.into_iter(),
+            ipv6: self.ipv6.into_iter(),
+        }
+    }
 }
 
 impl<'a> traits::PrefixSet<'a> for Set {
@@ -173,12 +823,68 @@ impl<'a> traits::PrefixSet<'a> for Set {
         }
     }
 
+    fn len(&'a self) -> usize {
+        self.ipv4.len().saturating_add(self.ipv6.len())
+    }
+
+    fn is_empty(&'a self) -> bool {
+        self.ipv4.is_empty() && self.ipv6.is_empty()
+    }
+
+    fn insert(&mut self, prefix: Self::Prefix) -> &mut Self {
+        Self::insert(self, prefix)
+    }
+
+    fn insert_range(&mut self, range: Self::Range) -> &mut Self {
+        Self::insert(self, range)
+    }
+
+    fn remove(&mut self, prefix: Self::Prefix) -> &mut Self {
+        Self::remove(self, prefix)
+    }
+
+    fn remove_range(&mut self, range: Self::Range) -> &mut Self {
+        Self::remove(self, range)
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+
     fn contains(&self, prefix: Self::Prefix) -> bool {
         match prefix {
             Self::Prefix::Ipv4(prefix) => self.ipv4.contains(prefix),
             Self::Prefix::Ipv6(prefix) => self.ipv6.contains(prefix),
         }
     }
+
+    fn contains_range(&self, range: &Self::Range) -> bool {
+        match range {
+            Self::Range::Ipv4(range) => self.ipv4.contains_range(range),
+            Self::Range::Ipv6(range) => self.ipv6.contains_range(range),
+        }
+    }
+
+    fn contains_address(&self, addr: Address) -> bool {
+        match addr {
+            Address::Ipv4(addr) => self.ipv4.contains_address(addr),
+            Address::Ipv6(addr) => self.ipv6.contains_address(addr),
+        }
+    }
+
+    fn longest_match(&self, addr: Address) -> Option<Self::Prefix> {
+        match addr {
+            Address::Ipv4(addr) => self.ipv4.longest_match(addr).map(Prefix::Ipv4),
+            Address::Ipv6(addr) => self.ipv6.longest_match(addr).map(Prefix::Ipv6),
+        }
+    }
+
+    fn longest_match_prefix(&self, prefix: Self::Prefix) -> Option<Self::Prefix> {
+        match prefix {
+            Self::Prefix::Ipv4(prefix) => self.ipv4.longest_match_prefix(prefix).map(Prefix::Ipv4),
+            Self::Prefix::Ipv6(prefix) => self.ipv6.longest_match_prefix(prefix).map(Prefix::Ipv6),
+        }
+    }
 }
 
 impl From<concrete::PrefixSet<Ipv4>> for Set {
@@ -200,41 +906,60 @@ impl From<concrete::PrefixSet<Ipv6>> for Set {
 }
 
 impl Extend<Prefix> for Set {
-    #[allow(unused_results)]
     fn extend<I>(&mut self, iter: I)
     where
         I: IntoIterator<Item = Prefix>,
     {
-        iter.into_iter().for_each(|prefix| match prefix {
-            Prefix::Ipv4(prefix) => {
-                self.ipv4.insert_only(prefix);
-            }
-            Prefix::Ipv6(prefix) => {
-                self.ipv6.insert_only(prefix);
-            }
-        });
-        self.aggregate();
+        _ = self.insert_from(iter);
     }
 }
 
 impl Extend<Range> for Set {
-    #[allow(unused_results)]
     fn extend<I>(&mut self, iter: I)
     where
         I: IntoIterator<Item = Range>,
     {
-        iter.into_iter().for_each(|range| match range {
-            Range::Ipv4(range) => {
-                self.ipv4.insert_only(range);
-            }
-            Range::Ipv6(range) => {
-                self.ipv6.insert_only(range);
-            }
-        });
-        self.aggregate();
+        _ = self.insert_from(iter);
+    }
+}
+
+impl<'a> Extend<&'a Prefix> for Set {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a Prefix>,
+    {
+        self.extend(iter.into_iter().copied());
     }
 }
 
+impl<'a> Extend<&'a Range> for Set {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = &'a Range>,
+    {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+/// Construct a [`Set`] from [`Prefix`]es or [`Range`]s, either owned or
+/// borrowed.
+///
+/// # Examples
+///
+/// ```
+/// # use ip::{traits::PrefixSet as _, Any, Error, Prefix, PrefixRange, PrefixSet};
+/// let prefixes = ["192.0.2.0/24", "2001:db8::/32"]
+///     .into_iter()
+///     .map(str::parse::<Prefix<Any>>)
+///     .collect::<Result<Vec<_>, _>>()?;
+/// let ranges = vec!["2001:db8::/32,33,33".parse::<PrefixRange<Any>>()?];
+///
+/// let mut set = PrefixSet::<Any>::from_iter(&prefixes);
+/// set.extend(&ranges);
+///
+/// assert_eq!(set.len(), 1 + 1 + 2);
+/// # Ok::<_, Error>(())
+/// ```
 impl<T> FromIterator<T> for Set
 where
     Self: Extend<T>,
@@ -282,6 +1007,13 @@ impl BitAnd for Set {
     }
 }
 
+impl BitAndAssign for Set {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.ipv4 &= rhs.ipv4;
+        self.ipv6 &= rhs.ipv6;
+    }
+}
+
 impl BitOr for Set {
     type Output = Self;
 
@@ -293,6 +1025,13 @@ impl BitOr for Set {
     }
 }
 
+impl BitOrAssign for Set {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.ipv4 |= rhs.ipv4;
+        self.ipv6 |= rhs.ipv6;
+    }
+}
+
 impl BitXor for Set {
     type Output = Self;
 
@@ -304,6 +1043,13 @@ impl BitXor for Set {
     }
 }
 
+impl BitXorAssign for Set {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.ipv4 ^= rhs.ipv4;
+        self.ipv6 ^= rhs.ipv6;
+    }
+}
+
 impl Not for Set {
     type Output = Self;
 
@@ -326,6 +1072,13 @@ impl Add for Set {
     }
 }
 
+impl AddAssign for Set {
+    fn add_assign(&mut self, rhs: Self) {
+        self.ipv4 += rhs.ipv4;
+        self.ipv6 += rhs.ipv6;
+    }
+}
+
 impl Mul for Set {
     type Output = Self;
 
@@ -337,6 +1090,13 @@ impl Mul for Set {
     }
 }
 
+impl MulAssign for Set {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.ipv4 *= rhs.ipv4;
+        self.ipv6 *= rhs.ipv6;
+    }
+}
+
 impl Sub for Set {
     type Output = Self;
 
@@ -348,6 +1108,207 @@ impl Sub for Set {
     }
 }
 
+impl SubAssign for Set {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.ipv4 -= rhs.ipv4;
+        self.ipv6 -= rhs.ipv6;
+    }
+}
+
+impl BitAnd for &Set {
+    type Output = Set;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Set {
+            ipv4: &self.ipv4 & &rhs.ipv4,
+            ipv6: &self.ipv6 & &rhs.ipv6,
+        }
+    }
+}
+
+impl BitOr for &Set {
+    type Output = Set;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Set {
+            ipv4: &self.ipv4 | &rhs.ipv4,
+            ipv6: &self.ipv6 | &rhs.ipv6,
+        }
+    }
+}
+
+impl BitXor for &Set {
+    type Output = Set;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Set {
+            ipv4: &self.ipv4 ^ &rhs.ipv4,
+            ipv6: &self.ipv6 ^ &rhs.ipv6,
+        }
+    }
+}
+
+impl Sub for &Set {
+    type Output = Set;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Set {
+            ipv4: &self.ipv4 - &rhs.ipv4,
+            ipv6: &self.ipv6 - &rhs.ipv6,
+        }
+    }
+}
+
+/// Parse a [`Set`] from a list of IPv4 and IPv6 prefixes and prefix ranges,
+/// separated by whitespace and/or commas.
+///
+/// Items of both address families may be freely mixed. An item containing a
+/// `':'` is parsed as IPv6, and any other item as IPv4. Each item is
+/// dispatched to the set for its address family, and each of those is
+/// aggregated once, after all items have been inserted.
+///
+/// The output of the [`Display`][fmt::Display] implementation can always be
+/// parsed back into an equal [`Set`].
+///
+/// See the [`FromStr`] implementation of [`concrete::PrefixSet`] for details.
+///
+/// # Errors
+///
+/// Parsing fails on the first item that is not a valid prefix or prefix
+/// range of its address family. The returned error is the one reported for
+/// that address family, so that, for example, an IPv4 prefix-length greater
+/// than `32` results in an error of kind
+/// [`PrefixLength`][crate::error::Kind::PrefixLength].
+///
+/// The [`position()`][crate::Error::position] of the returned error gives
+/// the byte offset into the input at which the failure occurred. This
+/// identifies the offending item: the offset falls within it, or at its end
+/// if the item is incomplete.
+///
+/// # Examples
+///
+/// ```
+/// use ip::{error::Kind, traits::PrefixSet as _, Any, Error, PrefixSet};
+///
+/// let set = "192.0.2.0/24 10.0.0.0/8,8,10 2001:db8::/32".parse::<PrefixSet<Any>>()?;
+/// assert_eq!(set.len(), 1 + (1 + 2 + 4) + 1);
+///
+/// let err = "192.0.2.0/24,2001:db8::/129".parse::<PrefixSet<Any>>().unwrap_err();
+/// assert_eq!(err.kind(), Kind::PrefixLength);
+/// assert_eq!(err.position(), Some(13));
+///
+/// let err = "2001:db8::/32 192.0.2.0/33".parse::<PrefixSet<Any>>().unwrap_err();
+/// assert_eq!(err.kind(), Kind::PrefixLength);
+/// # Ok::<_, Error>(())
+/// ```
+///
+/// Round-tripping the body of a filter file containing both address
+/// families:
+///
+/// ```
+/// use ip::{Any, Error, PrefixSet};
+///
+/// let body = "\
+/// 192.0.2.0/24
+/// 198.51.100.0/22,24,24
+/// 2001:db8::/32,48,48
+/// 10.0.0.0/8,8,8 10.0.0.0/8,16,16
+/// 2001:db8:f00::/40
+/// ";
+/// let set = body.parse::<PrefixSet<Any>>()?;
+///
+/// let displayed = set.to_string();
+/// assert_eq!(
+///     displayed.lines().collect::<Vec<_>>(),
+///     [
+///         "198.51.100.0/22,24,24",
+///         "192.0.2.0/24,24,24",
+///         "10.0.0.0/8,8,8",
+///         "10.0.0.0/8,16,16",
+///         "2001:db8::/32,48,48",
+///         "2001:db8:f00::/40,40,40",
+///     ],
+/// );
+/// assert_eq!(displayed.parse::<PrefixSet<Any>>()?, set);
+///
+/// let input = "192.0.2.0/24\n2001:db8::/129\n";
+/// let err = input.parse::<PrefixSet<Any>>().unwrap_err();
+/// let offending = err.position().and_then(|pos| {
+///     let start = input[..pos].rfind('\n').map_or(0, |i| i + 1);
+///     input[start..].lines().next()
+/// });
+/// assert_eq!(offending, Some("2001:db8::/129"));
+/// # Ok::<_, Error>(())
+/// ```
+impl FromStr for Set {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::default();
+        for (offset, item) in concrete::ListItems::new(s) {
+            // only IPv6 items can contain a ':'
+            if item.contains(':') {
+                _ = set.ipv6.insert_only(parse_item(item, offset)?);
+            } else {
+                _ = set.ipv4.insert_only(parse_item(item, offset)?);
+            }
+        }
+        _ = set.aggregate();
+        Ok(set)
+    }
+}
+
+// Parse a single list item found at `offset` as a prefix or prefix range of
+// address family `A`.
+fn parse_item<A: traits::Afi>(
+    item: &str,
+    offset: usize,
+) -> Result<concrete::PrefixRange<A>, Error> {
+    if item.contains(',') {
+        item.parse()
+    } else {
+        item.parse::<concrete::Prefix<A>>()
+            .map(concrete::PrefixRange::from)
+    }
+    .map_err(|err| err.at(offset + err.position().unwrap_or_default()))
+}
+
+/// Write the contents of the set, one item per line, with all IPv4 items
+/// written before any IPv6 items.
+///
+/// See the [`Display`][fmt::Display] implementation of
+/// [`concrete::PrefixSet`] for the format used, including the effect of the
+/// alternate flag (`{:#}`).
+///
+/// # Examples
+///
+/// ```
+/// use ip::{Any, Error, PrefixRange, PrefixSet};
+///
+/// let set = ["2001:db8::/32,48,48", "192.0.2.0/24,25,25"]
+///     .into_iter()
+///     .map(str::parse::<PrefixRange<Any>>)
+///     .collect::<Result<PrefixSet<Any>, _>>()?;
+///
+/// let displayed = set.to_string();
+/// assert_eq!(displayed, "192.0.2.0/24,25,25\n2001:db8::/32,48,48");
+///
+/// assert_eq!(displayed.parse::<PrefixSet<Any>>()?, set);
+///
+/// assert_eq!(format!("{set:#}").lines().count(), 2 + (1 << 16));
+/// # Ok::<_, Error>(())
+/// ```
+impl fmt::Display for Set {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use traits::PrefixSet as _;
+        fmt::Display::fmt(&self.ipv4, f)?;
+        if !self.ipv4.is_empty() && !self.ipv6.is_empty() {
+            f.write_str("\n")?;
+        }
+        fmt::Display::fmt(&self.ipv6, f)
+    }
+}
+
 impl PartialOrd for Set {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         use Ordering::{Equal, Greater, Less};
@@ -365,3 +1326,75 @@ impl PartialOrd for Set {
         }
     }
 }
+
+/// Generate arbitrary [`PrefixSet<Any>`][Set]s by inserting a collection of
+/// arbitrary [`PrefixRange<Any>`][Range]s of either address family.
+///
+/// See the [`Arbitrary`] implementation for [`concrete::PrefixSet`].
+#[cfg(any(test, feature = "arbitrary"))]
+impl Arbitrary for Set {
+    type Parameters = ParamsFor<Vec<Range>>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        any_with::<Vec<Range>>(params)
+            .prop_map(|ranges| {
+                let mut set = Self::new();
+                _ = set.insert_from(ranges);
+                set
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Kind, TestResult};
+
+    fn parse_err(s: &str) -> (Kind, Option<usize>) {
+        let err = s.parse::<Set>().unwrap_err();
+        (err.kind(), err.position())
+    }
+
+    #[test]
+    fn covered_prefix_insert_is_not_reported() -> TestResult {
+        let mut set: Set = "192.0.2.0/25 192.0.2.128/25".parse()?;
+        assert!(!set.insert_reporting("192.0.2.0/24".parse::<Prefix>()?));
+        assert!(set.insert_reporting("2001:db8::/32".parse::<Prefix>()?));
+        Ok(())
+    }
+
+    #[test]
+    fn partially_present_range_remove_is_reported() -> TestResult {
+        let mut set: Set = "2001:db8::/48 2001:db8:2::/48".parse()?;
+        assert!(set.remove_reporting("2001:db8::/47,48,49".parse::<Range>()?));
+        assert_eq!(set.address_count(), (0, 1 << 80));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_keeps_ipv4_errors() {
+        let (kind, _) = parse_err("2001:db8::/32 192.0.2.0/24 10.0.0.0/33");
+        assert_eq!(kind, Kind::PrefixLength);
+        let (kind, _) = parse_err("2001:db8::/32 10.0.0.0/8,4,8");
+        assert_eq!(kind, Kind::PrefixLengthRange);
+        assert_eq!(parse_err("garbage"), (Kind::ParserError, Some(0)));
+        assert_eq!(
+            parse_err("2001:db8::/32 192.0.2.256/24"),
+            (Kind::ParserError, Some(22))
+        );
+    }
+
+    #[test]
+    fn parse_keeps_ipv6_errors() {
+        let (kind, _) = parse_err("192.0.2.0/24 2001:db8::/32 2001:db8::/129");
+        assert_eq!(kind, Kind::PrefixLength);
+        let (kind, _) = parse_err("192.0.2.0/24 2001:db8::/32,16,32");
+        assert_eq!(kind, Kind::PrefixLengthRange);
+        assert_eq!(
+            parse_err("192.0.2.0/24 2001:db8::g/32"),
+            (Kind::ParserError, Some(23))
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use super::Prefix;
+
+/// A wrapper around [`Prefix`] implementing a total order.
+///
+/// The [`PartialOrd`] implementation for [`Prefix`] orders prefixes of the
+/// same address family by containment, and therefore cannot be used to key
+/// ordered collections such as [`BTreeMap`][std::collections::BTreeMap] or
+/// [`BTreeSet`][std::collections::BTreeSet]. Wrapping a [`Prefix`] in a
+/// [`SortablePrefix`] opts in to a canonical total order instead, without
+/// changing the ordering semantics of [`Prefix`] itself.
+///
+/// # Ordering
+///
+/// [`SortablePrefix`] values are ordered by comparing, in turn:
+///
+/// 1. the address family, with all IPv4 prefixes ordered before all IPv6
+///    prefixes;
+/// 2. the network address of the prefix, in ascending numeric order; and
+/// 3. the prefix length, in ascending order.
+///
+/// Consequently, a prefix sorts immediately before any longer prefixes
+/// sharing the same network address, so that a covering prefix is always
+/// ordered before all of its subprefixes.
+///
+/// This is the order implemented by [`Prefix::cmp_canonical()`], and is
+/// consistent with the [`Eq`] implementation for [`Prefix`].
+///
+/// # Examples
+///
+/// ``` rust
+/// use std::collections::BTreeSet;
+///
+/// use ip::{any::SortablePrefix, Any, Prefix};
+///
+/// let prefixes = ["2001:db8::/32", "192.0.2.0/24", "192.0.0.0/16", "::/0"]
+///     .into_iter()
+///     .map(|s| s.parse::<Prefix<Any>>().map(SortablePrefix::from))
+///     .collect::<Result<BTreeSet<_>, _>>()?;
+///
+/// assert_eq!(
+///     prefixes.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+///     ["192.0.0.0/16", "192.0.2.0/24", "::/0", "2001:db8::/32"],
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct SortablePrefix(pub Prefix);
+
+impl SortablePrefix {
+    /// Consume `self`, returning the wrapped [`Prefix`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{any::SortablePrefix, Any, Prefix};
+    ///
+    /// let prefix = "192.0.2.0/24".parse::<Prefix<Any>>()?;
+    ///
+    /// assert_eq!(SortablePrefix::from(prefix).into_inner(), prefix);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn into_inner(self) -> Prefix {
+        self.0
+    }
+}
+
+impl Ord for SortablePrefix {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_canonical(&other.0)
+    }
+}
+
+impl PartialOrd for SortablePrefix {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<Prefix> for SortablePrefix {
+    fn from(prefix: Prefix) -> Self {
+        Self(prefix)
+    }
+}
+
+impl From<SortablePrefix> for Prefix {
+    fn from(sortable: SortablePrefix) -> Self {
+        sortable.0
+    }
+}
+
+impl fmt::Display for SortablePrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{arbitrary::any, proptest};
+
+    use super::*;
+    use crate::traits::Prefix as _;
+
+    proptest! {
+        #[test]
+        fn consistent_with_eq(p in any::<Prefix>(), q in any::<Prefix>()) {
+            let (p, q) = (SortablePrefix(p), SortablePrefix(q));
+            assert_eq!(p.cmp(&q) == Ordering::Equal, p == q);
+            assert_eq!(p.cmp(&q), q.cmp(&p).reverse());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn covering_prefix_first(p in any::<Prefix>()) {
+            if let Some(supernet) = p.supernet() {
+                assert!(SortablePrefix(supernet) < SortablePrefix(p));
+            }
+        }
+    }
+}
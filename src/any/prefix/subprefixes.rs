@@ -1,8 +1,14 @@
+use core::iter::FusedIterator;
+
 use super::Prefix;
 use crate::concrete::{self, Ipv4, Ipv6};
 
 /// Iterator returned by
 /// [`Prefix::subprefixes()`][crate::traits::Prefix::subprefixes].
+///
+/// Unlike [`concrete::Subprefixes<Ipv4>`], this type does not implement
+/// [`ExactSizeIterator`], because the number of IPv6 subprefixes may exceed
+/// [`usize::MAX`]. Use [`Self::checked_len`] instead.
 #[derive(Debug, Clone)]
 pub enum Subprefixes {
     /// IPv4 variant.
@@ -11,6 +17,20 @@ pub enum Subprefixes {
     Ipv6(concrete::Subprefixes<Ipv6>),
 }
 
+impl Subprefixes {
+    /// Returns the number of subprefixes remaining in the iterator, or
+    /// [`None`] if that number exceeds [`usize::MAX`].
+    ///
+    /// See [`concrete::Subprefixes::checked_len`].
+    #[must_use]
+    pub fn checked_len(&self) -> Option<usize> {
+        match self {
+            Self::Ipv4(iter) => iter.checked_len(),
+            Self::Ipv6(iter) => iter.checked_len(),
+        }
+    }
+}
+
 impl Iterator for Subprefixes {
     type Item = Prefix;
 
@@ -20,4 +40,34 @@ impl Iterator for Subprefixes {
             Self::Ipv6(iter) => iter.next().map(Self::Item::Ipv6),
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Ipv4(iter) => iter.size_hint(),
+            Self::Ipv6(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for Subprefixes {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Ipv4(iter) => iter.next_back().map(Self::Item::Ipv4),
+            Self::Ipv6(iter) => iter.next_back().map(Self::Item::Ipv6),
+        }
+    }
+}
+
+impl FusedIterator for Subprefixes {}
+
+impl From<concrete::Subprefixes<Ipv4>> for Subprefixes {
+    fn from(iter: concrete::Subprefixes<Ipv4>) -> Self {
+        Self::Ipv4(iter)
+    }
+}
+
+impl From<concrete::Subprefixes<Ipv6>> for Subprefixes {
+    fn from(iter: concrete::Subprefixes<Ipv6>) -> Self {
+        Self::Ipv6(iter)
+    }
 }
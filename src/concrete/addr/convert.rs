@@ -4,6 +4,7 @@ use super::Address;
 use crate::traits::primitive::{Address as _, IntoIpv6Segments as _};
 use crate::{
     concrete::{Ipv4, Ipv6},
+    error::{err, Error, Kind},
     traits::Afi,
 };
 
@@ -55,16 +56,87 @@ impl From<[u16; 8]> for Address<Ipv6> {
     }
 }
 
-#[cfg(feature = "std")]
-impl From<std::net::Ipv4Addr> for Address<Ipv4> {
-    fn from(addr: std::net::Ipv4Addr) -> Self {
+// `std::net` re-exports these types from `core::net`, so the following
+// conversions also apply to the `std::net` types, with or without the `std`
+// feature enabled.
+impl From<core::net::Ipv4Addr> for Address<Ipv4> {
+    fn from(addr: core::net::Ipv4Addr) -> Self {
         Self::new(addr.into())
     }
 }
 
-#[cfg(feature = "std")]
-impl From<std::net::Ipv6Addr> for Address<Ipv6> {
-    fn from(addr: std::net::Ipv6Addr) -> Self {
+impl From<core::net::Ipv6Addr> for Address<Ipv6> {
+    fn from(addr: core::net::Ipv6Addr) -> Self {
         Self::new(addr.into())
     }
 }
+
+impl From<Address<Ipv4>> for core::net::Ipv4Addr {
+    fn from(addr: Address<Ipv4>) -> Self {
+        addr.into_primitive().into()
+    }
+}
+
+impl From<Address<Ipv6>> for core::net::Ipv6Addr {
+    fn from(addr: Address<Ipv6>) -> Self {
+        addr.into_primitive().into()
+    }
+}
+
+macro_rules! impl_try_from_ip_addr {
+    ( $( $variant:ident => $af:ident ),* $(,)? ) => {
+        $(
+            impl TryFrom<core::net::IpAddr> for Address<$af> {
+                type Error = Error;
+
+                fn try_from(addr: core::net::IpAddr) -> Result<Self, Self::Error> {
+                    if let core::net::IpAddr::$variant(inner) = addr {
+                        Ok(inner.into())
+                    } else {
+                        Err(err!(Kind::AfiMismatch))
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_try_from_ip_addr! { V4 => Ipv4, V6 => Ipv6, }
+
+#[cfg(test)]
+mod tests {
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn ipv4_round_trip() {
+        let std_addr = Ipv4Addr::new(192, 0, 2, 1);
+        let addr = Address::<Ipv4>::from(std_addr);
+        assert_eq!(addr, Address::from_octets([192, 0, 2, 1]));
+        assert_eq!(Ipv4Addr::from(addr), std_addr);
+    }
+
+    #[test]
+    fn ipv6_round_trip() {
+        let std_addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        let addr = Address::<Ipv6>::from(std_addr);
+        assert_eq!(addr, Address::from([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]));
+        assert_eq!(Ipv6Addr::from(addr), std_addr);
+    }
+
+    #[test]
+    fn try_from_ip_addr() {
+        let v4 = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let v6 = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        assert_eq!(Address::<Ipv4>::try_from(v4).ok(), Some(Address::LOCALHOST));
+        assert_eq!(Address::<Ipv6>::try_from(v6).ok(), Some(Address::LOCALHOST));
+        assert_eq!(
+            Address::<Ipv4>::try_from(v6).unwrap_err().kind(),
+            Kind::AfiMismatch
+        );
+        assert_eq!(
+            Address::<Ipv6>::try_from(v4).unwrap_err().kind(),
+            Kind::AfiMismatch
+        );
+    }
+}
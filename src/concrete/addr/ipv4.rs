@@ -1,11 +1,95 @@
 use super::Address;
 use crate::{
-    concrete::{Ipv4, Ipv6},
+    concrete::{Ipv4, Ipv6, Prefix, PrefixLength},
+    error::Error,
+    parser,
     traits::{primitive::Address as _, Afi},
 };
 
+/// Options controlling the leniency of [`Address::<Ipv4>::parse_with()`].
+///
+/// The default options are strict, and accept exactly the same inputs as
+/// the [`FromStr`][core::str::FromStr] implementation for
+/// [`Address<Ipv4>`]. Each option relaxes the parser to accept an
+/// additional legacy notation.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::ParseOptions, Address, Ipv4};
+///
+/// let options = ParseOptions::new()
+///     .allow_leading_zeros(true)
+///     .allow_classful_shorthand(true);
+///
+/// assert_eq!(
+///     Address::<Ipv4>::parse_with("010.1", options)?,
+///     "10.1.0.0".parse::<Address<Ipv4>>()?,
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    leading_zeros: bool,
+    classful_shorthand: bool,
+}
+
+impl ParseOptions {
+    /// Construct a new set of strict [`ParseOptions`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            leading_zeros: false,
+            classful_shorthand: false,
+        }
+    }
+
+    /// Set whether octets may have leading zeros, as in `010.0.0.1`.
+    ///
+    /// Octets with leading zeros are interpreted as decimal, not octal, so
+    /// `010` is parsed as `10`. An octet may still have at most three
+    /// digits.
+    #[must_use]
+    pub const fn allow_leading_zeros(mut self, allow: bool) -> Self {
+        self.leading_zeros = allow;
+        self
+    }
+
+    /// Set whether fewer than four octets may be given, as in the classful
+    /// shorthand `10` for `10.0.0.0`.
+    ///
+    /// Any missing trailing octets are taken to be zero, so `172.16` is
+    /// parsed as `172.16.0.0`.
+    #[must_use]
+    pub const fn allow_classful_shorthand(mut self, allow: bool) -> Self {
+        self.classful_shorthand = allow;
+        self
+    }
+}
+
 // TODO: make methods `const fn`
 impl Address<Ipv4> {
+    /// Construct a new [`Address<Ipv4>`] from a big-endian byte-array, in a
+    /// `const` context.
+    ///
+    /// This is equivalent to [`Address::from_octets()`], which cannot be a
+    /// `const fn` because it is generic over the address family.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4};
+    ///
+    /// const ADDR: Address<Ipv4> = Address::<Ipv4>::from_octets_const([192, 0, 2, 1]);
+    ///
+    /// assert_eq!(ADDR, "192.0.2.1".parse::<Address<Ipv4>>()?);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn from_octets_const(octets: [u8; 4]) -> Self {
+        Self::new(u32::from_be_bytes(octets))
+    }
+
     /// The IPv4 subnet-local broadcast address `255.255.255.255`.
     pub const BROADCAST: Self = {
         if let Some(inner) = <Ipv4 as Afi>::Primitive::BROADCAST {
@@ -15,6 +99,65 @@ impl Address<Ipv4> {
         }
     };
 
+    /// Parse an [`Address<Ipv4>`] from `s`, with the leniency given by
+    /// `options`.
+    ///
+    /// With the default [`ParseOptions`] this is equivalent to
+    /// [`str::parse()`].
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`ParserError`][crate::error::Kind::ParserError] is
+    /// returned if `s` is not a valid IPv4 address under `options`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::ParseOptions, Address, Ipv4};
+    ///
+    /// let strict = ParseOptions::default();
+    /// assert!(Address::<Ipv4>::parse_with("192.0.02.1", strict).is_err());
+    /// assert!(Address::<Ipv4>::parse_with("10", strict).is_err());
+    ///
+    /// assert_eq!(
+    ///     Address::<Ipv4>::parse_with("192.0.02.1", strict.allow_leading_zeros(true))?,
+    ///     "192.0.2.1".parse::<Address<Ipv4>>()?,
+    /// );
+    /// assert_eq!(
+    ///     Address::<Ipv4>::parse_with("10", strict.allow_classful_shorthand(true))?,
+    ///     "10.0.0.0".parse::<Address<Ipv4>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn parse_with(s: &str, options: ParseOptions) -> Result<Self, Error> {
+        parser::ipv4::parse_addr_with(s, options.leading_zeros, options.classful_shorthand)
+            .map(Self::new)
+    }
+
+    /// Returns a big-endian [`[u16; 2]`] representing the 16-bit segments of
+    /// the address.
+    ///
+    /// This is provided for symmetry with
+    /// [`Address::<Ipv6>::segments()`][Address::segments].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.1".parse::<Address<Ipv4>>()?.segments(),
+    ///     [0xc000, 0x0201],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn segments(&self) -> [u16; 2] {
+        let inner = self.into_primitive();
+        [(inner >> 16) as u16, inner as u16]
+    }
+
     /// Converts this [`Address<Ipv4>`] to an IPv4-compatible
     /// [`Address<Ipv6>`].
     ///
@@ -66,6 +209,38 @@ impl Address<Ipv4> {
         Address::from_octets(octets)
     }
 
+    /// Returns the 6to4 [`Prefix<Ipv6>`] corresponding to this
+    /// [`Address<Ipv4>`].
+    ///
+    /// 6to4 prefixes are of the form `2002:aabb:ccdd::/48`, where
+    /// `aa.bb.cc.dd` is the hex representation of the IPv4 address. See
+    /// [RFC 3056].
+    ///
+    /// [RFC 3056]: https://tools.ietf.org/html/rfc3056
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.4".parse::<Address<Ipv4>>()?.to_6to4_prefix(),
+    ///     "2002:c000:0204::/48".parse::<Prefix<Ipv6>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use]
+    pub fn to_6to4_prefix(&self) -> Prefix<Ipv6> {
+        let mut octets = <Ipv6 as Afi>::Octets::default();
+        octets[..2].copy_from_slice(&[0x20, 0x02]);
+        octets[2..6].copy_from_slice(&self.octets());
+        // ok to unwrap since 48 is always a valid IPv6 prefix length
+        let length = PrefixLength::from_primitive(48).unwrap();
+        Prefix::new(Address::from_octets(octets), length)
+    }
+
     fn to_ipv6_lo_octets(self) -> <Ipv6 as Afi>::Octets {
         let mut octets = <Ipv6 as Afi>::Octets::default();
         octets[12..].copy_from_slice(&self.octets());
@@ -1,12 +1,302 @@
+use core::fmt;
+
 use super::Address;
 use crate::{
     any,
-    concrete::{Ipv4, Ipv6},
+    concrete::{Ipv4, Ipv6, Prefix},
+    error::{err, Error, Kind},
+    parser,
     traits::{primitive::IntoIpv6Segments as _, Address as _, Afi},
 };
 
+const SOLICITED_NODE_PREFIX: u128 = 0xff02_0000_0000_0000_0000_0001_ff00_0000;
+
 // TODO: make methods `const fn`
 impl Address<Ipv6> {
+    /// Construct a new [`Address<Ipv6>`] from a big-endian byte-array, in a
+    /// `const` context.
+    ///
+    /// This is equivalent to [`Address::from_octets()`], which cannot be a
+    /// `const fn` because it is generic over the address family.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// const ADDR: Address<Ipv6> = Address::<Ipv6>::from_octets_const([0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    ///
+    /// assert_eq!(ADDR, "2001:db8::1".parse::<Address<Ipv6>>()?);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn from_octets_const(octets: [u8; 16]) -> Self {
+        Self::new(u128::from_be_bytes(octets))
+    }
+
+    /// Parse an [`Address<Ipv6>`] from a string, optionally enclosed in
+    /// square brackets, as found in URLs and socket address strings.
+    ///
+    /// A trailing port is not accepted: see [`strip_port()`] for splitting
+    /// a port from an address string.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input (without brackets) is not a valid IPv6 address.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     Address::<Ipv6>::from_bracketed("[2001:db8::1]")?,
+    ///     "2001:db8::1".parse::<Address<Ipv6>>()?,
+    /// );
+    ///
+    /// assert!(Address::<Ipv6>::from_bracketed("[]").is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    ///
+    /// [`strip_port()`]: crate::concrete::strip_port
+    pub fn from_bracketed(s: &str) -> Result<Self, Error> {
+        strip_brackets(s).unwrap_or(s).parse()
+    }
+
+    /// Parse an [`Address<Ipv6>`] from a string, optionally followed by a
+    /// `%`-separated zone identifier, as described in [RFC 4007].
+    ///
+    /// The zone identifier, if present, is returned alongside the address.
+    /// [`Address<Ipv6>`] does not itself carry a zone, so callers needing to
+    /// display a scoped address should write the zone back out after the
+    /// address, e.g. `format!("{addr}%{zone}")`.
+    ///
+    /// [RFC 4007]: https://tools.ietf.org/html/rfc4007#section-11
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input before the `%` is not a valid IPv6 address, or if
+    /// the zone identifier following the `%` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// let (addr, zone) = Address::<Ipv6>::parse_scoped("fe80::1%eth0")?;
+    /// assert_eq!(addr, "fe80::1".parse::<Address<Ipv6>>()?);
+    /// assert_eq!(zone, Some("eth0"));
+    ///
+    /// assert_eq!(Address::<Ipv6>::parse_scoped("2001:db8::1")?.1, None);
+    /// assert!(Address::<Ipv6>::parse_scoped("fe80::1%").is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn parse_scoped(s: &str) -> Result<(Self, Option<&str>), Error> {
+        parser::ipv6::parse_scoped_addr(s).map(|(addr, zone)| (Self::new(addr), zone))
+    }
+
+    /// Construct the link-local [`Address<Ipv6>`] with a modified EUI-64
+    /// interface identifier derived from a 48-bit MAC address.
+    ///
+    /// The interface identifier is formed by inverting the universal/local
+    /// bit of the MAC address and inserting `ff:fe` between the third and
+    /// fourth octets, as described in [RFC 4291] Appendix A. The result is
+    /// appended to `fe80::/64`.
+    ///
+    /// [RFC 4291]: https://tools.ietf.org/html/rfc4291#appendix-A
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     Address::<Ipv6>::from_eui64([0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde]),
+    ///     "fe80::3656:78ff:fe9a:bcde".parse::<Address<Ipv6>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn from_eui64(mac: [u8; 6]) -> Self {
+        let mut octets = <Ipv6 as Afi>::Octets::default();
+        octets[..2].copy_from_slice(&[0xfe, 0x80]);
+        octets[8..11].copy_from_slice(&mac[..3]);
+        octets[11..13].copy_from_slice(&[0xff, 0xfe]);
+        octets[13..].copy_from_slice(&mac[3..]);
+        octets[8] ^= 0x02;
+        Self::from_octets(octets)
+    }
+
+    /// Construct an [`Address<Ipv6>`] from the network address of `prefix`
+    /// and a 64-bit interface identifier.
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`PrefixLength`][Kind::PrefixLength] is returned if
+    /// the length of `prefix` is greater than 64.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6, Prefix};
+    ///
+    /// let prefix = "2001:db8:f00::/64".parse::<Prefix<Ipv6>>()?;
+    /// assert_eq!(
+    ///     Address::<Ipv6>::from_interface_id(prefix, 0x0123_4567_89ab_cdef)?,
+    ///     "2001:db8:f00::123:4567:89ab:cdef".parse::<Address<Ipv6>>()?,
+    /// );
+    ///
+    /// let too_long = "2001:db8:f00::/96".parse::<Prefix<Ipv6>>()?;
+    /// assert!(Address::<Ipv6>::from_interface_id(too_long, 1).is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn from_interface_id(prefix: Prefix<Ipv6>, iid: u64) -> Result<Self, Error> {
+        if prefix.length().into_primitive() > 64 {
+            return Err(err!(
+                Kind::PrefixLength,
+                "interface identifiers require a prefix no longer than /64"
+            ));
+        }
+        Ok(Self::new(
+            prefix.prefix().into_primitive() | u128::from(iid),
+        ))
+    }
+
+    /// Returns the 48-bit MAC address from which the modified EUI-64
+    /// interface identifier of the address was derived, or [`None`] if the
+    /// interface identifier does not contain the `ff:fe` marker octets.
+    ///
+    /// This is the reverse of the transformation applied by
+    /// [`Self::from_eui64()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     "fe80::3656:78ff:fe9a:bcde"
+    ///         .parse::<Address<Ipv6>>()?
+    ///         .to_eui64_mac(),
+    ///     Some([0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde]),
+    /// );
+    ///
+    /// assert_eq!("fe80::1".parse::<Address<Ipv6>>()?.to_eui64_mac(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use]
+    pub fn to_eui64_mac(&self) -> Option<[u8; 6]> {
+        match self.octets() {
+            [.., oui0, oui1, oui2, 0xff, 0xfe, nic0, nic1, nic2] => {
+                Some([oui0 ^ 0x02, oui1, oui2, nic0, nic1, nic2])
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the low-order 64 bits of the address, i.e. the interface
+    /// identifier, as an integer in host byte order.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// let addr = "2001:db8:f00::3656:78ff:fe9a:bcde".parse::<Address<Ipv6>>()?;
+    ///
+    /// assert_eq!(addr.interface_id(), 0x3656_78ff_fe9a_bcde);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn interface_id(&self) -> u64 {
+        self.into_primitive() as u64
+    }
+
+    /// Returns the high-order 64 bits of the address, i.e. the network
+    /// identifier, as an integer in host byte order.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// let addr = "2001:db8:f00::3656:78ff:fe9a:bcde".parse::<Address<Ipv6>>()?;
+    ///
+    /// assert_eq!(addr.network_id(), 0x2001_0db8_0f00_0000);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn network_id(&self) -> u64 {
+        (self.into_primitive() >> 64) as u64
+    }
+
+    /// Returns a new address with the same network identifier as `self` and
+    /// the interface identifier replaced by `iid`, given in host byte order.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// let addr = "2001:db8:f00::3656:78ff:fe9a:bcde".parse::<Address<Ipv6>>()?;
+    ///
+    /// assert_eq!(
+    ///     addr.with_interface_id(1),
+    ///     "2001:db8:f00::1".parse::<Address<Ipv6>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn with_interface_id(self, iid: u64) -> Self {
+        Self::new((self.into_primitive() & !(u64::MAX as u128)) | iid as u128)
+    }
+
+    /// Returns the solicited-node multicast address corresponding to `self`,
+    /// formed by appending the low-order 24 bits of `self` to the prefix
+    /// `ff02::1:ff00:0/104`.
+    ///
+    /// See [RFC 4291 Section 2.7.1].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     "2001:db8::1:2:3".parse::<Address<Ipv6>>()?.solicited_node(),
+    ///     "ff02::1:ff02:3".parse::<Address<Ipv6>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    ///
+    /// [RFC 4291 Section 2.7.1]: https://tools.ietf.org/html/rfc4291#section-2.7.1
+    #[must_use]
+    pub const fn solicited_node(&self) -> Self {
+        Self::new(SOLICITED_NODE_PREFIX | (self.into_primitive() & 0x00ff_ffff))
+    }
+
+    /// Returns [`true`] if the address is a solicited-node multicast address,
+    /// within `ff02::1:ff00:0/104`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert!("ff02::1:ff02:3"
+    ///     .parse::<Address<Ipv6>>()?
+    ///     .is_solicited_node_multicast());
+    /// assert!(!"ff02::1".parse::<Address<Ipv6>>()?.is_solicited_node_multicast());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn is_solicited_node_multicast(&self) -> bool {
+        self.into_primitive() & !0x00ff_ffff == SOLICITED_NODE_PREFIX
+    }
+
     /// Returns [`true`] if the address is unicast link local.
     ///
     /// This method is provided for compatibility with [`std::net::Ipv6Addr`],
@@ -65,6 +355,119 @@ impl Address<Ipv6> {
         }
     }
 
+    /// Returns the [`MulticastFlags`] of the address if the address is a
+    /// multicast address, or [`None`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// let well_known = "ff02::1".parse::<Address<Ipv6>>()?;
+    /// assert!(!well_known.multicast_flags().unwrap().transient());
+    ///
+    /// let embedded_rp = "ff7e:140:2001:db8:beef:feed::1234".parse::<Address<Ipv6>>()?;
+    /// let flags = embedded_rp.multicast_flags().unwrap();
+    /// assert!(flags.transient() && flags.prefix_based() && flags.rendezvous_point());
+    ///
+    /// assert_eq!("2001:db8::1".parse::<Address<Ipv6>>()?.multicast_flags(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn multicast_flags(&self) -> Option<MulticastFlags> {
+        self.is_multicast()
+            .then(|| MulticastFlags(self.octets()[1] >> 4))
+    }
+
+    /// Returns [`true`] if the address is a 6to4 address, within
+    /// `2002::/16`. See [RFC 3056].
+    ///
+    /// [RFC 3056]: https://tools.ietf.org/html/rfc3056
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert!("2002:c000:204::1".parse::<Address<Ipv6>>()?.is_6to4());
+    /// assert!(!"2001:db8::1".parse::<Address<Ipv6>>()?.is_6to4());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_6to4(&self) -> bool {
+        matches!(self.octets(), [0x20, 0x02, ..])
+    }
+
+    /// Returns the [`Address<Ipv4>`] embedded in bits 16 to 47 of a 6to4
+    /// [`Address<Ipv6>`], or [`None`] if the address is not within
+    /// `2002::/16`.
+    ///
+    /// See also [`Address::<Ipv4>::to_6to4_prefix()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     "2002:c000:204::1".parse::<Address<Ipv6>>()?.to_6to4_ipv4(),
+    ///     Some("192.0.2.4".parse::<Address<Ipv4>>()?),
+    /// );
+    ///
+    /// assert_eq!("2001:db8::1".parse::<Address<Ipv6>>()?.to_6to4_ipv4(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    #[must_use]
+    pub fn to_6to4_ipv4(&self) -> Option<Address<Ipv4>> {
+        match self.octets() {
+            [0x20, 0x02, a, b, c, d, ..] => Some(Address::from_octets([a, b, c, d])),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Ipv6TeredoInfo`][TeredoInfo] embedded in a Teredo
+    /// [`Address<Ipv6>`], or [`None`] if the address is not within
+    /// `2001::/32`. See [RFC 4380].
+    ///
+    /// See also [`Self::to_6to4_ipv4()`] for the extraction of the IPv4
+    /// address embedded in a 6to4 address.
+    ///
+    /// [RFC 4380]: https://tools.ietf.org/html/rfc4380#section-4
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4, Ipv6};
+    ///
+    /// let teredo = "2001:0:4136:e378:8000:63bf:3fff:fdd2"
+    ///     .parse::<Address<Ipv6>>()?
+    ///     .teredo()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(teredo.server(), "65.54.227.120".parse::<Address<Ipv4>>()?);
+    /// assert_eq!(teredo.flags(), 0x8000);
+    /// assert_eq!(teredo.client(), "192.0.2.45".parse::<Address<Ipv4>>()?);
+    /// assert_eq!(teredo.port(), 40000);
+    ///
+    /// assert_eq!("2001:db8::1".parse::<Address<Ipv6>>()?.teredo(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn teredo(&self) -> Option<TeredoInfo> {
+        match self.octets() {
+            [0x20, 0x01, 0x00, 0x00, s0, s1, s2, s3, f0, f1, p0, p1, c0, c1, c2, c3] => {
+                Some(TeredoInfo {
+                    server: Address::from_octets([s0, s1, s2, s3]),
+                    flags: u16::from_be_bytes([f0, f1]),
+                    port: !u16::from_be_bytes([p0, p1]),
+                    client: Address::from_octets([!c0, !c1, !c2, !c3]),
+                })
+            }
+            _ => None,
+        }
+    }
+
     /// Returns a big-endian [`[u16; 8]`] representing the segments of the
     /// address.
     ///
@@ -186,15 +589,87 @@ impl Address<Ipv6> {
             _ => None,
         }
     }
+
+    /// Returns [`true`] if the address is an IPv4-mapped IPv6 address,
+    /// within `::ffff:0:0/96`. See [RFC 4291 Section 2.5.5.2].
+    ///
+    /// [RFC 4291 Section 2.5.5.2]: https://tools.ietf.org/html/rfc4291#section-2.5.5.2
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert!("::ffff:192.0.2.1".parse::<Address<Ipv6>>()?.is_ipv4_mapped());
+    /// assert!(!"::192.0.2.1".parse::<Address<Ipv6>>()?.is_ipv4_mapped());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_ipv4_mapped(&self) -> bool {
+        matches!(
+            self.octets(),
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, ..]
+        )
+    }
+
+    /// Returns [`true`] if the address is a (deprecated) IPv4-compatible IPv6
+    /// address, within `::/96`. See [RFC 4291 Section 2.5.5.1].
+    ///
+    /// As with [`Self::to_ipv4()`], the unspecified address `::` and the
+    /// loopback address `::1` are considered to be IPv4-compatible.
+    ///
+    /// [RFC 4291 Section 2.5.5.1]: https://tools.ietf.org/html/rfc4291#section-2.5.5.1
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert!("::192.0.2.1".parse::<Address<Ipv6>>()?.is_ipv4_compatible());
+    /// assert!(!"::ffff:192.0.2.1".parse::<Address<Ipv6>>()?.is_ipv4_compatible());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_ipv4_compatible(&self) -> bool {
+        matches!(self.octets(), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, ..])
+    }
+}
+
+pub(crate) fn strip_brackets(s: &str) -> Option<&str> {
+    s.strip_prefix('[')
+        .and_then(|inner| inner.strip_suffix(']'))
 }
 
-// TODO: document omission of `non_exhaustive`
 /// IPv6 multicast address scopes, as defined in [RFC 4291].
 ///
 /// See also [`Address::multicast_scope()`].
 ///
+/// Every value of the four-bit scope field maps to a variant, with values
+/// that are not yet assigned represented by [`MulticastScope::Unassigned`].
+/// The enum is therefore deliberately not `#[non_exhaustive]`: the
+/// assignment of a new scope value would be a breaking change to the
+/// meaning of [`MulticastScope::Unassigned`] in any case.
+///
+/// Assigned scopes are ordered by their reach, so that, for example,
+/// [`MulticastScope::LinkLocal`] is less than [`MulticastScope::Global`].
+/// [`MulticastScope::Reserved`] and [`MulticastScope::Unassigned`], which
+/// have no defined reach, are ordered before all assigned scopes.
+///
 /// [RFC 4291]: https://tools.ietf.org/html/rfc4291
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{Address, Ipv6, Ipv6MulticastScope};
+///
+/// let scope = "ff05::2".parse::<Address<Ipv6>>()?.multicast_scope();
+///
+/// assert_eq!(scope, Some(Ipv6MulticastScope::SiteLocal));
+/// assert!(scope < Some(Ipv6MulticastScope::Global));
+/// assert_eq!(scope.unwrap().to_string(), "Site-Local");
+/// # Ok::<(), ip::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum MulticastScope {
     /// Reserved.
     Reserved,
@@ -215,3 +690,117 @@ pub enum MulticastScope {
     /// Global scope.
     Global,
 }
+
+/// The flags field of an IPv6 multicast address, as defined in [RFC 4291]
+/// and updated by [RFC 3306] and [RFC 3956].
+///
+/// See also [`Address::multicast_flags()`].
+///
+/// [RFC 4291]: https://tools.ietf.org/html/rfc4291#section-2.7
+/// [RFC 3306]: https://tools.ietf.org/html/rfc3306#section-4
+/// [RFC 3956]: https://tools.ietf.org/html/rfc3956#section-3
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct MulticastFlags(u8);
+
+impl MulticastFlags {
+    const TRANSIENT: u8 = 0b0001;
+    const PREFIX_BASED: u8 = 0b0010;
+    const RENDEZVOUS_POINT: u8 = 0b0100;
+
+    /// Returns [`true`] if the `T` flag is set, indicating a dynamically
+    /// assigned, rather than well-known, multicast address.
+    #[must_use]
+    pub const fn transient(&self) -> bool {
+        self.0 & Self::TRANSIENT != 0
+    }
+
+    /// Returns [`true`] if the `P` flag is set, indicating a multicast
+    /// address assigned based on a unicast prefix.
+    #[must_use]
+    pub const fn prefix_based(&self) -> bool {
+        self.0 & Self::PREFIX_BASED != 0
+    }
+
+    /// Returns [`true`] if the `R` flag is set, indicating a multicast
+    /// address with an embedded rendezvous point address.
+    #[must_use]
+    pub const fn rendezvous_point(&self) -> bool {
+        self.0 & Self::RENDEZVOUS_POINT != 0
+    }
+}
+
+/// The information embedded in a Teredo IPv6 address, as defined in
+/// [RFC 4380].
+///
+/// See [`Address::teredo()`].
+///
+/// [RFC 4380]: https://tools.ietf.org/html/rfc4380#section-4
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct TeredoInfo {
+    server: Address<Ipv4>,
+    flags: u16,
+    port: u16,
+    client: Address<Ipv4>,
+}
+
+impl TeredoInfo {
+    /// Get the IPv4 address of the Teredo server.
+    #[must_use]
+    pub const fn server(&self) -> Address<Ipv4> {
+        self.server
+    }
+
+    /// Get the Teredo flags field.
+    #[must_use]
+    pub const fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    /// Get the external UDP port of the Teredo client, with the obfuscation
+    /// removed.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Get the external IPv4 address of the Teredo client, with the
+    /// obfuscation removed.
+    #[must_use]
+    pub const fn client(&self) -> Address<Ipv4> {
+        self.client
+    }
+}
+
+impl MulticastScope {
+    /// Returns [`true`] if this is the global multicast scope.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::Ipv6MulticastScope;
+    ///
+    /// assert!(Ipv6MulticastScope::Global.is_global());
+    /// assert!(!Ipv6MulticastScope::OrganizationLocal.is_global());
+    /// ```
+    #[must_use]
+    pub const fn is_global(&self) -> bool {
+        matches!(self, Self::Global)
+    }
+}
+
+impl fmt::Display for MulticastScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Reserved => "Reserved",
+            Self::Unassigned => "Unassigned",
+            Self::InterfaceLocal => "Interface-Local",
+            Self::LinkLocal => "Link-Local",
+            Self::RealmLocal => "Realm-Local",
+            Self::AdminLocal => "Admin-Local",
+            Self::SiteLocal => "Site-Local",
+            Self::OrganizationLocal => "Organization-Local",
+            Self::Global => "Global",
+        };
+        f.write_str(name)
+    }
+}
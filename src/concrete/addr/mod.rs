@@ -1,12 +1,15 @@
-use core::borrow::BorrowMut;
+use core::borrow::{Borrow, BorrowMut};
 use core::fmt;
 use core::str::FromStr;
 
-use super::{impl_try_from_any, AddressRange, PrefixLength};
+use num_traits::{CheckedAdd as _, CheckedSub as _, One as _};
+
+use super::{fnv1a, impl_try_from_any, AddressRange, PrefixLength};
 use crate::{
     any, concrete,
     error::{err, Error, Kind},
     fmt::AddressDisplay,
+    parser::{self, parse_port},
     traits::{
         self,
         primitive::{Address as _, Octets as _},
@@ -20,14 +23,33 @@ pub use self::private::Address;
 
 mod convert;
 mod ops;
+#[cfg(all(feature = "step_trait", nightly))]
+mod step;
 
 mod ipv4;
+pub use self::ipv4::ParseOptions;
+
 mod ipv6;
-pub use self::ipv6::MulticastScope as Ipv6MulticastScope;
+pub(crate) use self::ipv6::strip_brackets;
+pub use self::ipv6::{
+    MulticastFlags as Ipv6MulticastFlags, MulticastScope as Ipv6MulticastScope,
+    TeredoInfo as Ipv6TeredoInfo,
+};
 
 mod range;
 pub use self::range::Range;
 
+#[cfg(feature = "std")]
+mod set;
+#[cfg(feature = "std")]
+pub use self::set::Set;
+
+mod rdns;
+pub(crate) use self::rdns::reverse_dns_afi;
+
+mod special;
+pub use self::special::SpecialPurpose;
+
 impl<A: Afi> Address<A> {
     /// The `localhost` address for address family `A`.
     pub const LOCALHOST: Self = Self::new(A::Primitive::LOCALHOST);
@@ -38,8 +60,14 @@ impl<A: Afi> Address<A> {
     /// The "all-zeros" address for address family `A`.
     pub const ZEROS: Self = Self::new(A::Primitive::ZERO);
 
+    /// The "all-ones" address for address family `A`.
+    pub const ONES: Self = Self::new(A::Primitive::ONES);
+
     /// Construct a new [`Address<A>`] from a big-endian byte-array.
     ///
+    /// Concrete address types also provide a `const` equivalent: see
+    /// [`Address::from_octets_const()`].
+    ///
     /// # Examples
     ///
     /// ``` rust
@@ -116,15 +144,199 @@ impl<A: Afi> Address<A> {
         self.into_primitive().to_be_bytes()
     }
 
+    /// Returns a fingerprint of `self`, suitable for persistent use.
+    ///
+    /// The fingerprint is the 64-bit [FNV-1a] hash of the big-endian
+    /// [`octets()`][Self::octets] of `self`. Unlike the [`Hash`][core::hash::Hash]
+    /// implementation, whose output depends on the [`Hasher`][core::hash::Hasher]
+    /// in use and may change between versions of Rust or of this crate, the
+    /// fingerprint of an address is guaranteed never to change.
+    ///
+    /// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.1".parse::<Address<Ipv4>>()?.fingerprint(),
+    ///     0x492d_47c0_4621_4554,
+    /// );
+    /// assert_eq!(
+    ///     "2001:db8::1".parse::<Address<Ipv6>>()?.fingerprint(),
+    ///     0xf971_61b7_a3be_1c14,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        fnv1a(self.octets().borrow())
+    }
+
+    /// Returns the address immediately following `self`, or [`None`] if
+    /// `self` is [`Self::ONES`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.255".parse::<Address<Ipv4>>()?.successor(),
+    ///     Some("192.0.3.0".parse()?),
+    /// );
+    /// assert_eq!(Address::<Ipv4>::ONES.successor(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn successor(self) -> Option<Self> {
+        self.into_primitive()
+            .checked_add(&A::Primitive::one())
+            .map(Self::new)
+    }
+
+    /// Returns the address immediately preceding `self`, or [`None`] if
+    /// `self` is [`Self::ZEROS`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     "2001:db8:1::".parse::<Address<Ipv6>>()?.predecessor(),
+    ///     Some("2001:db8:0:ffff:ffff:ffff:ffff:ffff".parse()?),
+    /// );
+    /// assert_eq!(Address::<Ipv6>::ZEROS.predecessor(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn predecessor(self) -> Option<Self> {
+        self.into_primitive()
+            .checked_sub(&A::Primitive::one())
+            .map(Self::new)
+    }
+
+    /// Returns an iterator over every entry of the IANA Special-Purpose
+    /// Address Registry that contains `self`.
+    ///
+    /// An address may be contained in several registry entries, for example
+    /// where a more-specific entry is carved out of a larger block. Entries
+    /// are yielded ordered by the first address of each entry, and then
+    /// from least- to most-specific.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::SpecialPurpose, Address, Ipv4, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     "192.0.0.9"
+    ///         .parse::<Address<Ipv4>>()?
+    ///         .special_ranges()
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         SpecialPurpose::ProtocolAssignments,
+    ///         SpecialPurpose::PortControlProtocolAnycast,
+    ///     ],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "2606:4700:4700::1111"
+    ///         .parse::<Address<Ipv6>>()?
+    ///         .special_ranges()
+    ///         .next(),
+    ///     None,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn special_ranges(&self) -> impl Iterator<Item = SpecialPurpose> {
+        let addr = self.into_primitive();
+        A::Primitive::SPECIAL_PURPOSE_RANGES
+            .iter()
+            .filter(move |(_, range)| range.contains(&addr))
+            .map(|(purpose, _)| *purpose)
+    }
+
+    /// Returns the bitwise exclusive-or of `self` and `other`, as the
+    /// integer primitive appropriate to `A`.
+    ///
+    /// Use the [`BitXor`][core::ops::BitXor] implementation instead to get
+    /// the result as an [`Address<A>`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4};
+    ///
+    /// let a = "192.0.2.1".parse::<Address<Ipv4>>()?;
+    /// let b = "192.0.2.254".parse::<Address<Ipv4>>()?;
+    ///
+    /// assert_eq!(a.xor_bits(b), 0xff);
+    /// assert_eq!(a ^ b, "0.0.0.255".parse::<Address<Ipv4>>()?);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn xor_bits(self, other: Self) -> A::Primitive {
+        self.into_primitive() ^ other.into_primitive()
+    }
+
     #[allow(clippy::missing_panics_doc)]
     /// Compute the common length of `self` and another [`Address<A>`].
     ///
     /// See also [`common_length()`].
     pub fn common_length(self, other: Self) -> PrefixLength<A> {
         // ok to unwrap here as long as primitive width invariants hold
-        PrefixLength::<A>::from_primitive((self ^ other).leading_zeros()).unwrap()
+        PrefixLength::<A>::from_primitive(self.xor_bits(other).leading_zeros()).unwrap()
+    }
+
+    /// Returns the longest [`Prefix<A>`][concrete::Prefix] that contains
+    /// both `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4, Prefix};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.1"
+    ///         .parse::<Address<Ipv4>>()?
+    ///         .common_prefix("192.0.2.129".parse()?),
+    ///     "192.0.2.0/24".parse::<Prefix<Ipv4>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn common_prefix(self, other: Self) -> concrete::Prefix<A> {
+        concrete::Prefix::new(self, self.common_length(other))
+    }
+
+    /// Convert `self` into the corresponding [`any::Address`].
+    ///
+    /// This is equivalent to [`any::Address::from()`][From::from], and is the inverse
+    /// of [`any::Address::into_concrete()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Any, Ipv4};
+    ///
+    /// let addr = "192.0.2.1".parse::<Address<Ipv4>>()?;
+    ///
+    /// assert_eq!(addr.into_any(), "192.0.2.1".parse::<Address<Any>>()?);
+    /// assert_eq!(addr.into_any().into_concrete(), Some(addr));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn into_any(self) -> any::Address
+    where
+        any::Address: From<Self>,
+    {
+        self.into()
     }
 }
+
 /// Compute the length, as a [`PrefixLength<A>`], for the common prefixes of
 /// two [`Address<A>`].
 ///
@@ -154,6 +366,61 @@ pub fn common_length<A: Afi>(lhs: Address<A>, rhs: Address<A>) -> PrefixLength<A
     lhs.common_length(rhs)
 }
 
+/// Split a trailing `:port` suffix from an address string, where doing so is
+/// unambiguous.
+///
+/// A port is split from either a bracketed IPv6 address (e.g.
+/// `[2001:db8::1]:8080`) or a string containing exactly one `:` (e.g.
+/// `192.0.2.1:80`). Surrounding brackets are removed from the returned
+/// address substring, so that it can be parsed normally.
+///
+/// Unbracketed strings containing more than one `:` are returned unchanged,
+/// since any trailing `:n` may be part of an IPv6 address. Likewise, if the
+/// port is not a valid decimal [`u16`], the input is returned unchanged.
+///
+/// Nothing is split unless the part before the port is itself an address:
+/// an IPv6 address (optionally with a zone identifier) inside brackets, or
+/// an IPv4 address otherwise. Host names such as `example.com:80` are
+/// returned unchanged.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::concrete::strip_port;
+///
+/// assert_eq!(strip_port("[2001:db8::1]:8080"), ("2001:db8::1", Some(8080)));
+/// assert_eq!(strip_port("[2001:db8::1]"), ("2001:db8::1", None));
+/// assert_eq!(strip_port("192.0.2.1:80"), ("192.0.2.1", Some(80)));
+/// assert_eq!(strip_port("2001:db8::1:80"), ("2001:db8::1:80", None));
+/// assert_eq!(strip_port("example.com:80"), ("example.com:80", None));
+/// ```
+#[must_use]
+pub fn strip_port(s: &str) -> (&str, Option<u16>) {
+    if let Some((addr, tail)) = s
+        .strip_prefix('[')
+        .and_then(|inner| inner.split_once(']'))
+        .filter(|(addr, _)| parser::ipv6::parse_scoped_addr(addr).is_ok())
+    {
+        if tail.is_empty() {
+            return (addr, None);
+        }
+        if let Some(port) = tail
+            .strip_prefix(':')
+            .and_then(|port| parse_port(port).ok())
+        {
+            return (addr, Some(port));
+        }
+    } else if let Some((addr, port)) = s
+        .split_once(':')
+        .filter(|(addr, _)| parser::ipv4::parse_addr(addr).is_ok())
+    {
+        if let Ok(port) = parse_port(port) {
+            return (addr, Some(port));
+        }
+    }
+    (s, None)
+}
+
 impl<A: Afi> traits::Address for Address<A> {
     fn afi(&self) -> concrete::Afi {
         A::as_afi()
@@ -238,6 +505,11 @@ impl<A: Afi> traits::Address for Address<A> {
         self == &Self::UNSPECIFIED
     }
 
+    #[cfg(feature = "std")]
+    fn to_reverse_dns(&self) -> std::string::String {
+        self.reverse_dns_name(A::Primitive::MAX_LENGTH.into())
+    }
+
     #[allow(clippy::option_if_let_else)]
     fn is_unique_local(&self) -> bool {
         if let Some(range) = A::Primitive::ULA_RANGE {
@@ -246,6 +518,22 @@ impl<A: Afi> traits::Address for Address<A> {
             false
         }
     }
+
+    fn is_unicast_link_local(&self) -> bool {
+        match A::as_afi() {
+            concrete::Afi::Ipv4 => false,
+            concrete::Afi::Ipv6 => self.is_link_local(),
+        }
+    }
+
+    #[allow(clippy::option_if_let_else)]
+    fn is_global_unicast(&self) -> bool {
+        if let Some(range) = A::Primitive::GLOBAL_UNICAST_RANGE {
+            AddressRange::from(&range).contains(self)
+        } else {
+            self.is_unicast_global()
+        }
+    }
 }
 
 impl<A: Afi> FromStr for Address<A> {
@@ -298,9 +586,61 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::vec;
+    use std::vec::Vec;
+
+    use std::string::ToString as _;
+
     use super::*;
     use crate::traits::Address as _;
 
+    #[test]
+    fn parse_with_default_options_is_strict() {
+        let options = ParseOptions::default();
+        assert_eq!(options, ParseOptions::new());
+        for input in ["010.0.0.1", "10.0.0.01", "10", "10.0", "10.0.0"] {
+            assert_eq!(
+                Address::<Ipv4>::parse_with(input, options)
+                    .unwrap_err()
+                    .kind(),
+                Kind::ParserError
+            );
+        }
+        assert_eq!(
+            Address::<Ipv4>::parse_with("10.0.0.1", options).unwrap(),
+            "10.0.0.1".parse::<Address<Ipv4>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_with_leading_zeros() {
+        let options = ParseOptions::new().allow_leading_zeros(true);
+        assert_eq!(
+            Address::<Ipv4>::parse_with("010.000.00.001", options).unwrap(),
+            Address::<Ipv4>::from_octets([10, 0, 0, 1])
+        );
+        assert!(Address::<Ipv4>::parse_with("10.0.0", options).is_err());
+        assert!(Address::<Ipv4>::parse_with("0256.0.0.1", options).is_err());
+    }
+
+    #[test]
+    fn parse_with_classful_shorthand() {
+        let options = ParseOptions::new().allow_classful_shorthand(true);
+        for (input, octets) in [
+            ("10", [10, 0, 0, 0]),
+            ("172.16", [172, 16, 0, 0]),
+            ("192.168.1", [192, 168, 1, 0]),
+            ("192.168.1.1", [192, 168, 1, 1]),
+        ] {
+            assert_eq!(
+                Address::<Ipv4>::parse_with(input, options).unwrap(),
+                Address::<Ipv4>::from_octets(octets)
+            );
+        }
+        assert!(Address::<Ipv4>::parse_with("010", options).is_err());
+        assert!(Address::<Ipv4>::parse_with("10.0.0.0.0", options).is_err());
+    }
+
     #[test]
     fn ipv4_broadcast_is_broadcast() {
         assert!("255.255.255.255"
@@ -741,4 +1081,583 @@ mod tests {
             .unwrap()
             .is_unicast_global());
     }
+
+    #[test]
+    fn ipv6_6to4_to_ipv4() {
+        assert_eq!(
+            "2002:c000:0204::1"
+                .parse::<Address<Ipv6>>()
+                .unwrap()
+                .to_6to4_ipv4(),
+            Some("192.0.2.4".parse::<Address<Ipv4>>().unwrap())
+        );
+    }
+
+    #[test]
+    fn ipv6_non_6to4_to_ipv4_is_none() {
+        assert!("2001:db8::1"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .to_6to4_ipv4()
+            .is_none());
+    }
+
+    #[test]
+    fn ipv4_to_6to4_prefix() {
+        assert_eq!(
+            "192.0.2.4"
+                .parse::<Address<Ipv4>>()
+                .unwrap()
+                .to_6to4_prefix(),
+            "2002:c000:0204::/48"
+                .parse::<concrete::Prefix<Ipv6>>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_6to4_round_trip() {
+        let prefix = "2002:c000:0204::/48"
+            .parse::<concrete::Prefix<Ipv6>>()
+            .unwrap();
+        let ipv4 = prefix.prefix().to_6to4_ipv4().unwrap();
+        assert!(prefix.prefix().is_6to4());
+        assert_eq!(ipv4.to_6to4_prefix(), prefix);
+    }
+
+    #[test]
+    fn ipv6_ipv4_mapped_is_not_compatible() {
+        let addr = "::ffff:192.0.2.1".parse::<Address<Ipv6>>().unwrap();
+        assert!(addr.is_ipv4_mapped());
+        assert!(!addr.is_ipv4_compatible());
+    }
+
+    #[test]
+    fn ipv6_ipv4_compatible_is_not_mapped() {
+        let addr = "::192.0.2.1".parse::<Address<Ipv6>>().unwrap();
+        assert!(addr.is_ipv4_compatible());
+        assert!(!addr.is_ipv4_mapped());
+    }
+
+    #[test]
+    fn ipv6_documentation_is_neither_mapped_nor_compatible() {
+        let addr = "2001:db8::c000:201".parse::<Address<Ipv6>>().unwrap();
+        assert!(!addr.is_ipv4_mapped());
+        assert!(!addr.is_ipv4_compatible());
+    }
+
+    #[test]
+    fn ipv6_multicast_scopes_are_ordered() {
+        let scope = |s: &str| {
+            s.parse::<Address<Ipv6>>()
+                .unwrap()
+                .multicast_scope()
+                .unwrap()
+        };
+        assert!(scope("ff0e::1") > scope("ff05::1"));
+        assert!(scope("ff02::1") < scope("ff08::1"));
+        assert_eq!(scope("ff02::1"), scope("ff12::2"));
+        assert!(Ipv6MulticastScope::InterfaceLocal < Ipv6MulticastScope::LinkLocal);
+    }
+
+    #[test]
+    fn ipv6_unassigned_multicast_scopes_are_least() {
+        let unassigned = "ff06::1"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .multicast_scope()
+            .unwrap();
+        assert_eq!(unassigned, Ipv6MulticastScope::Unassigned);
+        assert!(unassigned < Ipv6MulticastScope::InterfaceLocal);
+        assert!(Ipv6MulticastScope::Reserved < unassigned);
+        assert!(!unassigned.is_global());
+    }
+
+    #[test]
+    fn ipv6_multicast_scope_names() {
+        let names = [
+            ("ff00::1", "Reserved"),
+            ("ff01::1", "Interface-Local"),
+            ("ff02::1", "Link-Local"),
+            ("ff03::1", "Realm-Local"),
+            ("ff04::1", "Admin-Local"),
+            ("ff05::1", "Site-Local"),
+            ("ff07::1", "Unassigned"),
+            ("ff08::1", "Organization-Local"),
+            ("ff0e::1", "Global"),
+            ("ff0f::1", "Reserved"),
+        ];
+        for (addr, name) in names {
+            let scope = addr
+                .parse::<Address<Ipv6>>()
+                .unwrap()
+                .multicast_scope()
+                .unwrap();
+            assert_eq!(scope.to_string(), name);
+            assert_eq!(scope.is_global(), name == "Global");
+        }
+    }
+
+    #[test]
+    fn ipv6_link_local_is_unicast_link_local() {
+        let addr = "fe80::1".parse::<Address<Ipv6>>().unwrap();
+        assert!(traits::Address::is_unicast_link_local(&addr));
+        assert!(!addr.is_global_unicast());
+    }
+
+    #[test]
+    fn ipv6_site_local_is_not_unicast_link_local() {
+        let addr = "fec0::1".parse::<Address<Ipv6>>().unwrap();
+        assert!(!traits::Address::is_unicast_link_local(&addr));
+        assert!(!addr.is_global_unicast());
+    }
+
+    #[test]
+    fn ipv6_global_unicast_bounds() {
+        for addr in ["2000::1", "3fff:ffff::1", "2001:db8::1"] {
+            assert!(addr.parse::<Address<Ipv6>>().unwrap().is_global_unicast());
+        }
+        for addr in ["1fff:ffff::1", "4000::1", "ff0e::1"] {
+            assert!(!addr.parse::<Address<Ipv6>>().unwrap().is_global_unicast());
+        }
+    }
+
+    #[test]
+    fn ipv4_global_unicast_is_unicast_global() {
+        for addr in ["1.1.1.1", "192.168.1.1", "224.0.1.1", "169.254.0.1"] {
+            let addr = addr.parse::<Address<Ipv4>>().unwrap();
+            assert_eq!(addr.is_global_unicast(), addr.is_unicast_global());
+            assert!(!addr.is_unicast_link_local());
+        }
+    }
+
+    #[test]
+    fn ipv6_well_known_multicast_flags() {
+        let flags = "ff05::1:3"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .multicast_flags()
+            .unwrap();
+        assert!(!flags.transient());
+        assert!(!flags.prefix_based());
+        assert!(!flags.rendezvous_point());
+    }
+
+    #[test]
+    fn ipv6_prefix_based_multicast_flags() {
+        let flags = "ff3e:30:2001:db8::1"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .multicast_flags()
+            .unwrap();
+        assert!(flags.transient());
+        assert!(flags.prefix_based());
+        assert!(!flags.rendezvous_point());
+    }
+
+    #[test]
+    fn ipv6_unicast_has_no_multicast_flags() {
+        assert_eq!(
+            "fe80::1"
+                .parse::<Address<Ipv6>>()
+                .unwrap()
+                .multicast_flags(),
+            None
+        );
+    }
+
+    #[test]
+    fn ipv6_teredo_info() {
+        let info = "2001:0:4136:e378:8000:63bf:3fff:fdd2"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .teredo()
+            .unwrap();
+        assert_eq!(
+            info.server(),
+            "65.54.227.120".parse::<Address<Ipv4>>().unwrap()
+        );
+        assert_eq!(info.flags(), 0x8000);
+        assert_eq!(info.port(), 40000);
+        assert_eq!(
+            info.client(),
+            "192.0.2.45".parse::<Address<Ipv4>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_teredo_all_ones_client() {
+        let info = "2001::".parse::<Address<Ipv6>>().unwrap().teredo().unwrap();
+        assert_eq!(info.port(), u16::MAX);
+        assert_eq!(info.client(), Address::<Ipv4>::BROADCAST);
+    }
+
+    #[test]
+    fn ipv6_non_teredo_is_none() {
+        assert!("2001:1::1"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .teredo()
+            .is_none());
+        assert!("2002:c000:204::1"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .teredo()
+            .is_none());
+    }
+
+    #[test]
+    fn ipv4_segments() {
+        assert_eq!(Address::<Ipv4>::BROADCAST.segments(), [0xffff, 0xffff]);
+        assert_eq!(
+            "10.1.255.0".parse::<Address<Ipv4>>().unwrap().segments(),
+            [0x0a01, 0xff00]
+        );
+    }
+
+    #[test]
+    fn strip_port_from_bracketed_ipv6() {
+        assert_eq!(
+            strip_port("[2001:db8::1]:8080"),
+            ("2001:db8::1", Some(8080))
+        );
+    }
+
+    #[test]
+    fn strip_brackets_without_port() {
+        assert_eq!(strip_port("[2001:db8::1]"), ("2001:db8::1", None));
+    }
+
+    #[test]
+    fn strip_port_from_ipv4() {
+        assert_eq!(strip_port("192.0.2.1:80"), ("192.0.2.1", Some(80)));
+    }
+
+    #[test]
+    fn strip_port_from_bare_ipv6_is_noop() {
+        assert_eq!(strip_port("2001:db8::1:80"), ("2001:db8::1:80", None));
+        assert_eq!(strip_port("::1"), ("::1", None));
+    }
+
+    #[test]
+    fn strip_port_invalid_port_is_noop() {
+        assert_eq!(strip_port("192.0.2.1:65536"), ("192.0.2.1:65536", None));
+        assert_eq!(strip_port("[::1]:http"), ("[::1]:http", None));
+    }
+
+    #[test]
+    fn strip_port_from_empty_brackets_is_noop() {
+        assert_eq!(strip_port("[]:80"), ("[]:80", None));
+        assert!(strip_port("[]").0.parse::<Address<Ipv6>>().is_err());
+    }
+
+    #[test]
+    fn strip_port_ignores_host_names() {
+        for input in [
+            "foo:80",
+            "example.com:8080",
+            "[foo]:80",
+            "[192.0.2.1]:80",
+            "[foo]",
+        ] {
+            assert_eq!(strip_port(input), (input, None));
+        }
+        assert_eq!(strip_port("[fe80::1%eth0]:80"), ("fe80::1%eth0", Some(80)));
+    }
+
+    #[test]
+    fn ipv6_from_bracketed() {
+        assert_eq!(
+            Address::<Ipv6>::from_bracketed("[2001:db8::1]").unwrap(),
+            "2001:db8::1".parse::<Address<Ipv6>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_from_bracketed_accepts_bare() {
+        assert_eq!(
+            Address::<Ipv6>::from_bracketed("2001:db8::1").unwrap(),
+            "2001:db8::1".parse::<Address<Ipv6>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_from_bracketed_rejects_empty_brackets() {
+        assert_eq!(
+            Address::<Ipv6>::from_bracketed("[]").unwrap_err().kind(),
+            Kind::ParserError
+        );
+    }
+
+    #[test]
+    fn ipv6_from_bracketed_rejects_port() {
+        assert!(Address::<Ipv6>::from_bracketed("[2001:db8::1]:8080").is_err());
+    }
+
+    #[test]
+    fn ipv6_from_eui64_rfc4291_example() {
+        assert_eq!(
+            Address::<Ipv6>::from_eui64([0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde]),
+            "fe80::3656:78ff:fe9a:bcde"
+                .parse::<Address<Ipv6>>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_from_eui64_local_mac() {
+        assert_eq!(
+            Address::<Ipv6>::from_eui64([0x02, 0x00, 0x5e, 0x10, 0x00, 0x01]),
+            "fe80::5eff:fe10:1".parse::<Address<Ipv6>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_eui64_mac_round_trip() {
+        let mac = [0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde];
+        assert_eq!(Address::<Ipv6>::from_eui64(mac).to_eui64_mac(), Some(mac));
+    }
+
+    #[test]
+    fn ipv6_non_eui64_has_no_mac() {
+        assert!("2001:db8::1"
+            .parse::<Address<Ipv6>>()
+            .unwrap()
+            .to_eui64_mac()
+            .is_none());
+    }
+
+    #[test]
+    fn ipv6_from_interface_id() {
+        let prefix = "2001:db8::/48".parse().unwrap();
+        assert_eq!(
+            Address::<Ipv6>::from_interface_id(prefix, 0x3656_78ff_fe9a_bcde).unwrap(),
+            "2001:db8::3656:78ff:fe9a:bcde"
+                .parse::<Address<Ipv6>>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_from_interface_id_rejects_long_prefix() {
+        let prefix = "2001:db8::/65".parse().unwrap();
+        assert_eq!(
+            Address::<Ipv6>::from_interface_id(prefix, 1)
+                .unwrap_err()
+                .kind(),
+            Kind::PrefixLength
+        );
+    }
+
+    #[test]
+    fn ipv4_common_prefix() {
+        let x = "10.1.2.3".parse::<Address<Ipv4>>().unwrap();
+        let y = "10.1.3.3".parse::<Address<Ipv4>>().unwrap();
+        assert_eq!(
+            x.common_prefix(y),
+            "10.1.2.0/23".parse::<concrete::Prefix<Ipv4>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_common_prefix_with_self() {
+        let x = "2001:db8::1".parse::<Address<Ipv6>>().unwrap();
+        assert_eq!(x.common_prefix(x), concrete::Prefix::from(x));
+    }
+
+    #[test]
+    fn ipv6_common_prefix_disjoint() {
+        let x = "2001:db8::1".parse::<Address<Ipv6>>().unwrap();
+        let y = "fe80::1".parse::<Address<Ipv6>>().unwrap();
+        assert_eq!(x.common_prefix(y), concrete::Prefix::DEFAULT);
+    }
+
+    #[test]
+    fn ipv4_reverse_dns() {
+        assert_eq!(
+            "10.20.30.40"
+                .parse::<Address<Ipv4>>()
+                .unwrap()
+                .to_reverse_dns(),
+            "40.30.20.10.in-addr.arpa."
+        );
+    }
+
+    #[test]
+    fn ipv6_reverse_dns_nibble_order() {
+        assert_eq!(
+            "2001:db8:abcd:12::fe1"
+                .parse::<Address<Ipv6>>()
+                .unwrap()
+                .to_reverse_dns(),
+            "1.e.f.0.0.0.0.0.0.0.0.0.0.0.0.0.2.1.0.0.d.c.b.a.8.b.d.0.1.0.0.2.ip6.arpa."
+        );
+    }
+
+    #[test]
+    fn ipv6_reverse_dns_has_32_nibble_labels() {
+        assert_eq!(
+            Address::<Ipv6>::ONES
+                .to_reverse_dns()
+                .split_terminator('.')
+                .filter(|label| *label == "f")
+                .count(),
+            32
+        );
+    }
+
+    #[test]
+    fn ipv6_solicited_node() {
+        let addr = "2001:db8::1:2:3".parse::<Address<Ipv6>>().unwrap();
+        let snma = "ff02::1:ff02:3".parse::<Address<Ipv6>>().unwrap();
+        assert_eq!(addr.solicited_node(), snma);
+        assert!(snma.is_solicited_node_multicast());
+        assert!(snma.is_multicast());
+        assert_eq!(snma.solicited_node(), snma);
+    }
+
+    #[test]
+    fn ipv6_unicast_is_not_solicited_node_multicast() {
+        let addr = "2001:db8::1:ff02:3".parse::<Address<Ipv6>>().unwrap();
+        assert!(!addr.is_solicited_node_multicast());
+    }
+
+    #[test]
+    fn ipv6_interface_id_split() {
+        let addr = "2001:db8:1:2:3:4:5:6".parse::<Address<Ipv6>>().unwrap();
+        assert_eq!(addr.network_id(), 0x2001_0db8_0001_0002);
+        assert_eq!(addr.interface_id(), 0x0003_0004_0005_0006);
+    }
+
+    #[test]
+    fn ipv6_with_interface_id() {
+        let addr = "2001:db8:1:2:3:4:5:6".parse::<Address<Ipv6>>().unwrap();
+        assert_eq!(
+            addr.with_interface_id(0x3656_78ff_fe9a_bcde),
+            "2001:db8:1:2:3656:78ff:fe9a:bcde"
+                .parse::<Address<Ipv6>>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_interface_id_round_trip() {
+        let addr = "fe80::3656:78ff:fe9a:bcde"
+            .parse::<Address<Ipv6>>()
+            .unwrap();
+        assert_eq!(
+            Address::<Ipv6>::UNSPECIFIED.with_interface_id(addr.interface_id()),
+            "::3656:78ff:fe9a:bcde".parse::<Address<Ipv6>>().unwrap()
+        );
+        assert_eq!(addr.with_interface_id(addr.interface_id()), addr);
+    }
+
+    fn special_ranges<A: Afi>(addr: &str) -> Vec<SpecialPurpose> {
+        addr.parse::<Address<A>>()
+            .unwrap()
+            .special_ranges()
+            .collect()
+    }
+
+    #[test]
+    fn ipv4_global_unicast_has_no_special_ranges() {
+        assert!(special_ranges::<Ipv4>("1.1.1.1").is_empty());
+    }
+
+    #[test]
+    fn ipv6_global_unicast_has_no_special_ranges() {
+        assert!(special_ranges::<Ipv6>("2606:4700:4700::1111").is_empty());
+    }
+
+    #[test]
+    fn ipv4_private_has_one_special_range() {
+        assert_eq!(
+            special_ranges::<Ipv4>("172.16.10.65"),
+            vec![SpecialPurpose::Private]
+        );
+    }
+
+    #[test]
+    fn ipv6_documentation_has_one_special_range() {
+        assert_eq!(
+            special_ranges::<Ipv6>("2001:db8::1"),
+            vec![SpecialPurpose::Documentation]
+        );
+    }
+
+    #[test]
+    fn ipv4_unspecified_has_two_special_ranges() {
+        assert_eq!(
+            special_ranges::<Ipv4>("0.0.0.0"),
+            vec![SpecialPurpose::ThisNetwork, SpecialPurpose::ThisHost]
+        );
+    }
+
+    #[test]
+    fn ipv4_pcp_anycast_has_two_special_ranges() {
+        assert_eq!(
+            special_ranges::<Ipv4>("192.0.0.9"),
+            vec![
+                SpecialPurpose::ProtocolAssignments,
+                SpecialPurpose::PortControlProtocolAnycast
+            ]
+        );
+    }
+
+    #[test]
+    fn ipv4_service_continuity_has_two_special_ranges() {
+        assert_eq!(
+            special_ranges::<Ipv4>("192.0.0.1"),
+            vec![
+                SpecialPurpose::ProtocolAssignments,
+                SpecialPurpose::ServiceContinuity
+            ]
+        );
+    }
+
+    #[test]
+    fn ipv4_proto_assignment_has_one_special_range() {
+        assert_eq!(
+            special_ranges::<Ipv4>("192.0.0.100"),
+            vec![SpecialPurpose::ProtocolAssignments]
+        );
+    }
+
+    #[test]
+    fn ipv4_broadcast_has_two_special_ranges() {
+        assert_eq!(
+            special_ranges::<Ipv4>("255.255.255.255"),
+            vec![SpecialPurpose::Reserved, SpecialPurpose::LimitedBroadcast]
+        );
+    }
+
+    #[test]
+    fn ipv6_teredo_has_two_special_ranges() {
+        assert_eq!(
+            special_ranges::<Ipv6>("2001::1"),
+            vec![SpecialPurpose::ProtocolAssignments, SpecialPurpose::Teredo]
+        );
+    }
+
+    #[test]
+    fn ipv6_turn_anycast_has_two_special_ranges() {
+        assert_eq!(
+            special_ranges::<Ipv6>("2001:1::2"),
+            vec![
+                SpecialPurpose::ProtocolAssignments,
+                SpecialPurpose::TraversalUsingRelaysAnycast
+            ]
+        );
+    }
+
+    #[test]
+    fn ipv6_orchidv2_has_two_special_ranges() {
+        assert_eq!(
+            special_ranges::<Ipv6>("2001:20::1"),
+            vec![
+                SpecialPurpose::ProtocolAssignments,
+                SpecialPurpose::OrchidV2
+            ]
+        );
+    }
 }
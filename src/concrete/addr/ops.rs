@@ -1,4 +1,6 @@
-use core::ops::{Add, BitAnd, BitAndAssign, BitOr, BitXor};
+use core::ops::{
+    Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr,
+};
 
 use num_traits::CheckedAdd;
 
@@ -6,7 +8,10 @@ use super::{
     super::{mask_types::Type, Mask},
     Address,
 };
-use crate::traits::Afi;
+use crate::traits::{
+    primitive::{self, Address as _},
+    Afi,
+};
 
 impl<A: Afi, T: Type> BitAnd<Mask<T, A>> for Address<A> {
     type Output = Self;
@@ -43,10 +48,162 @@ impl<A: Afi, T: Type> Add<Mask<T, A>> for Address<A> {
     }
 }
 
+impl<A: Afi> BitAnd<Self> for Address<A> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::new(self.into_primitive() & rhs.into_primitive())
+    }
+}
+
+impl<A: Afi> BitOr<Self> for Address<A> {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self::new(self.into_primitive() | rhs.into_primitive())
+    }
+}
+
+impl<A: Afi, T> BitOrAssign<T> for Address<A>
+where
+    Self: BitOr<T, Output = Self>,
+{
+    fn bitor_assign(&mut self, rhs: T) {
+        *self = self.bitor(rhs);
+    }
+}
+
 impl<A: Afi> BitXor<Self> for Address<A> {
-    type Output = A::Primitive;
+    type Output = Self;
 
     fn bitxor(self, rhs: Self) -> Self::Output {
-        self.into_primitive() ^ rhs.into_primitive()
+        Self::new(self.xor_bits(rhs))
+    }
+}
+
+impl<A: Afi, T> BitXorAssign<T> for Address<A>
+where
+    Self: BitXor<T, Output = Self>,
+{
+    fn bitxor_assign(&mut self, rhs: T) {
+        *self = self.bitxor(rhs);
+    }
+}
+
+impl<A: Afi> Not for Address<A> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::new(!self.into_primitive())
+    }
+}
+
+/// Shift the bits of an [`Address<A>`] towards the most significant bit.
+///
+/// Shifting by the width of the address family or more yields
+/// [`Address::ZEROS`], rather than overflowing.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{Address, Ipv4};
+///
+/// let addr = "0.0.2.1".parse::<Address<Ipv4>>()?;
+///
+/// assert_eq!(addr << 8, "0.2.1.0".parse::<Address<Ipv4>>()?);
+/// assert_eq!(addr << 32, Address::<Ipv4>::ZEROS);
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> Shl<u8> for Address<A>
+where
+    A::Primitive: primitive::Address<A, Length = u8>,
+{
+    type Output = Self;
+
+    fn shl(self, rhs: u8) -> Self::Output {
+        if rhs < A::Primitive::MAX_LENGTH {
+            Self::new(self.into_primitive() << rhs)
+        } else {
+            Self::ZEROS
+        }
+    }
+}
+
+/// Shift the bits of an [`Address<A>`] towards the least significant bit.
+///
+/// Shifting by the width of the address family or more yields
+/// [`Address::ZEROS`], rather than overflowing.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{Address, Ipv6};
+///
+/// let addr = "2001:db8::".parse::<Address<Ipv6>>()?;
+///
+/// assert_eq!(addr >> 96, "::2001:db8".parse::<Address<Ipv6>>()?);
+/// assert_eq!(addr >> 128, Address::<Ipv6>::ZEROS);
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> Shr<u8> for Address<A>
+where
+    A::Primitive: primitive::Address<A, Length = u8>,
+{
+    type Output = Self;
+
+    fn shr(self, rhs: u8) -> Self::Output {
+        if rhs < A::Primitive::MAX_LENGTH {
+            Self::new(self.into_primitive() >> rhs)
+        } else {
+            Self::ZEROS
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{arbitrary::any, proptest};
+
+    use super::*;
+    use crate::{Ipv4, Ipv6};
+
+    proptest! {
+        #[test]
+        fn ipv4_bitwise_ops(a in any::<Address<Ipv4>>(), b in any::<Address<Ipv4>>()) {
+            assert_eq!((a & b).into_primitive(), a.into_primitive() & b.into_primitive());
+            assert_eq!((a | b).into_primitive(), a.into_primitive() | b.into_primitive());
+            assert_eq!((a ^ b).into_primitive(), a.xor_bits(b));
+            assert_eq!(!!a, a);
+            assert_eq!(a ^ b ^ b, a);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv6_bitwise_ops(a in any::<Address<Ipv6>>(), b in any::<Address<Ipv6>>()) {
+            assert_eq!((a & b).into_primitive(), a.into_primitive() & b.into_primitive());
+            assert_eq!((a | b).into_primitive(), a.into_primitive() | b.into_primitive());
+            assert_eq!((a ^ b).into_primitive(), a.xor_bits(b));
+            assert_eq!(!a & a, Address::ZEROS);
+            assert_eq!(!a | a, Address::ONES);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv4_shifts(a in any::<Address<Ipv4>>(), n in 0u8..=u8::MAX) {
+            let expected = |shifted: Option<u32>| shifted.filter(|_| n < 32).unwrap_or_default();
+            assert_eq!((a << n).into_primitive(), expected(a.into_primitive().checked_shl(n.into())));
+            assert_eq!((a >> n).into_primitive(), expected(a.into_primitive().checked_shr(n.into())));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv6_shifts(a in any::<Address<Ipv6>>(), n in 0u8..=u8::MAX) {
+            let expected = |shifted: Option<u128>| shifted.filter(|_| n < 128).unwrap_or_default();
+            assert_eq!((a << n).into_primitive(), expected(a.into_primitive().checked_shl(n.into())));
+            assert_eq!((a >> n).into_primitive(), expected(a.into_primitive().checked_shr(n.into())));
+        }
     }
 }
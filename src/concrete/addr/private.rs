@@ -1,6 +1,13 @@
 use crate::traits::Afi;
 
 /// An IP address of address family `A`.
+///
+/// # Hashing
+///
+/// The values fed to a [`Hasher`][core::hash::Hasher] by the [`Hash`]
+/// implementation are not guaranteed to remain the same across versions of
+/// this crate. Use [`Address::fingerprint()`] where a hash that is stable
+/// over time is required, for example when persisting shard assignments.
 #[derive(Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Address<A: Afi>(A::Primitive);
 
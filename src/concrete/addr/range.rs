@@ -1,7 +1,15 @@
-use core::ops::RangeInclusive;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+use core::str::FromStr;
 
-use super::Address;
-use crate::traits::Afi;
+use super::{impl_try_from_any, Address};
+use crate::{
+    any,
+    concrete::{Ipv4, Ipv6, Prefix, PrefixLength},
+    error::{err, Error, Kind},
+    traits::{Afi, Prefix as _, PrefixLength as _},
+};
 
 /// An inclusive range of [`Address<A>`].
 ///
@@ -10,30 +18,826 @@ use crate::traits::Afi;
 /// ``` rust
 /// use ip::{concrete::AddressRange, Ipv4};
 ///
-/// let range = AddressRange::<Ipv4>::new("10.250.0.0".parse()?, "10.252.255.255".parse()?);
+/// let range = AddressRange::<Ipv4>::new("10.250.0.0".parse()?, "10.252.255.255".parse()?)?;
 ///
 /// let mid = "10.251.127.1".parse()?;
 ///
 /// assert!(range.contains(&mid));
 /// # Ok::<(), ip::Error>(())
 /// ```
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct Range<A: Afi>(RangeInclusive<Address<A>>);
 
 impl<A: Afi> Range<A> {
     /// Construct a new [`AddressRange<A>`][Self] from `start` and `end` bounds.
-    pub const fn new(start: Address<A>, end: Address<A>) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`AddressRangeBounds`][Kind::AddressRangeBounds] is
+    /// returned if `start` is greater than `end`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, error::Kind, Ipv4};
+    ///
+    /// let err = AddressRange::<Ipv4>::new("10.0.0.2".parse()?, "10.0.0.1".parse()?).unwrap_err();
+    /// assert_eq!(err.kind(), Kind::AddressRangeBounds);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn new(start: Address<A>, end: Address<A>) -> Result<Self, Error> {
+        if start <= end {
+            Ok(Self::new_unchecked(start, end))
+        } else {
+            Err(err!(Kind::AddressRangeBounds))
+        }
+    }
+
+    /// Construct a new [`AddressRange<A>`][Self] from `start` and `end` bounds,
+    /// without checking that `start <= end`.
+    ///
+    /// If `start` is greater than `end` then the resulting range will be
+    /// empty. Prefer [`Self::new()`] outside of `const` contexts.
+    pub const fn new_unchecked(start: Address<A>, end: Address<A>) -> Self {
         Self(start..=end)
     }
 
+    /// Returns the lower bound of the range.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Address, Ipv4};
+    ///
+    /// let range = "10.0.0.0-10.0.0.255".parse::<AddressRange<Ipv4>>()?;
+    ///
+    /// assert_eq!(range.start(), "10.0.0.0".parse::<Address<Ipv4>>()?);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn start(&self) -> Address<A> {
+        *self.0.start()
+    }
+
+    /// Returns the upper bound of the range.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Address, Ipv4};
+    ///
+    /// let range = "10.0.0.0-10.0.0.255".parse::<AddressRange<Ipv4>>()?;
+    ///
+    /// assert_eq!(range.end(), "10.0.0.255".parse::<Address<Ipv4>>()?);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub const fn end(&self) -> Address<A> {
+        *self.0.end()
+    }
+
     /// Returns [`true`] if `addr` is contained in the range.
     pub fn contains(&self, addr: &Address<A>) -> bool {
         self.0.contains(addr)
     }
+
+    /// Returns [`true`] if every address in `other` is contained in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Ipv4};
+    ///
+    /// let range = "10.0.0.0-10.0.0.255".parse::<AddressRange<Ipv4>>()?;
+    ///
+    /// assert!(range.contains_range(&"10.0.0.16-10.0.0.31".parse()?));
+    /// assert!(!range.contains_range(&"10.0.0.16-10.0.1.31".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn contains_range(&self, other: &Self) -> bool {
+        self.start() <= other.start() && other.end() <= self.end()
+    }
+
+    /// Returns [`true`] if `self` and `other` do not overlap, but one begins
+    /// immediately after the other ends.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Ipv4};
+    ///
+    /// let range = "10.0.0.0-10.0.0.99".parse::<AddressRange<Ipv4>>()?;
+    ///
+    /// assert!(range.is_adjacent(&"10.0.0.100-10.0.0.255".parse()?));
+    /// assert!(!range.is_adjacent(&"10.0.0.101-10.0.0.255".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        self.end().successor() == Some(other.start())
+            || other.end().successor() == Some(self.start())
+    }
+
+    /// Returns the range of addresses contained in both `self` and `other`,
+    /// or [`None`] if they do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Ipv4};
+    ///
+    /// let a = "10.0.0.0-10.0.0.99".parse::<AddressRange<Ipv4>>()?;
+    /// let b = "10.0.0.50-10.0.0.255".parse::<AddressRange<Ipv4>>()?;
+    ///
+    /// assert_eq!(a.intersection(&b), Some("10.0.0.50-10.0.0.99".parse()?));
+    /// assert_eq!(a.intersection(&"10.0.1.0-10.0.1.255".parse()?), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start().max(other.start()), self.end().min(other.end())).ok()
+    }
+
+    /// Returns the range of addresses contained in either `self` or `other`,
+    /// if `self` and `other` overlap or are adjacent.
+    ///
+    /// Returns [`None`] if the union would not be contiguous.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Ipv4};
+    ///
+    /// let a = "10.0.0.0-10.0.0.99".parse::<AddressRange<Ipv4>>()?;
+    /// let b = "10.0.0.100-10.0.0.255".parse::<AddressRange<Ipv4>>()?;
+    ///
+    /// assert_eq!(a.union(&b), Some("10.0.0.0-10.0.0.255".parse()?));
+    /// assert_eq!(a.union(&"10.0.1.0-10.0.1.255".parse()?), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        (self.intersection(other).is_some() || self.is_adjacent(other)).then(|| {
+            Self::new_unchecked(self.start().min(other.start()), self.end().max(other.end()))
+        })
+    }
+
+    /// Returns an iterator over the [`Address<A>`] contained in the range,
+    /// from `start` to `end` inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Address, Ipv4};
+    ///
+    /// let range = AddressRange::<Ipv4>::new("10.0.0.254".parse()?, "10.0.1.1".parse()?)?;
+    ///
+    /// assert_eq!(
+    ///     range.iter().collect::<Vec<_>>(),
+    ///     vec![
+    ///         "10.0.0.254".parse::<Address<Ipv4>>()?,
+    ///         "10.0.0.255".parse()?,
+    ///         "10.0.1.0".parse()?,
+    ///         "10.0.1.1".parse()?,
+    ///     ],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn iter(&self) -> IntoIter<A> {
+        self.clone().into_iter()
+    }
+
+    /// Returns an iterator over the minimal set of [`Prefix<A>`] that exactly
+    /// cover the range, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::AddressRange, Ipv4, Prefix};
+    ///
+    /// let range = "10.0.0.3-10.0.0.10".parse::<AddressRange<Ipv4>>()?;
+    ///
+    /// assert_eq!(
+    ///     range.to_prefixes().collect::<Vec<_>>(),
+    ///     vec![
+    ///         "10.0.0.3/32".parse::<Prefix<Ipv4>>()?,
+    ///         "10.0.0.4/30".parse()?,
+    ///         "10.0.0.8/31".parse()?,
+    ///         "10.0.0.10/32".parse()?,
+    ///     ],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn to_prefixes(&self) -> impl Iterator<Item = Prefix<A>> {
+        Prefixes {
+            remaining: (!self.0.is_empty()).then(|| (self.start(), self.end())),
+        }
+    }
+}
+
+impl<A: Afi> IntoIterator for Range<A> {
+    type Item = Address<A>;
+    type IntoIter = IntoIter<A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let remaining = (!self.0.is_empty()).then(|| self.0.into_inner());
+        IntoIter { remaining }
+    }
+}
+
+impl<A: Afi> IntoIterator for &Range<A> {
+    type Item = Address<A>;
+    type IntoIter = IntoIter<A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the [`Address<A>`] contained in an
+/// [`AddressRange<A>`][Range].
+#[derive(Clone, Debug)]
+pub struct IntoIter<A: Afi> {
+    remaining: Option<(Address<A>, Address<A>)>,
+}
+
+impl<A: Afi> Iterator for IntoIter<A> {
+    type Item = Address<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.remaining.take()?;
+        if start < end {
+            self.remaining = start.successor().map(|next| (next, end));
+        }
+        Some(start)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.map_or((0, Some(0)), |(start, end)| {
+            (end.into_primitive() - start.into_primitive())
+                .try_into()
+                .ok()
+                .and_then(|diff: usize| diff.checked_add(1))
+                .map_or((usize::MAX, None), |len| (len, Some(len)))
+        })
+    }
+}
+
+impl<A: Afi> DoubleEndedIterator for IntoIter<A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.remaining.take()?;
+        if start < end {
+            self.remaining = end.predecessor().map(|prev| (start, prev));
+        }
+        Some(end)
+    }
+}
+
+impl<A: Afi> FusedIterator for IntoIter<A> {}
+
+#[derive(Clone, Debug)]
+struct Prefixes<A: Afi> {
+    remaining: Option<(Address<A>, Address<A>)>,
+}
+
+impl<A: Afi> Iterator for Prefixes<A> {
+    type Item = Prefix<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.remaining.take()?;
+        // Find the shortest prefix that starts at `start` and ends on or
+        // before `end`. This always terminates, at worst with a host prefix.
+        let mut length = PrefixLength::MIN;
+        let prefix = loop {
+            let prefix = Prefix::new(start, length);
+            if prefix.network() == start && prefix.broadcast() <= end {
+                break prefix;
+            }
+            length = length.increment().ok()?;
+        };
+        if prefix.broadcast() < end {
+            self.remaining = prefix.broadcast().successor().map(|next| (next, end));
+        }
+        Some(prefix)
+    }
 }
 
+impl<A: Afi> FusedIterator for Prefixes<A> {}
+
 impl<A: Afi> From<&RangeInclusive<A::Primitive>> for Range<A> {
     fn from(range: &RangeInclusive<A::Primitive>) -> Self {
-        Self::new(Address::new(*range.start()), Address::new(*range.end()))
+        Self::new_unchecked(Address::new(*range.start()), Address::new(*range.end()))
+    }
+}
+
+/// Construct an [`AddressRange<A>`][Range] from native range syntax.
+///
+/// # Errors
+///
+/// As with [`Range::new()`], an error of kind
+/// [`AddressRangeBounds`][Kind::AddressRangeBounds] is returned if `start` is
+/// greater than `end`.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::AddressRange, error::Kind, Address, Ipv4};
+///
+/// let start = "192.0.2.10".parse::<Address<Ipv4>>()?;
+/// let end = "192.0.2.20".parse::<Address<Ipv4>>()?;
+/// let range: AddressRange<Ipv4> = (start..=end).try_into()?;
+///
+/// assert_eq!(range, "192.0.2.10-192.0.2.20".parse()?);
+/// assert_eq!(
+///     AddressRange::try_from(end..=start).unwrap_err().kind(),
+///     Kind::AddressRangeBounds,
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> TryFrom<RangeInclusive<Address<A>>> for Range<A> {
+    type Error = Error;
+
+    fn try_from(range: RangeInclusive<Address<A>>) -> Result<Self, Self::Error> {
+        let (start, end) = range.into_inner();
+        Self::new(start, end)
+    }
+}
+
+/// # Examples
+///
+/// ``` rust
+/// use core::ops::RangeBounds;
+///
+/// use ip::{concrete::AddressRange, Address, Ipv4};
+///
+/// fn count<R: RangeBounds<Address<Ipv4>>>(range: &R, addrs: &[Address<Ipv4>]) -> usize {
+///     addrs.iter().filter(|&addr| range.contains(addr)).count()
+/// }
+///
+/// let addrs = ["192.0.2.1".parse()?, "192.0.2.10".parse()?, "192.0.2.20".parse()?];
+/// let range = "192.0.2.5-192.0.2.20".parse::<AddressRange<Ipv4>>()?;
+///
+/// assert_eq!(count(&range, &addrs), 2);
+/// assert_eq!(count(&(addrs[0]..addrs[2]), &addrs), 2);
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> RangeBounds<Address<A>> for Range<A> {
+    fn start_bound(&self) -> Bound<&Address<A>> {
+        self.0.start_bound()
+    }
+
+    fn end_bound(&self) -> Bound<&Address<A>> {
+        self.0.end_bound()
+    }
+}
+
+impl<A: Afi> From<Prefix<A>> for Range<A> {
+    fn from(prefix: Prefix<A>) -> Self {
+        Self::new_unchecked(prefix.network(), prefix.broadcast())
+    }
+}
+
+impl_try_from_any! {
+    any::AddressRange {
+        any::AddressRange::Ipv4 => Range<Ipv4>,
+        any::AddressRange::Ipv6 => Range<Ipv6>,
+    }
+}
+
+/// Convert an [`AddressRange<A>`][Range] into the [`Prefix<A>`] having
+/// exactly the same first and last addresses.
+///
+/// # Errors
+///
+/// An error of kind [`AddressRangeNotPrefix`][Kind::AddressRangeNotPrefix]
+/// is returned if the range does not correspond exactly to a single prefix.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::AddressRange, error::Kind, Ipv4, Prefix};
+///
+/// let range = "192.0.2.0-192.0.2.255".parse::<AddressRange<Ipv4>>()?;
+/// assert_eq!(Prefix::<Ipv4>::try_from(range)?, "192.0.2.0/24".parse::<Prefix<Ipv4>>()?);
+///
+/// let range = "192.0.2.0-192.0.2.127".parse::<AddressRange<Ipv4>>()?;
+/// assert!(Prefix::<Ipv4>::try_from(range).is_ok());
+///
+/// let range = "192.0.2.1-192.0.2.255".parse::<AddressRange<Ipv4>>()?;
+/// assert_eq!(
+///     Prefix::<Ipv4>::try_from(range).unwrap_err().kind(),
+///     Kind::AddressRangeNotPrefix,
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> TryFrom<Range<A>> for Prefix<A> {
+    type Error = Error;
+
+    fn try_from(range: Range<A>) -> Result<Self, Self::Error> {
+        range
+            .to_prefixes()
+            .next()
+            .filter(|prefix| prefix.broadcast() == range.end())
+            .ok_or_else(|| err!(Kind::AddressRangeNotPrefix))
+    }
+}
+
+/// Parse an [`AddressRange<A>`][Range] from a string of the form
+/// `start-end`, optionally with whitespace surrounding the `-`.
+///
+/// A single address, without a `-`, is parsed as a range containing only
+/// that address.
+///
+/// # Errors
+///
+/// An error of kind [`ParserError`][Kind::ParserError] is returned if
+/// either bound is not a valid address, and an error of kind
+/// [`AddressRangeBounds`][Kind::AddressRangeBounds] is returned if `start`
+/// is greater than `end`.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::AddressRange, error::Kind, Ipv4};
+///
+/// let range = "192.0.2.10 - 192.0.2.20".parse::<AddressRange<Ipv4>>()?;
+///
+/// assert!(range.contains(&"192.0.2.15".parse()?));
+/// assert_eq!(range.to_string(), "192.0.2.10-192.0.2.20");
+///
+/// assert_eq!(
+///     "192.0.2.20-192.0.2.10"
+///         .parse::<AddressRange<Ipv4>>()
+///         .unwrap_err()
+///         .kind(),
+///     Kind::AddressRangeBounds,
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> FromStr for Range<A> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((start, end)) = s.split_once('-') {
+            let start = start.trim_end().parse()?;
+            let end = end.trim_start();
+            // report errors in the end address relative to the whole input
+            let offset = s.len() - end.len();
+            let end = end
+                .parse()
+                .map_err(|err: Error| err.at(offset + err.position().unwrap_or_default()))?;
+            Self::new(start, end)
+        } else {
+            let addr = s.parse()?;
+            Ok(Self::new_unchecked(addr, addr))
+        }
+    }
+}
+
+impl<A: Afi> fmt::Display for Range<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start(), self.end())
+    }
+}
+
+impl<A: Afi> fmt::Debug for Range<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AddressRange<{:?}>({})", A::as_afi(), self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::format;
+    use std::string::ToString as _;
+    use std::vec::Vec;
+
+    use proptest::{arbitrary::any, proptest};
+
+    use super::*;
+    use crate::{error::TestResult, Ipv4, Ipv6};
+
+    #[test]
+    fn parse_with_and_without_whitespace() -> TestResult {
+        let expected = Range::<Ipv4>::new("192.0.2.10".parse()?, "192.0.2.20".parse()?)?;
+        for s in [
+            "192.0.2.10-192.0.2.20",
+            "192.0.2.10 - 192.0.2.20",
+            "192.0.2.10\t-192.0.2.20",
+        ] {
+            assert_eq!(s.parse::<Range<Ipv4>>()?.0, expected.0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_error_positions() {
+        for (s, position) in [
+            ("10.0.0.x - 10.0.0.1", 7),
+            ("10.0.0.1 - 10.0.0.x", 18),
+            ("10.0.0.1-10.0.0.x", 16),
+            ("10.0.0.1 -\t10.0.0.256", 18),
+        ] {
+            let err = s.parse::<Range<Ipv4>>().unwrap_err();
+            assert_eq!(err.kind(), Kind::ParserError, "parsing {s:?}");
+            assert_eq!(err.position(), Some(position), "parsing {s:?}");
+        }
+    }
+
+    #[test]
+    fn parse_single_address() -> TestResult {
+        let range = "2001:db8::1".parse::<Range<Ipv6>>()?;
+        assert_eq!(range.iter().count(), 1);
+        assert_eq!(range.to_string(), "2001:db8::1-2001:db8::1");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rejects_inverted_and_malformed() {
+        let kind = |s: &str| s.parse::<Range<Ipv6>>().unwrap_err().kind();
+        assert_eq!(kind("2001:db8::2-2001:db8::1"), Kind::AddressRangeBounds);
+        assert_eq!(kind("2001:db8::1-"), Kind::ParserError);
+        assert_eq!(kind(" 2001:db8::1-2001:db8::2"), Kind::ParserError);
+        assert_eq!(
+            kind("2001:db8::1-2001:db8::2-2001:db8::3"),
+            Kind::ParserError
+        );
+    }
+
+    #[test]
+    fn try_from_range_inclusive() -> TestResult {
+        let (start, end) = ("2001:db8::1".parse()?, "2001:db8::ff".parse()?);
+        let range = Range::<Ipv6>::try_from(start..=end)?;
+        assert_eq!(range, Range::new(start, end)?);
+        assert_eq!(range.start_bound(), Bound::Included(&start));
+        assert_eq!(range.end_bound(), Bound::Included(&end));
+        assert_eq!(
+            Range::<Ipv6>::try_from(end..=start).unwrap_err().kind(),
+            Kind::AddressRangeBounds
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unaligned_range_to_prefixes() -> TestResult {
+        let range = "10.0.0.3-10.0.0.10".parse::<Range<Ipv4>>()?;
+        assert_eq!(range.to_prefixes().count(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn single_prefix_range_to_prefixes() -> TestResult {
+        let prefix = "2001:db8::/32".parse::<Prefix<Ipv6>>()?;
+        let prefixes: Vec<_> = Range::from(prefix).to_prefixes().collect();
+        assert_eq!(prefixes, [prefix]);
+        Ok(())
+    }
+
+    #[test]
+    fn whole_space_to_prefixes() {
+        let prefixes: Vec<_> = Range::from(Prefix::<Ipv4>::DEFAULT).to_prefixes().collect();
+        assert_eq!(prefixes, [Prefix::DEFAULT]);
+    }
+
+    #[test]
+    fn top_of_space_to_prefixes() -> TestResult {
+        let range = "255.255.255.254-255.255.255.255".parse::<Range<Ipv4>>()?;
+        let prefixes: Vec<_> = range.to_prefixes().collect();
+        assert_eq!(prefixes, ["255.255.255.254/31".parse::<Prefix<Ipv4>>()?]);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_range_to_prefixes() -> TestResult {
+        let range = Range::<Ipv6>::new_unchecked("2001:db8::2".parse()?, "2001:db8::1".parse()?);
+        assert_eq!(range.to_prefixes().count(), 0);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn to_prefixes_exactly_covers_range(a in any::<Address<Ipv6>>(), b in any::<Address<Ipv6>>()) {
+            let range = Range::new(a.min(b), a.max(b)).unwrap();
+            let prefixes: Vec<_> = range.to_prefixes().collect();
+            assert_eq!(prefixes.first().unwrap().network(), range.start());
+            assert_eq!(prefixes.last().unwrap().broadcast(), range.end());
+            for pair in prefixes.windows(2) {
+                assert_eq!(pair[0].broadcast().successor(), Some(pair[1].network()));
+            }
+            assert!(prefixes.len() <= 2 * 128);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prefix_range_round_trip(prefix in any::<Prefix<Ipv6>>()) {
+            assert_eq!(Prefix::try_from(Range::from(prefix)).unwrap(), prefix);
+        }
+
+        #[test]
+        fn range_prefix_round_trip(a in any::<Address<Ipv4>>(), b in any::<Address<Ipv4>>()) {
+            let range = Range::new(a.min(b), a.max(b)).unwrap();
+            match Prefix::try_from(range.clone()) {
+                Ok(prefix) => assert_eq!(Range::from(prefix), range),
+                Err(err) => {
+                    assert_eq!(err.kind(), Kind::AddressRangeNotPrefix);
+                    assert!(range.to_prefixes().count() > 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unaligned_range_is_not_prefix() -> TestResult {
+        let range = "10.0.0.3-10.0.0.10".parse::<Range<Ipv4>>()?;
+        assert_eq!(
+            Prefix::try_from(range).unwrap_err().kind(),
+            Kind::AddressRangeNotPrefix
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn adjacent_at_top_of_space() -> TestResult {
+        let top = "255.255.255.255".parse::<Range<Ipv4>>()?;
+        let bottom = "0.0.0.0".parse::<Range<Ipv4>>()?;
+        assert!(!top.is_adjacent(&bottom));
+        assert!(!bottom.is_adjacent(&top));
+        assert!(top.is_adjacent(&"255.255.255.254".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping_ranges_are_not_adjacent() -> TestResult {
+        let a = "2001:db8::-2001:db8::ff".parse::<Range<Ipv6>>()?;
+        assert!(!a.is_adjacent(&a));
+        assert!(!a.is_adjacent(&"2001:db8::ff-2001:db8::1ff".parse()?));
+        Ok(())
+    }
+
+    fn arbitrary_range(a: Address<Ipv4>, b: Address<Ipv4>) -> Range<Ipv4> {
+        Range::new(a.min(b), a.max(b)).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn intersection_is_commutative(
+            a in any::<Address<Ipv4>>(),
+            b in any::<Address<Ipv4>>(),
+            c in any::<Address<Ipv4>>(),
+            d in any::<Address<Ipv4>>(),
+        ) {
+            let (x, y) = (arbitrary_range(a, b), arbitrary_range(c, d));
+            assert_eq!(x.intersection(&y), y.intersection(&x));
+        }
+
+        #[test]
+        fn union_is_commutative(
+            a in any::<Address<Ipv4>>(),
+            b in any::<Address<Ipv4>>(),
+            c in any::<Address<Ipv4>>(),
+            d in any::<Address<Ipv4>>(),
+        ) {
+            let (x, y) = (arbitrary_range(a, b), arbitrary_range(c, d));
+            assert_eq!(x.union(&y), y.union(&x));
+        }
+
+        #[test]
+        fn range_ops_consistent_with_contains(
+            a in any::<Address<Ipv4>>(),
+            b in any::<Address<Ipv4>>(),
+            c in any::<Address<Ipv4>>(),
+            d in any::<Address<Ipv4>>(),
+            addr in any::<Address<Ipv4>>(),
+        ) {
+            let (x, y) = (arbitrary_range(a, b), arbitrary_range(c, d));
+            let in_both = x.contains(&addr) && y.contains(&addr);
+            assert_eq!(x.intersection(&y).is_some_and(|r| r.contains(&addr)), in_both);
+            if let Some(union) = x.union(&y) {
+                assert_eq!(union.contains(&addr), x.contains(&addr) || y.contains(&addr));
+                assert!(union.contains_range(&x) && union.contains_range(&y));
+            }
+            if let Some(intersection) = x.intersection(&y) {
+                assert!(x.contains_range(&intersection) && y.contains_range(&intersection));
+            }
+        }
+    }
+
+    #[test]
+    fn bounds_accessors() -> TestResult {
+        let start = "2001:db8::1".parse()?;
+        let end = "2001:db8::ff".parse()?;
+        let range = Range::<Ipv6>::new(start, end)?;
+        assert_eq!(range.start(), start);
+        assert_eq!(range.end(), end);
+        Ok(())
+    }
+
+    #[test]
+    fn debug_uses_address_formatting() -> TestResult {
+        let range = Range::from("10.0.0.0/24".parse::<Prefix<Ipv4>>()?);
+        assert_eq!(
+            format!("{range:?}"),
+            "AddressRange<Ipv4>(10.0.0.0-10.0.0.255)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn display_round_trip() -> TestResult {
+        let range = Range::from("2001:db8::/126".parse::<Prefix<Ipv6>>()?);
+        let s = range.to_string();
+        assert_eq!(s, "2001:db8::-2001:db8::3");
+        assert_eq!(s.parse::<Range<Ipv6>>()?.0, range.0);
+        Ok(())
+    }
+
+    #[test]
+    fn inverted_range_is_rejected() -> TestResult {
+        let err = Range::<Ipv4>::new("192.0.2.2".parse()?, "192.0.2.1".parse()?).unwrap_err();
+        assert_eq!(err.kind(), Kind::AddressRangeBounds);
+        Ok(())
+    }
+
+    #[test]
+    fn singleton_range_is_accepted() -> TestResult {
+        let addr = "2001:db8::1".parse()?;
+        let range = Range::<Ipv6>::new(addr, addr)?;
+        assert!(range.contains(&addr));
+        Ok(())
+    }
+
+    #[test]
+    fn range_from_prefix() -> TestResult {
+        let range = Range::from("192.0.2.0/24".parse::<Prefix<Ipv4>>()?);
+        assert!(range.contains(&"192.0.2.0".parse()?));
+        assert!(range.contains(&"192.0.2.255".parse()?));
+        assert!(!range.contains(&"192.0.1.255".parse()?));
+        assert!(!range.contains(&"192.0.3.0".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_range() -> TestResult {
+        let range = Range::<Ipv4>::new("192.0.2.254".parse()?, "192.0.3.1".parse()?)?;
+        assert_eq!(range.iter().size_hint(), (4, Some(4)));
+        assert_eq!(range.iter().count(), 4);
+        assert_eq!(range.iter().next(), Some("192.0.2.254".parse()?));
+        assert_eq!(range.iter().next_back(), Some("192.0.3.1".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_range_reversed() -> TestResult {
+        let range = Range::<Ipv4>::new("192.0.2.1".parse()?, "192.0.2.3".parse()?)?;
+        let forward: Vec<_> = range.iter().collect();
+        let mut backward: Vec<_> = range.iter().rev().collect();
+        backward.reverse();
+        assert_eq!(forward, backward);
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_range_meeting_in_the_middle() -> TestResult {
+        let range = Range::<Ipv6>::new("2001:db8::1".parse()?, "2001:db8::3".parse()?)?;
+        let mut iter = range.into_iter();
+        assert_eq!(iter.next(), Some("2001:db8::1".parse()?));
+        assert_eq!(iter.next_back(), Some("2001:db8::3".parse()?));
+        assert_eq!(iter.next(), Some("2001:db8::2".parse()?));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn iterate_range_terminates_at_ones() {
+        let range = Range::<Ipv4>::new(Address::new(u32::MAX - 2), Address::ONES).unwrap();
+        let addrs: Vec<_> = range.iter().collect();
+        assert_eq!(addrs.len(), 3);
+        assert_eq!(addrs.last(), Some(&Address::ONES));
+    }
+
+    #[test]
+    fn iterate_range_terminates_at_zeros() {
+        let range = Range::<Ipv6>::new(Address::ZEROS, Address::new(2)).unwrap();
+        assert_eq!(range.iter().rev().count(), 3);
+    }
+
+    #[test]
+    fn full_ipv6_range_size_hint_is_unbounded() {
+        let range = Range::<Ipv6>::from(Prefix::DEFAULT);
+        assert_eq!(range.iter().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn inverted_unchecked_range_is_empty() {
+        let range = Range::<Ipv4>::new_unchecked(Address::ONES, Address::ZEROS);
+        assert_eq!(range.iter().size_hint(), (0, Some(0)));
+        assert_eq!(range.iter().next(), None);
+    }
+
+    #[test]
+    fn range_from_default_prefix() -> TestResult {
+        let range = Range::from(Prefix::<Ipv6>::DEFAULT);
+        assert!(range.contains(&Address::ZEROS));
+        assert!(range.contains(&"ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse()?));
+        Ok(())
     }
 }
@@ -0,0 +1,394 @@
+use core::borrow::BorrowMut as _;
+
+use super::Address;
+use crate::{
+    concrete::{Afi, Prefix, PrefixLength},
+    error::{err, Error, Kind},
+    traits::{self, primitive::Octets as _},
+};
+
+impl Afi {
+    const fn reverse_dns_zone(self) -> &'static str {
+        match self {
+            Self::Ipv4 => "in-addr.arpa",
+            Self::Ipv6 => "ip6.arpa",
+        }
+    }
+
+    pub(crate) const fn reverse_dns_label_bits(self) -> usize {
+        match self {
+            Self::Ipv4 => 8,
+            Self::Ipv6 => 4,
+        }
+    }
+}
+
+/// Determine the address family of a reverse DNS name from its suffix.
+pub(crate) fn reverse_dns_afi(name: &str) -> Result<Afi, Error> {
+    [Afi::Ipv4, Afi::Ipv6]
+        .into_iter()
+        .find(|afi| reverse_dns_labels(name, *afi).is_some())
+        .ok_or_else(|| {
+            err!(
+                Kind::ParserError,
+                "name is not within in-addr.arpa or ip6.arpa"
+            )
+            .at(0)
+        })
+}
+
+/// Strip the (case-insensitive) zone suffix and optional trailing dot from a
+/// reverse DNS name, returning the remaining labels.
+fn reverse_dns_labels(name: &str, afi: Afi) -> Option<&str> {
+    let name = name.strip_suffix('.').unwrap_or(name);
+    let zone = afi.reverse_dns_zone();
+    let split = name.len().checked_sub(zone.len())?;
+    if !name.is_char_boundary(split) || !name[split..].eq_ignore_ascii_case(zone) {
+        return None;
+    }
+    match &name[..split] {
+        "" => Some(""),
+        labels => labels.strip_suffix('.'),
+    }
+}
+
+fn parse_label(label: &str, afi: Afi) -> Result<u8, Error> {
+    let (max_len, radix) = match afi {
+        Afi::Ipv4 => (3, 10),
+        Afi::Ipv6 => (1, 16),
+    };
+    if label.is_empty() || label.len() > max_len || !label.chars().all(|c| c.is_digit(radix)) {
+        return Err(err!(Kind::ParserError, "invalid reverse DNS label"));
+    }
+    // reject leading zeros, as the dotted-quad address parser does
+    if label.len() > 1 && label.starts_with('0') {
+        return Err(err!(Kind::ParserError, "unexpected leading zero"));
+    }
+    u8::from_str_radix(label, radix)
+        .map_err(|_| err!(Kind::ParserError, "invalid reverse DNS label"))
+}
+
+impl<A: traits::Afi> Address<A> {
+    /// Returns the reverse DNS name consisting of the labels representing
+    /// the most-significant `bits` of `self`, which must be a multiple of the
+    /// per-label width for address family `A`.
+    #[cfg(feature = "std")]
+    pub(crate) fn reverse_dns_name(self, bits: usize) -> std::string::String {
+        use core::{borrow::Borrow as _, fmt::Write as _};
+
+        let mut name = std::string::String::new();
+        let octets = self.octets();
+        let octets: &[u8] = octets.borrow();
+        match A::as_afi() {
+            Afi::Ipv4 => {
+                octets[..bits / 8]
+                    .iter()
+                    .rev()
+                    .for_each(|octet| _ = write!(name, "{octet}."));
+            }
+            Afi::Ipv6 => {
+                (0..bits / 4)
+                    .rev()
+                    .map(|i| {
+                        if i % 2 == 0 {
+                            octets[i / 2] >> 4
+                        } else {
+                            octets[i / 2] & 0x0f
+                        }
+                    })
+                    .for_each(|nibble| _ = write!(name, "{nibble:x}."));
+            }
+        }
+        name.push_str(A::as_afi().reverse_dns_zone());
+        name.push('.');
+        name
+    }
+
+    /// Parse a reverse DNS name, returning the address formed from its
+    /// labels and the number of bits represented by those labels.
+    fn parse_reverse_dns(name: &str) -> Result<(Self, usize), Error> {
+        let afi = A::as_afi();
+        let labels = reverse_dns_labels(name, afi).ok_or_else(|| {
+            err!(
+                Kind::ParserError,
+                "name is not within the reverse DNS zone of the address family"
+            )
+            .at(0)
+        })?;
+        let label_bits = afi.reverse_dns_label_bits();
+        let max_bits = A::Octets::LENGTH * 8;
+        let mut octets = A::Octets::ZEROS;
+        let mut bits = 0;
+        if !labels.is_empty() {
+            // `labels` is a prefix of `name`, so offsets into it are offsets
+            // into `name` too
+            let mut end = labels.len();
+            for label in labels.rsplit('.') {
+                let start = end - label.len();
+                if bits == max_bits {
+                    return Err(err!(Kind::ParserError, "too many reverse DNS labels").at(start));
+                }
+                let value = parse_label(label, afi).map_err(|err| err.at(start))?;
+                octets.borrow_mut()[bits / 8] |= value << (8 - label_bits - bits % 8);
+                bits += label_bits;
+                end = start.saturating_sub(1);
+            }
+        }
+        Ok((Self::from_octets(octets), bits))
+    }
+
+    /// Parse an [`Address<A>`] from its reverse DNS name.
+    ///
+    /// The name must be within `in-addr.arpa` (IPv4) or `ip6.arpa` (IPv6),
+    /// and contain exactly one label for every octet (IPv4) or nibble (IPv6)
+    /// of the address. As for dotted-quad addresses, IPv4 labels must not
+    /// have leading zeros. The trailing dot is optional, and the name is
+    /// case-insensitive.
+    ///
+    /// This is the reverse of
+    /// [`Address::to_reverse_dns()`][traits::Address::to_reverse_dns()].
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`ParserError`][Kind::ParserError] is returned if
+    /// the name is not a valid reverse DNS name of the full address.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     Address::<Ipv4>::from_reverse_dns("1.2.0.192.in-addr.arpa.")?,
+    ///     "192.0.2.1".parse::<Address<Ipv4>>()?,
+    /// );
+    /// assert_eq!(
+    ///     Address::<Ipv6>::from_reverse_dns(
+    ///         "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.B.D.0.1.0.0.2.IP6.ARPA"
+    ///     )?,
+    ///     "2001:db8::1".parse::<Address<Ipv6>>()?,
+    /// );
+    ///
+    /// assert!(Address::<Ipv4>::from_reverse_dns("2.0.192.in-addr.arpa.").is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn from_reverse_dns(name: &str) -> Result<Self, Error> {
+        match Self::parse_reverse_dns(name)? {
+            (addr, bits) if bits == A::Octets::LENGTH * 8 => Ok(addr),
+            // the missing labels belong at the start of the name
+            _ => Err(err!(Kind::ParserError, "too few reverse DNS labels").at(0)),
+        }
+    }
+}
+
+impl<A: traits::Afi> Prefix<A> {
+    /// Parse a [`Prefix<A>`] from the name of a reverse DNS zone.
+    ///
+    /// The prefix-length is inferred from the number of labels in the name,
+    /// with each label representing one octet (IPv4) or nibble (IPv6).
+    ///
+    /// See [`Address::from_reverse_dns()`] for the accepted syntax.
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`ParserError`][Kind::ParserError] is returned if
+    /// the name is not a valid reverse DNS name.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!(
+    ///     Prefix::<Ipv4>::from_reverse_dns("2.0.192.in-addr.arpa.")?,
+    ///     "192.0.2.0/24".parse::<Prefix<Ipv4>>()?,
+    /// );
+    /// assert_eq!(
+    ///     Prefix::<Ipv6>::from_reverse_dns("8.b.d.0.1.0.0.2.ip6.arpa")?,
+    ///     "2001:db8::/32".parse::<Prefix<Ipv6>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn from_reverse_dns(name: &str) -> Result<Self, Error> {
+        let (addr, bits) = Address::parse_reverse_dns(name)?;
+        Ok(Self::new(addr, PrefixLength::try_from(bits)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ipv4, Ipv6};
+
+    fn assert_parser_error<T: core::fmt::Debug>(result: Result<T, Error>) {
+        assert_eq!(result.unwrap_err().kind(), Kind::ParserError);
+    }
+
+    #[test]
+    fn ipv4_address_without_trailing_dot() {
+        assert_eq!(
+            Address::<Ipv4>::from_reverse_dns("40.30.20.10.in-addr.arpa").unwrap(),
+            "10.20.30.40".parse::<Address<Ipv4>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv4_address_uppercase_suffix() {
+        assert_eq!(
+            Address::<Ipv4>::from_reverse_dns("40.30.20.10.IN-ADDR.Arpa.").unwrap(),
+            "10.20.30.40".parse::<Address<Ipv4>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv4_address_too_few_labels() {
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns("30.20.10.in-addr.arpa."));
+    }
+
+    #[test]
+    fn ipv4_address_too_many_labels() {
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns(
+            "50.40.30.20.10.in-addr.arpa.",
+        ));
+    }
+
+    #[test]
+    fn ipv4_label_out_of_range() {
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns(
+            "256.30.20.10.in-addr.arpa.",
+        ));
+    }
+
+    #[test]
+    fn ipv4_label_not_decimal() {
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns(
+            "a.30.20.10.in-addr.arpa.",
+        ));
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns(
+            "+1.30.20.10.in-addr.arpa.",
+        ));
+    }
+
+    #[test]
+    fn ipv4_label_leading_zero() {
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns(
+            "001.2.0.192.in-addr.arpa.",
+        ));
+        assert_parser_error(Prefix::<Ipv4>::from_reverse_dns("00.10.in-addr.arpa."));
+        assert_eq!(
+            Address::<Ipv4>::from_reverse_dns("0.2.0.192.in-addr.arpa.").unwrap(),
+            "192.0.2.0".parse::<Address<Ipv4>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv4_empty_label() {
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns("1..20.10.in-addr.arpa."));
+    }
+
+    #[test]
+    fn ipv4_wrong_zone() {
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns("1.2.0.192.ip6.arpa."));
+        assert_parser_error(Address::<Ipv4>::from_reverse_dns("1.2.0.192in-addr.arpa."));
+    }
+
+    #[test]
+    fn ipv4_zone_apex_is_default_prefix() {
+        assert_eq!(
+            Prefix::<Ipv4>::from_reverse_dns("in-addr.arpa.").unwrap(),
+            Prefix::DEFAULT
+        );
+    }
+
+    #[test]
+    fn ipv4_prefix_round_trip() {
+        let prefix = "10.0.0.0/8".parse::<Prefix<Ipv4>>().unwrap();
+        assert_eq!(
+            Prefix::<Ipv4>::from_reverse_dns(&traits::Prefix::to_reverse_dns(&prefix).unwrap())
+                .unwrap(),
+            prefix
+        );
+    }
+
+    #[test]
+    fn ipv6_address_mixed_case_nibbles() {
+        assert_eq!(
+            Address::<Ipv6>::from_reverse_dns(
+                "E.d.C.b.A.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.D.0.1.0.0.2.ip6.arpa."
+            )
+            .unwrap(),
+            "2001:db8::a:bcde".parse::<Address<Ipv6>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_address_too_few_labels() {
+        assert_parser_error(Address::<Ipv6>::from_reverse_dns(
+            "8.b.d.0.1.0.0.2.ip6.arpa.",
+        ));
+    }
+
+    #[test]
+    fn ipv6_prefix_too_many_labels() {
+        assert_parser_error(Prefix::<Ipv6>::from_reverse_dns(
+            "0.1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa.",
+        ));
+    }
+
+    #[test]
+    fn ipv6_malformed_nibbles() {
+        assert_parser_error(Prefix::<Ipv6>::from_reverse_dns("g.ip6.arpa."));
+        assert_parser_error(Prefix::<Ipv6>::from_reverse_dns("10.ip6.arpa."));
+        assert_parser_error(Prefix::<Ipv6>::from_reverse_dns("1..ip6.arpa."));
+        assert_parser_error(Prefix::<Ipv6>::from_reverse_dns("\u{e9}.ip6.arpa."));
+    }
+
+    #[test]
+    fn ipv6_odd_nibble_count() {
+        assert_eq!(
+            Prefix::<Ipv6>::from_reverse_dns("0.8.b.d.0.1.0.0.2.ip6.arpa").unwrap(),
+            "2001:db8::/36".parse::<Prefix<Ipv6>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ipv6_full_round_trip() {
+        let addr = "2001:db8:abcd:12::fe1".parse::<Address<Ipv6>>().unwrap();
+        assert_eq!(
+            Address::<Ipv6>::from_reverse_dns(&traits::Address::to_reverse_dns(&addr)).unwrap(),
+            addr
+        );
+    }
+
+    #[test]
+    fn afi_from_suffix() {
+        assert_eq!(reverse_dns_afi("2.0.192.IN-ADDR.ARPA.").unwrap(), Afi::Ipv4);
+        assert_eq!(reverse_dns_afi("ip6.arpa").unwrap(), Afi::Ipv6);
+        assert_parser_error(reverse_dns_afi("example.com."));
+    }
+
+    #[test]
+    fn error_positions() {
+        let position = |name| {
+            Address::<Ipv4>::from_reverse_dns(name)
+                .unwrap_err()
+                .position()
+        };
+        assert_eq!(position("40.30.20.10.example.com."), Some(0));
+        assert_eq!(position("30.20.10.in-addr.arpa."), Some(0));
+        assert_eq!(position("50.40.30.20.10.in-addr.arpa."), Some(0));
+        assert_eq!(position("40.300.20.10.in-addr.arpa."), Some(3));
+        assert_eq!(position("40.30.020.10.in-addr.arpa."), Some(6));
+        assert_eq!(position("40..20.10.in-addr.arpa."), Some(3));
+        assert_eq!(
+            Prefix::<Ipv6>::from_reverse_dns("8.b.x.0.1.0.0.2.ip6.arpa")
+                .unwrap_err()
+                .position(),
+            Some(4)
+        );
+        assert_eq!(
+            reverse_dns_afi("example.com.").unwrap_err().position(),
+            Some(0)
+        );
+    }
+}
@@ -0,0 +1,527 @@
+use core::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+use std::vec::Vec;
+
+use super::{Address, Range};
+use crate::{
+    concrete::{Prefix, PrefixSet},
+    traits::{Afi, PrefixSet as _},
+};
+
+/// A collection of IP addresses, stored as a sorted list of disjoint
+/// [`AddressRange<A>`][Range].
+///
+/// Unlike [`PrefixSet<A>`], which stores CIDR prefixes, an
+/// [`AddressSet<A>`][Set] stores arbitrary address ranges without first
+/// decomposing them into prefixes. Overlapping and adjacent ranges are
+/// coalesced on insertion, and ranges are split as required on removal.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{
+///     concrete::{AddressRange, AddressSet},
+///     Address, Ipv4,
+/// };
+///
+/// let mut set = AddressSet::<Ipv4>::new();
+/// set.insert("192.0.2.10-192.0.2.20".parse::<AddressRange<Ipv4>>()?)
+///     .insert("192.0.2.21-192.0.2.30".parse::<AddressRange<Ipv4>>()?)
+///     .remove("192.0.2.15".parse::<AddressRange<Ipv4>>()?);
+///
+/// assert_eq!(
+///     set.iter_ranges().map(ToString::to_string).collect::<Vec<_>>(),
+///     ["192.0.2.10-192.0.2.14", "192.0.2.16-192.0.2.30"],
+/// );
+/// assert!(set.contains("192.0.2.25".parse::<Address<Ipv4>>()?));
+/// assert!(!set.contains("192.0.2.15".parse::<Address<Ipv4>>()?));
+/// # Ok::<(), ip::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Set<A: Afi> {
+    ranges: Vec<Range<A>>,
+}
+
+impl<A: Afi> Set<A> {
+    /// Construct a new, empty [`AddressSet<A>`][Set].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Insert the addresses in `item` into `self`, coalescing any
+    /// overlapping or adjacent ranges.
+    ///
+    /// `T` can be either an [`AddressRange<A>`][Range] or a
+    /// [`Prefix<A>`].
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::{AddressRange, AddressSet}, Error, Ipv6, Prefix};
+    /// let set = AddressSet::<Ipv6>::new()
+    ///     .insert("2001:db8::/127".parse::<Prefix<Ipv6>>()?)
+    ///     .insert("2001:db8::2-2001:db8::ff".parse::<AddressRange<Ipv6>>()?)
+    ///     .to_owned();
+    /// assert_eq!(set.iter_ranges().count(), 1);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn insert<T>(&mut self, item: T) -> &mut Self
+    where
+        T: Into<Range<A>>,
+    {
+        let range = item.into();
+        let (mut start, mut end) = (range.start(), range.end());
+        if start <= end {
+            let lo = self
+                .ranges
+                .partition_point(|r| r.end().successor().is_some_and(|next| next < start));
+            let hi = self
+                .ranges
+                .partition_point(|r| end.successor().is_none_or(|next| r.start() <= next));
+            if lo < hi {
+                start = start.min(self.ranges[lo].start());
+                end = end.max(self.ranges[hi - 1].end());
+            }
+            _ = self
+                .ranges
+                .splice(lo..hi, [Range::new_unchecked(start, end)]);
+        }
+        self
+    }
+
+    /// Remove the addresses in `item` from `self`, splitting any partially
+    /// covered ranges.
+    ///
+    /// `T` can be either an [`AddressRange<A>`][Range] or a
+    /// [`Prefix<A>`].
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::{AddressRange, AddressSet}, Error, Ipv4, Prefix};
+    /// let set = AddressSet::<Ipv4>::new()
+    ///     .insert("192.0.2.0/24".parse::<Prefix<Ipv4>>()?)
+    ///     .remove("192.0.2.64-192.0.2.127".parse::<AddressRange<Ipv4>>()?)
+    ///     .to_owned();
+    /// assert_eq!(set.iter_ranges().count(), 2);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn remove<T>(&mut self, item: T) -> &mut Self
+    where
+        T: Into<Range<A>>,
+    {
+        let range = item.into();
+        let (start, end) = (range.start(), range.end());
+        if start <= end {
+            let lo = self.ranges.partition_point(|r| r.end() < start);
+            let hi = self.ranges.partition_point(|r| r.start() <= end);
+            if lo < hi {
+                let (first, last) = (self.ranges[lo].start(), self.ranges[hi - 1].end());
+                let before = start
+                    .predecessor()
+                    .filter(|prev| first <= *prev)
+                    .map(|prev| Range::new_unchecked(first, prev));
+                let after = end
+                    .successor()
+                    .filter(|next| *next <= last)
+                    .map(|next| Range::new_unchecked(next, last));
+                _ = self.ranges.splice(lo..hi, before.into_iter().chain(after));
+            }
+        }
+        self
+    }
+
+    /// Returns [`true`] if `addr` is contained in `self`.
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::{AddressRange, AddressSet}, Address, Error, Ipv4};
+    /// let set = AddressSet::<Ipv4>::from_iter([
+    ///     "10.0.0.3-10.0.0.10".parse::<AddressRange<Ipv4>>()?,
+    /// ]);
+    /// assert!(set.contains("10.0.0.3".parse::<Address<Ipv4>>()?));
+    /// assert!(!set.contains("10.0.0.11".parse::<Address<Ipv4>>()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn contains(&self, addr: Address<A>) -> bool {
+        let i = self.ranges.partition_point(|r| r.end() < addr);
+        self.ranges.get(i).is_some_and(|r| r.contains(&addr))
+    }
+
+    /// Returns an iterator over the disjoint, non-adjacent
+    /// [`AddressRange<A>`][Range] contained in `self`, in ascending order.
+    pub fn iter_ranges(&self) -> impl Iterator<Item = &Range<A>> {
+        self.ranges.iter()
+    }
+
+    /// Returns [`true`] if `self` contains no addresses.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Clear the contents of `self`.
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+}
+
+impl<A: Afi> Default for Set<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Afi, T> Extend<T> for Set<A>
+where
+    T: Into<Range<A>>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| _ = self.insert(item));
+    }
+}
+
+impl<A: Afi, T> FromIterator<T> for Set<A>
+where
+    T: Into<Range<A>>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Construct an [`AddressSet<A>`][Set] containing the addresses covered by
+/// the prefixes in a [`PrefixSet<A>`].
+impl<A: Afi> From<PrefixSet<A>> for Set<A> {
+    fn from(set: PrefixSet<A>) -> Self {
+        set.ranges().map(|range| range.prefix()).collect()
+    }
+}
+
+/// Construct a [`PrefixSet<A>`] containing the minimal set of prefixes that
+/// exactly cover the addresses in an [`AddressSet<A>`][Set].
+///
+/// See [`AddressRange::to_prefixes()`][Range::to_prefixes].
+impl<A: Afi> From<Set<A>> for PrefixSet<A> {
+    fn from(set: Set<A>) -> Self {
+        set.ranges.into_iter().collect()
+    }
+}
+
+impl<A: Afi> BitOr for Set<A> {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self.extend(rhs.ranges);
+        self
+    }
+}
+
+impl<A: Afi> BitAnd for Set<A> {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let mut ranges = Vec::new();
+        let (mut lhs, mut rhs) = (self.ranges.iter().peekable(), rhs.ranges.iter().peekable());
+        while let (Some(l), Some(r)) = (lhs.peek(), rhs.peek()) {
+            ranges.extend(l.intersection(r));
+            if l.end() < r.end() {
+                _ = lhs.next();
+            } else {
+                _ = rhs.next();
+            }
+        }
+        Self { ranges }
+    }
+}
+
+impl<A: Afi> Sub for Set<A> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        rhs.ranges
+            .into_iter()
+            .for_each(|range| _ = self.remove(range));
+        self
+    }
+}
+
+impl<A: Afi> BitXor for Set<A> {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        (self.clone() | rhs.clone()) - (self & rhs)
+    }
+}
+
+impl<A: Afi> Not for Set<A> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::from_iter([Prefix::<A>::DEFAULT]) - self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use proptest::{arbitrary::any, collection::vec, proptest};
+
+    use super::*;
+    use crate::{error::TestResult, Ipv4, Ipv6};
+
+    fn ranges<A: Afi>(set: &Set<A>) -> Vec<(Address<A>, Address<A>)> {
+        set.iter_ranges()
+            .map(|range| (range.start(), range.end()))
+            .collect()
+    }
+
+    fn range(s: &str) -> Range<Ipv4> {
+        s.parse().unwrap()
+    }
+
+    fn addr(s: &str) -> Address<Ipv4> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn remove_inverted_range_is_noop() {
+        let mut set = Set::from_iter([range("10.0.0.0-10.0.0.100")]);
+        _ = set.remove(Range::new_unchecked(addr("10.0.0.50"), addr("10.0.0.40")));
+        assert_eq!(ranges(&set), [(addr("10.0.0.0"), addr("10.0.0.100"))]);
+    }
+
+    #[test]
+    fn empty_set() {
+        let set = Set::<Ipv6>::new();
+        assert!(set.is_empty());
+        assert!(!set.contains(Address::UNSPECIFIED));
+    }
+
+    #[test]
+    fn insert_disjoint_is_sorted() {
+        let set = Set::from_iter([range("10.0.0.20-10.0.0.30"), range("10.0.0.1-10.0.0.5")]);
+        assert_eq!(
+            ranges(&set),
+            [
+                (addr("10.0.0.1"), addr("10.0.0.5")),
+                (addr("10.0.0.20"), addr("10.0.0.30")),
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_coalesces_adjacent() {
+        let set = Set::from_iter([range("10.0.0.1-10.0.0.5"), range("10.0.0.6-10.0.0.9")]);
+        assert_eq!(ranges(&set), [(addr("10.0.0.1"), addr("10.0.0.9"))]);
+        let set = Set::from_iter([range("10.0.0.6-10.0.0.9"), range("10.0.0.1-10.0.0.5")]);
+        assert_eq!(ranges(&set), [(addr("10.0.0.1"), addr("10.0.0.9"))]);
+    }
+
+    #[test]
+    fn insert_coalesces_overlapping() {
+        let set = Set::from_iter([range("10.0.0.1-10.0.0.5"), range("10.0.0.3-10.0.0.9")]);
+        assert_eq!(ranges(&set), [(addr("10.0.0.1"), addr("10.0.0.9"))]);
+    }
+
+    #[test]
+    fn insert_bridges_multiple() {
+        let set = Set::from_iter([
+            range("10.0.0.1-10.0.0.2"),
+            range("10.0.0.4-10.0.0.5"),
+            range("10.0.0.7-10.0.0.8"),
+            range("10.0.0.20-10.0.0.30"),
+            range("10.0.0.3-10.0.0.6"),
+        ]);
+        assert_eq!(
+            ranges(&set),
+            [
+                (addr("10.0.0.1"), addr("10.0.0.8")),
+                (addr("10.0.0.20"), addr("10.0.0.30")),
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_contained_is_unchanged() {
+        let mut set = Set::from_iter([range("10.0.0.0-10.0.0.255")]);
+        let before = set.clone();
+        _ = set.insert(range("10.0.0.10-10.0.0.20"));
+        assert_eq!(set, before);
+    }
+
+    #[test]
+    fn insert_at_bounds_of_space() {
+        let set = Set::from_iter([
+            range("255.255.255.255"),
+            range("0.0.0.0"),
+            range("255.255.255.0-255.255.255.254"),
+        ]);
+        assert_eq!(
+            ranges(&set),
+            [
+                (addr("0.0.0.0"), addr("0.0.0.0")),
+                (addr("255.255.255.0"), addr("255.255.255.255")),
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_empty_range_is_ignored() {
+        let mut set = Set::new();
+        _ = set.insert(Range::<Ipv4>::new_unchecked(
+            addr("10.0.0.2"),
+            addr("10.0.0.1"),
+        ));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn remove_splits_range() {
+        let mut set = Set::from_iter([range("10.0.0.0-10.0.0.255")]);
+        _ = set.remove(range("10.0.0.100-10.0.0.199"));
+        assert_eq!(
+            ranges(&set),
+            [
+                (addr("10.0.0.0"), addr("10.0.0.99")),
+                (addr("10.0.0.200"), addr("10.0.0.255")),
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_trims_edges() {
+        let mut set = Set::from_iter([range("10.0.0.0-10.0.0.255")]);
+        _ = set
+            .remove(range("10.0.0.0-10.0.0.9"))
+            .remove(range("10.0.0.250-10.0.1.0"));
+        assert_eq!(ranges(&set), [(addr("10.0.0.10"), addr("10.0.0.249"))]);
+    }
+
+    #[test]
+    fn remove_across_multiple_ranges() {
+        let mut set = Set::from_iter([
+            range("10.0.0.1-10.0.0.5"),
+            range("10.0.0.10-10.0.0.15"),
+            range("10.0.0.20-10.0.0.25"),
+        ]);
+        _ = set.remove(range("10.0.0.3-10.0.0.22"));
+        assert_eq!(
+            ranges(&set),
+            [
+                (addr("10.0.0.1"), addr("10.0.0.2")),
+                (addr("10.0.0.23"), addr("10.0.0.25")),
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_whole_space() {
+        let mut set = Set::from_iter([range("0.0.0.0"), range("255.255.255.255")]);
+        _ = set.remove(Prefix::DEFAULT);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn remove_absent_is_unchanged() {
+        let mut set = Set::from_iter([range("10.0.0.1-10.0.0.5")]);
+        let before = set.clone();
+        _ = set.remove(range("10.0.0.6-10.0.0.9"));
+        assert_eq!(set, before);
+    }
+
+    #[test]
+    fn set_operators() {
+        let a = Set::from_iter([range("10.0.0.0-10.0.0.99")]);
+        let b = Set::from_iter([range("10.0.0.50-10.0.0.149")]);
+        assert_eq!(
+            ranges(&(a.clone() | b.clone())),
+            [(addr("10.0.0.0"), addr("10.0.0.149"))]
+        );
+        assert_eq!(
+            ranges(&(a.clone() & b.clone())),
+            [(addr("10.0.0.50"), addr("10.0.0.99"))]
+        );
+        assert_eq!(
+            ranges(&(a.clone() - b.clone())),
+            [(addr("10.0.0.0"), addr("10.0.0.49"))]
+        );
+        assert_eq!(
+            ranges(&(a ^ b)),
+            [
+                (addr("10.0.0.0"), addr("10.0.0.49")),
+                (addr("10.0.0.100"), addr("10.0.0.149")),
+            ]
+        );
+    }
+
+    #[test]
+    fn complement() {
+        let set = !Set::from_iter([range("10.0.0.0-10.255.255.255")]);
+        assert_eq!(
+            ranges(&set),
+            [
+                (addr("0.0.0.0"), addr("9.255.255.255")),
+                (addr("11.0.0.0"), addr("255.255.255.255")),
+            ]
+        );
+        assert!((!Set::<Ipv6>::new()).contains(Address::UNSPECIFIED));
+    }
+
+    #[test]
+    fn prefix_set_round_trip() -> TestResult {
+        let set = Set::from_iter(["10.0.0.3-10.0.0.10".parse::<Range<Ipv4>>()?]);
+        let prefixes = PrefixSet::from(set.clone());
+        assert_eq!(prefixes.len(), 4);
+        assert_eq!(Set::from(prefixes), set);
+        Ok(())
+    }
+
+    #[test]
+    fn from_nested_prefix_set() -> TestResult {
+        let prefixes: PrefixSet<Ipv4> = ["10.0.0.0/8", "10.1.0.0/16", "11.0.0.0/8"]
+            .into_iter()
+            .map(str::parse::<Prefix<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(
+            ranges(&Set::from(prefixes)),
+            [(addr("10.0.0.0"), addr("11.255.255.255"))]
+        );
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_naive_model(ops in vec((any::<bool>(), any::<u8>(), any::<u8>()), 0..32)) {
+            let base = addr("192.0.2.0").into_primitive();
+            let to_range = |a: u8, b: u8| {
+                Range::<Ipv4>::new_unchecked(
+                    Address::new(base + u32::from(a.min(b))),
+                    Address::new(base + u32::from(a.max(b))),
+                )
+            };
+            let mut set = Set::new();
+            let mut model = vec![false; 256];
+            for (insert, a, b) in ops {
+                if insert {
+                    _ = set.insert(to_range(a, b));
+                } else {
+                    _ = set.remove(to_range(a, b));
+                }
+                model[usize::from(a.min(b))..=usize::from(a.max(b))].fill(insert);
+            }
+            for (i, expected) in model.into_iter().enumerate() {
+                let i = u32::try_from(i).unwrap();
+                assert_eq!(set.contains(Address::new(base + i)), expected);
+            }
+            for pair in set.ranges.windows(2) {
+                assert!(pair[0].end().successor().unwrap() < pair[1].start());
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use core::fmt;
+
+/// Entries in the IANA IPv4 and IPv6 Special-Purpose Address Registries.
+///
+/// See [`Address::special_ranges()`][super::Address::special_ranges()].
+///
+/// Some entries (e.g. [`Documentation`][Self::Documentation]) appear in
+/// both registries, and are represented by a single variant.
+///
+/// This enum is marked `#[non_exhaustive]`, since new entries are added to
+/// the registries from time to time.
+///
+/// See [RFC 6890] and the [IPv4] and [IPv6] registries.
+///
+/// [RFC 6890]: https://tools.ietf.org/html/rfc6890
+/// [IPv4]: https://www.iana.org/assignments/iana-ipv4-special-registry/
+/// [IPv6]: https://www.iana.org/assignments/iana-ipv6-special-registry/
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SpecialPurpose {
+    /// "This network": `0.0.0.0/8`.
+    ThisNetwork,
+    /// "This host on this network": `0.0.0.0/32`.
+    ThisHost,
+    /// Private-Use: `10.0.0.0/8`, `172.16.0.0/12` and `192.168.0.0/16`.
+    Private,
+    /// Shared Address Space: `100.64.0.0/10`.
+    Shared,
+    /// Loopback: `127.0.0.0/8` and `::1/128`.
+    Loopback,
+    /// Link-local: `169.254.0.0/16` and `fe80::/10`.
+    LinkLocal,
+    /// IETF Protocol Assignments: `192.0.0.0/24` and `2001::/23`.
+    ProtocolAssignments,
+    /// IPv4 Service Continuity Prefix: `192.0.0.0/29`.
+    ServiceContinuity,
+    /// IPv4 dummy address: `192.0.0.8/32`.
+    DummyAddress,
+    /// Port Control Protocol Anycast: `192.0.0.9/32` and `2001:1::1/128`.
+    PortControlProtocolAnycast,
+    /// Traversal Using Relays around NAT Anycast: `192.0.0.10/32` and
+    /// `2001:1::2/128`.
+    TraversalUsingRelaysAnycast,
+    /// NAT64/DNS64 Discovery: `192.0.0.170/32` and `192.0.0.171/32`.
+    Nat64Discovery,
+    /// Documentation: `192.0.2.0/24`, `198.51.100.0/24`, `203.0.113.0/24`
+    /// and `2001:db8::/32`.
+    Documentation,
+    /// AS112-v4 and AS112-v6: `192.31.196.0/24` and `2001:4:112::/48`.
+    As112,
+    /// Automatic Multicast Tunneling: `192.52.193.0/24` and `2001:3::/32`.
+    Amt,
+    /// Deprecated 6to4 Relay Anycast: `192.88.99.0/24`.
+    SixToFourRelayAnycast,
+    /// Direct Delegation AS112 Service: `192.175.48.0/24` and
+    /// `2620:4f:8000::/48`.
+    DirectDelegationAs112,
+    /// Benchmarking: `198.18.0.0/15` and `2001:2::/48`.
+    Benchmarking,
+    /// Reserved: `240.0.0.0/4`.
+    Reserved,
+    /// Limited Broadcast: `255.255.255.255/32`.
+    LimitedBroadcast,
+    /// Unspecified Address: `::/128`.
+    Unspecified,
+    /// IPv4-mapped Address: `::ffff:0:0/96`.
+    Ipv4Mapped,
+    /// IPv4-IPv6 Translation: `64:ff9b::/96`.
+    Ipv4Ipv6Translation,
+    /// IPv4-IPv6 Translation for local use: `64:ff9b:1::/48`.
+    Ipv4Ipv6TranslationLocal,
+    /// Discard-Only Address Block: `100::/64`.
+    DiscardOnly,
+    /// TEREDO: `2001::/32`.
+    Teredo,
+    /// Deprecated (previously ORCHID): `2001:10::/28`.
+    Orchid,
+    /// Overlay Routable Cryptographic Hash Identifiers, version 2:
+    /// `2001:20::/28`.
+    OrchidV2,
+    /// 6to4: `2002::/16`.
+    SixToFour,
+    /// Unique-Local: `fc00::/7`.
+    UniqueLocal,
+}
+
+impl fmt::Display for SpecialPurpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::ThisNetwork => "\"This network\"",
+            Self::ThisHost => "\"This host on this network\"",
+            Self::Private => "Private-Use",
+            Self::Shared => "Shared Address Space",
+            Self::Loopback => "Loopback",
+            Self::LinkLocal => "Link Local",
+            Self::ProtocolAssignments => "IETF Protocol Assignments",
+            Self::ServiceContinuity => "IPv4 Service Continuity Prefix",
+            Self::DummyAddress => "IPv4 dummy address",
+            Self::PortControlProtocolAnycast => "Port Control Protocol Anycast",
+            Self::TraversalUsingRelaysAnycast => "Traversal Using Relays around NAT Anycast",
+            Self::Nat64Discovery => "NAT64/DNS64 Discovery",
+            Self::Documentation => "Documentation",
+            Self::As112 => "AS112",
+            Self::Amt => "AMT",
+            Self::SixToFourRelayAnycast => "Deprecated (6to4 Relay Anycast)",
+            Self::DirectDelegationAs112 => "Direct Delegation AS112 Service",
+            Self::Benchmarking => "Benchmarking",
+            Self::Reserved => "Reserved",
+            Self::LimitedBroadcast => "Limited Broadcast",
+            Self::Unspecified => "Unspecified Address",
+            Self::Ipv4Mapped => "IPv4-mapped Address",
+            Self::Ipv4Ipv6Translation => "IPv4-IPv6 Translat.",
+            Self::Ipv4Ipv6TranslationLocal => "IPv4-IPv6 Translat. (local use)",
+            Self::DiscardOnly => "Discard-Only Address Block",
+            Self::Teredo => "TEREDO",
+            Self::Orchid => "Deprecated (previously ORCHID)",
+            Self::OrchidV2 => "ORCHIDv2",
+            Self::SixToFour => "6to4",
+            Self::UniqueLocal => "Unique-Local",
+        };
+        f.write_str(name)
+    }
+}
@@ -0,0 +1,80 @@
+use core::iter::Step;
+
+use num_traits::{CheckedAdd as _, CheckedSub as _};
+
+use super::Address;
+use crate::traits::Afi;
+
+/// Allows native range syntax to be used to iterate over addresses.
+///
+/// Only available on a nightly toolchain, with the `step_trait` feature
+/// enabled.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{Address, Ipv4};
+///
+/// let start = "192.0.2.254".parse::<Address<Ipv4>>()?;
+/// let end = "192.0.3.1".parse::<Address<Ipv4>>()?;
+///
+/// assert_eq!(
+///     (start..=end).map(|addr| addr.to_string()).collect::<Vec<_>>(),
+///     ["192.0.2.254", "192.0.2.255", "192.0.3.0", "192.0.3.1"],
+/// );
+/// assert_eq!((start..end).count(), 3);
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> Step for Address<A> {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        if start <= end {
+            let (start, end): (u128, u128) =
+                (start.into_primitive().into(), end.into_primitive().into());
+            usize::try_from(end - start).map_or((usize::MAX, None), |steps| (steps, Some(steps)))
+        } else {
+            (0, None)
+        }
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        let count = u128::try_from(count).ok()?.try_into().ok()?;
+        start.into_primitive().checked_add(&count).map(Self::new)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        let count = u128::try_from(count).ok()?.try_into().ok()?;
+        start.into_primitive().checked_sub(&count).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{arbitrary::any, proptest};
+
+    use super::*;
+    use crate::{Ipv4, Ipv6};
+
+    proptest! {
+        #[test]
+        fn ipv4_steps_match_successor(a in any::<Address<Ipv4>>()) {
+            assert_eq!(Step::forward_checked(a, 1), a.successor());
+            assert_eq!(Step::backward_checked(a, 1), a.predecessor());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv6_steps_between(a in any::<Address<Ipv6>>(), n in 0usize..1024) {
+            if let Some(b) = Step::forward_checked(a, n) {
+                assert_eq!(Step::steps_between(&a, &b), (n, Some(n)));
+                assert_eq!(Step::backward_checked(b, n), Some(a));
+            }
+        }
+    }
+
+    #[test]
+    fn whole_space_overflows() {
+        let steps = Step::steps_between(&Address::<Ipv6>::ZEROS, &Address::ONES);
+        assert_eq!(steps, (usize::MAX, None));
+    }
+}
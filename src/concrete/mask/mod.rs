@@ -1,9 +1,11 @@
 use core::fmt;
 use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+use core::str::FromStr;
 
 use super::{impl_try_from_any, Address, PrefixLength};
 use crate::{
     any,
+    error::{err, Error, Kind},
     fmt::AddressDisplay,
     traits::{self, primitive::Address as _, Afi},
     Ipv4, Ipv6,
@@ -31,6 +33,72 @@ impl<A: Afi, T: Type> Mask<T, A> {
 
     /// The "all-ones" mask.
     pub const ONES: Self = Self::new(A::Primitive::ONES);
+
+    /// Get the number of ones in the binary representation of `self`.
+    ///
+    /// For a [`Netmask<A>`] this is equal to the corresponding prefix length.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::Netmask, Ipv6, PrefixLength};
+    ///
+    /// let mask = Netmask::from(PrefixLength::<Ipv6>::from_primitive(48)?);
+    /// assert_eq!(mask.count_ones(), 48);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn count_ones(&self) -> u8 {
+        let ones: usize = self.into_primitive().count_ones().into();
+        ones as u8
+    }
+
+    /// Get the number of zeros in the binary representation of `self`.
+    ///
+    /// For a [`Netmask<A>`] this is equal to the number of host bits of the
+    /// corresponding prefix length.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::Netmask, Ipv4, PrefixLength};
+    ///
+    /// let mask = Netmask::from(PrefixLength::<Ipv4>::from_primitive(22)?);
+    /// assert_eq!(mask.count_zeros(), 10);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn count_zeros(&self) -> u8 {
+        let zeros: usize = self.into_primitive().count_zeros().into();
+        zeros as u8
+    }
+}
+
+impl<A: Afi> Bitmask<A> {
+    /// Get the [`PrefixLength<A>`] corresponding to `self`, if the set bits
+    /// of `self` form a contiguous run from the most significant bit.
+    ///
+    /// Returns [`None`] if `self` is not a valid netmask.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::Bitmask, Address, Ipv4};
+    ///
+    /// let mask = Bitmask::from("255.255.252.0".parse::<Address<Ipv4>>()?);
+    /// assert_eq!(mask.as_prefix_length().map(|len| len.get()), Some(22));
+    ///
+    /// let mask = Bitmask::from("255.0.255.0".parse::<Address<Ipv4>>()?);
+    /// assert_eq!(mask.as_prefix_length(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn as_prefix_length(&self) -> Option<PrefixLength<A>> {
+        let len = PrefixLength::from_primitive(self.into_primitive().not().leading_zeros()).ok()?;
+        (Netmask::from(len).into_primitive() == self.into_primitive()).then_some(len)
+    }
 }
 
 impl<T: Type, A: Afi> traits::Mask for Mask<T, A> {}
@@ -122,7 +190,81 @@ impl<A: Afi> Not for Bitmask<A> {
     }
 }
 
-// TODO: impl FromStr
+impl<A: Afi> Not for Netmask<A> {
+    type Output = Hostmask<A>;
+    fn not(self) -> Self::Output {
+        Self::Output::new(self.into_primitive().not())
+    }
+}
+
+impl<A: Afi> Not for Hostmask<A> {
+    type Output = Netmask<A>;
+    fn not(self) -> Self::Output {
+        Self::Output::new(self.into_primitive().not())
+    }
+}
+
+/// Parse a [`Netmask<A>`] from its dotted-quad or colon-separated address
+/// representation.
+///
+/// # Errors
+///
+/// An error of kind [`ParserError`][Kind::ParserError] is returned if `s`
+/// is not a valid address, or if the address is not a contiguous run of
+/// leading ones followed by trailing zeros.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::Netmask, traits::Prefix as _, Ipv4, Prefix};
+///
+/// let mask = "255.255.255.0".parse::<Netmask<Ipv4>>()?;
+///
+/// assert_eq!(mask, "192.0.2.0/24".parse::<Prefix<Ipv4>>()?.netmask());
+/// assert!("255.0.255.0".parse::<Netmask<Ipv4>>().is_err());
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> FromStr for Netmask<A> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Bitmask::from(s.parse::<Address<A>>()?)
+            .as_prefix_length()
+            .map(Self::from)
+            .ok_or_else(|| err!(Kind::ParserError, "address is not a valid mask").at(0))
+    }
+}
+
+/// Parse a [`Hostmask<A>`] from its dotted-quad or colon-separated address
+/// representation.
+///
+/// # Errors
+///
+/// An error of kind [`ParserError`][Kind::ParserError] is returned if `s`
+/// is not a valid address, or if the address is not a contiguous run of
+/// leading zeros followed by trailing ones.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::Hostmask, traits::Prefix as _, Ipv4, Prefix};
+///
+/// let mask = "0.0.0.255".parse::<Hostmask<Ipv4>>()?;
+///
+/// assert_eq!(mask, "192.0.2.0/24".parse::<Prefix<Ipv4>>()?.hostmask());
+/// assert!("255.255.255.0".parse::<Hostmask<Ipv4>>().is_err());
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> FromStr for Hostmask<A> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        (!Bitmask::from(s.parse::<Address<A>>()?))
+            .as_prefix_length()
+            .map(Self::from)
+            .ok_or_else(|| err!(Kind::ParserError, "address is not a valid mask").at(0))
+    }
+}
 
 impl<A: Afi, T: Type> fmt::Display for Mask<T, A>
 where
@@ -158,3 +300,128 @@ where
             .boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString as _;
+
+    use proptest::{arbitrary::any, proptest};
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn ipv4_not_netmask_is_hostmask(len in any::<PrefixLength<Ipv4>>()) {
+            assert_eq!(!Netmask::from(len), Hostmask::from(len));
+        }
+
+        #[test]
+        fn ipv4_not_hostmask_is_netmask(len in any::<PrefixLength<Ipv4>>()) {
+            assert_eq!(!Hostmask::from(len), Netmask::from(len));
+        }
+
+        #[test]
+        fn ipv6_not_netmask_is_hostmask(len in any::<PrefixLength<Ipv6>>()) {
+            assert_eq!(!Netmask::from(len), Hostmask::from(len));
+        }
+
+        #[test]
+        fn ipv6_not_hostmask_is_netmask(len in any::<PrefixLength<Ipv6>>()) {
+            assert_eq!(!Hostmask::from(len), Netmask::from(len));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv4_bitmask_prefix_length_round_trip(len in any::<PrefixLength<Ipv4>>()) {
+            let mask = Bitmask::<Ipv4>::new(Netmask::from(len).into_primitive());
+            assert_eq!(mask.as_prefix_length(), Some(len));
+        }
+
+        #[test]
+        fn ipv6_bitmask_prefix_length_round_trip(len in any::<PrefixLength<Ipv6>>()) {
+            let mask = Bitmask::<Ipv6>::new(Netmask::from(len).into_primitive());
+            assert_eq!(mask.as_prefix_length(), Some(len));
+        }
+    }
+
+    #[test]
+    fn non_contiguous_bitmask_has_no_prefix_length() {
+        assert_eq!(Bitmask::<Ipv4>::new(0xff00_ff00).as_prefix_length(), None);
+        assert_eq!(Bitmask::<Ipv4>::new(0x0000_00ff).as_prefix_length(), None);
+        assert_eq!(Bitmask::<Ipv6>::new(1).as_prefix_length(), None);
+    }
+
+    #[test]
+    fn whole_space_bitmask_prefix_lengths() {
+        assert_eq!(
+            Bitmask::<Ipv4>::ZEROS.as_prefix_length(),
+            Some(PrefixLength::MIN)
+        );
+        assert_eq!(
+            Bitmask::<Ipv6>::ONES.as_prefix_length(),
+            Some(PrefixLength::MAX)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn ipv4_netmask_counts(len in any::<PrefixLength<Ipv4>>()) {
+            let mask = Netmask::from(len);
+            assert_eq!(mask.count_ones(), len.get());
+            assert_eq!(mask.count_zeros(), 32 - len.get());
+        }
+
+        #[test]
+        fn ipv6_hostmask_counts(len in any::<PrefixLength<Ipv6>>()) {
+            let mask = Hostmask::from(len);
+            assert_eq!(mask.count_ones(), 128 - len.get());
+            assert_eq!(mask.count_zeros(), len.get());
+        }
+    }
+
+    #[test]
+    fn not_whole_space_masks() {
+        assert_eq!(!Netmask::<Ipv4>::ZEROS, Hostmask::ONES);
+        assert_eq!(!Hostmask::<Ipv6>::ZEROS, Netmask::ONES);
+    }
+
+    proptest! {
+        #[test]
+        fn ipv4_netmask_parse_round_trip(len in any::<PrefixLength<Ipv4>>()) {
+            let mask = Netmask::from(len);
+            assert_eq!(mask.to_string().parse::<Netmask<Ipv4>>().unwrap(), mask);
+        }
+
+        #[test]
+        fn ipv6_hostmask_parse_round_trip(len in any::<PrefixLength<Ipv6>>()) {
+            let mask = Hostmask::from(len);
+            assert_eq!(mask.to_string().parse::<Hostmask<Ipv6>>().unwrap(), mask);
+        }
+    }
+
+    #[test]
+    fn parse_invalid_masks() {
+        assert!("255.255.0.255".parse::<Netmask<Ipv4>>().is_err());
+        assert!("0.0.0.255".parse::<Netmask<Ipv4>>().is_err());
+        assert!("::ffff".parse::<Netmask<Ipv6>>().is_err());
+        assert!("ffff::".parse::<Hostmask<Ipv6>>().is_err());
+        assert!("255.255.255.0".parse::<Netmask<Ipv6>>().is_err());
+    }
+
+    #[test]
+    fn parse_any_netmask() {
+        assert!(matches!(
+            "255.255.255.0".parse::<any::Netmask>(),
+            Ok(any::Netmask::Ipv4(_))
+        ));
+        assert!(matches!(
+            "ffff:ffff::".parse::<any::Netmask>(),
+            Ok(any::Netmask::Ipv6(_))
+        ));
+        assert!(matches!(
+            "::ffff".parse::<any::Hostmask>(),
+            Ok(any::Hostmask::Ipv6(_))
+        ));
+    }
+}
@@ -2,7 +2,13 @@ mod af;
 pub use self::af::{Afi, Ipv4, Ipv6};
 
 mod addr;
-pub use self::addr::{common_length, Address, Ipv6MulticastScope, Range as AddressRange};
+#[cfg(feature = "std")]
+pub use self::addr::Set as AddressSet;
+pub use self::addr::{
+    common_length, strip_port, Address, Ipv6MulticastFlags, Ipv6MulticastScope, Ipv6TeredoInfo,
+    ParseOptions, Range as AddressRange, SpecialPurpose,
+};
+pub(crate) use self::addr::{reverse_dns_afi, strip_brackets};
 
 mod mask;
 pub use self::mask::{types as mask_types, Bitmask, Hostmask, Mask, Netmask};
@@ -12,8 +18,15 @@ pub use self::interface::Interface;
 
 mod prefix;
 #[cfg(feature = "std")]
+pub(crate) use self::prefix::ListItems;
+#[cfg(feature = "std")]
 pub use self::prefix::Set as PrefixSet;
-pub use self::prefix::{Prefix, PrefixLength, PrefixOrdering, Range as PrefixRange, Subprefixes};
+#[cfg(feature = "std")]
+pub use self::prefix::{map as prefix_map, Map as PrefixMap};
+pub use self::prefix::{
+    sort_prefixes, BranchPoint, Prefix, PrefixLength, PrefixOrdering, Range as PrefixRange,
+    Subprefixes,
+};
 
 macro_rules! impl_try_from_any {
     ( $any_ty:ty {
@@ -35,3 +48,19 @@ macro_rules! impl_try_from_any {
     }
 }
 use impl_try_from_any;
+
+// Compute the 64-bit FNV-1a hash of `bytes`.
+//
+// This is used to implement the `fingerprint()` methods of concrete types,
+// and must never be changed, since fingerprints are documented to be stable
+// across crate versions.
+fn fnv1a<'a, I>(bytes: I) -> u64
+where
+    I: IntoIterator<Item = &'a u8>,
+{
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.into_iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
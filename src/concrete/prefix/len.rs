@@ -19,6 +19,25 @@ mod private {
 
     /// An IP prefix length guaranteed to be within appropriate bounds for
     /// address family `A`.
+    ///
+    /// # Parsing
+    ///
+    /// [`PrefixLength<A>`] can be parsed from a decimal string, with or
+    /// without a leading `/`. Values greater than [`Self::MAX`] produce an
+    /// error of kind [`PrefixLength`][Kind::PrefixLength], while any other
+    /// malformed input (including surrounding whitespace) produces a
+    /// [`ParserError`][Kind::ParserError].
+    ///
+    /// ``` rust
+    /// use ip::{Error, Ipv4, PrefixLength};
+    ///
+    /// let length = "24".parse::<PrefixLength<Ipv4>>()?;
+    ///
+    /// assert_eq!(length, "/24".parse()?);
+    /// assert!("33".parse::<PrefixLength<Ipv4>>().is_err());
+    /// assert!(" 24".parse::<PrefixLength<Ipv4>>().is_err());
+    /// # Ok::<(), Error>(())
+    /// ```
     #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
     pub struct PrefixLength<A: Afi>(<A::Primitive as primitive::Address<A>>::Length);
 
@@ -60,11 +79,104 @@ mod private {
         pub(super) const fn as_u8(&self) -> &u8 {
             &self.0
         }
+
+        /// Get the value of `self` as a [`u8`].
+        ///
+        /// # Examples
+        ///
+        /// ``` rust
+        /// use ip::{Ipv6, PrefixLength};
+        ///
+        /// let length = PrefixLength::<Ipv6>::try_from(48u8)?;
+        ///
+        /// assert_eq!(length.get(), 48);
+        /// # Ok::<(), ip::Error>(())
+        /// ```
+        #[must_use]
+        pub const fn get(&self) -> u8 {
+            self.0
+        }
     }
 }
 
 pub use self::private::PrefixLength;
 
+impl<A: Afi> PrefixLength<A> {
+    /// Add `n` to `self`, returning the resulting [`PrefixLength<A>`].
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`PrefixLength`][Kind::PrefixLength] is returned if
+    /// the result would be greater than [`Self::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, PrefixLength};
+    ///
+    /// let length = PrefixLength::<Ipv4>::from_primitive(24)?;
+    ///
+    /// assert_eq!(length.checked_add(4)?, PrefixLength::<Ipv4>::from_primitive(28)?);
+    /// assert!(length.checked_add(9).is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn checked_add(self, n: u8) -> Result<Self, Error> {
+        let l: usize = self.into_primitive().into();
+        l.checked_add(n.into())
+            .ok_or_else(|| err!(Kind::PrefixLength))
+            .and_then(Self::try_from)
+    }
+
+    /// Subtract `n` from `self`, returning the resulting [`PrefixLength<A>`].
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`PrefixLength`][Kind::PrefixLength] is returned if
+    /// the result would be less than [`Self::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv6, PrefixLength};
+    ///
+    /// let length = PrefixLength::<Ipv6>::from_primitive(48)?;
+    ///
+    /// assert_eq!(length.checked_sub(16)?, PrefixLength::<Ipv6>::from_primitive(32)?);
+    /// assert!(length.checked_sub(49).is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn checked_sub(self, n: u8) -> Result<Self, Error> {
+        let l: usize = self.into_primitive().into();
+        l.checked_sub(n.into())
+            .ok_or_else(|| err!(Kind::PrefixLength))
+            .and_then(Self::try_from)
+    }
+
+    /// Convert `self` into the corresponding [`any::PrefixLength`].
+    ///
+    /// This is equivalent to [`any::PrefixLength::from()`][From::from], and is
+    /// the inverse of [`any::PrefixLength::into_concrete()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Ipv4, PrefixLength};
+    ///
+    /// let length = PrefixLength::<Ipv4>::from_primitive(24)?;
+    ///
+    /// assert!(matches!(length.into_any(), PrefixLength::<Any>::Ipv4(_)));
+    /// assert_eq!(length.into_any().into_concrete(), Some(length));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn into_any(self) -> any::PrefixLength
+    where
+        any::PrefixLength: From<Self>,
+    {
+        self.into()
+    }
+}
+
 impl<A: Afi> TryFrom<usize> for PrefixLength<A> {
     type Error = Error;
 
@@ -76,6 +188,25 @@ impl<A: Afi> TryFrom<usize> for PrefixLength<A> {
     }
 }
 
+/// Conversion from the [`u8`] representation of a prefix length.
+impl<A: Afi> TryFrom<u8> for PrefixLength<A> {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        usize::from(value).try_into()
+    }
+}
+
+impl<A> From<PrefixLength<A>> for u8
+where
+    A: Afi,
+    A::Primitive: primitive::Address<A, Length = Self>,
+{
+    fn from(length: PrefixLength<A>) -> Self {
+        length.get()
+    }
+}
+
 impl<A: Afi> traits::PrefixLength for PrefixLength<A> {
     fn increment(self) -> Result<Self, Error> {
         let l = self.into_primitive();
@@ -176,6 +307,68 @@ mod tests {
             let length = input.parse::<PrefixLength<Ipv4>>();
             assert!(length.is_err_and(|err| err.kind() == Kind::PrefixLength));
         }
+
+        #[test]
+        fn parse_bare() {
+            let length = "24".parse::<PrefixLength<Ipv4>>().unwrap();
+            assert_eq!(length.get(), 24);
+            assert!("33"
+                .parse::<PrefixLength<Ipv4>>()
+                .is_err_and(|err| err.kind() == Kind::PrefixLength));
+            assert!("256"
+                .parse::<PrefixLength<Ipv4>>()
+                .is_err_and(|err| err.kind() == Kind::PrefixLength));
+        }
+
+        #[test]
+        fn u8_round_trip() {
+            let length = PrefixLength::<Ipv4>::try_from(24u8).unwrap();
+            assert_eq!(length.get(), 24);
+            assert_eq!(u8::from(length), 24);
+        }
+
+        #[test]
+        fn try_from_u8_max() {
+            assert_eq!(
+                PrefixLength::<Ipv4>::try_from(32u8).unwrap(),
+                PrefixLength::MAX
+            );
+        }
+
+        #[test]
+        fn try_from_u8_too_long() {
+            assert!(PrefixLength::<Ipv4>::try_from(33u8)
+                .is_err_and(|err| err.kind() == Kind::PrefixLength));
+        }
+
+        #[test]
+        fn checked_add_to_max() {
+            let length = PrefixLength::<Ipv4>::from_primitive(24).unwrap();
+            assert_eq!(length.checked_add(8).unwrap(), PrefixLength::MAX);
+        }
+
+        #[test]
+        fn checked_add_overflow() {
+            let length = PrefixLength::<Ipv4>::from_primitive(24).unwrap();
+            assert!(length
+                .checked_add(9)
+                .is_err_and(|err| err.kind() == Kind::PrefixLength));
+            assert!(PrefixLength::<Ipv4>::MAX.checked_add(u8::MAX).is_err());
+        }
+
+        #[test]
+        fn checked_sub_to_min() {
+            let length = PrefixLength::<Ipv4>::from_primitive(24).unwrap();
+            assert_eq!(length.checked_sub(24).unwrap(), PrefixLength::MIN);
+        }
+
+        #[test]
+        fn checked_sub_underflow() {
+            let length = PrefixLength::<Ipv4>::from_primitive(24).unwrap();
+            assert!(length
+                .checked_sub(25)
+                .is_err_and(|err| err.kind() == Kind::PrefixLength));
+        }
     }
 
     mod ipv6 {
@@ -190,9 +383,43 @@ mod tests {
 
         #[test]
         fn parse_invalid() {
-            let input = "128";
+            let input = "129";
             let length = input.parse::<PrefixLength<Ipv6>>();
-            assert!(length.is_err_and(|err| err.kind() == Kind::ParserError));
+            assert!(length.is_err_and(|err| err.kind() == Kind::PrefixLength));
+        }
+
+        #[test]
+        fn parse_bare() {
+            let length = "128".parse::<PrefixLength<Ipv6>>().unwrap();
+            assert_eq!(length, PrefixLength::MAX);
+        }
+
+        #[test]
+        fn parse_whitespace() {
+            for input in [" 64", "64 ", "/ 64", "6 4"] {
+                let length = input.parse::<PrefixLength<Ipv6>>();
+                assert!(length.is_err_and(|err| err.kind() == Kind::ParserError));
+            }
+        }
+
+        #[test]
+        fn try_from_u8_beyond_ipv4_max() {
+            let length = PrefixLength::<Ipv6>::try_from(128u8).unwrap();
+            assert_eq!(u8::from(length), 128);
+            assert!(PrefixLength::<Ipv6>::try_from(129u8).is_err());
+        }
+
+        #[test]
+        fn checked_add_beyond_ipv4_max() {
+            let length = PrefixLength::<Ipv6>::from_primitive(32).unwrap();
+            assert_eq!(length.checked_add(96).unwrap(), PrefixLength::MAX);
+            assert!(length.checked_add(97).is_err());
+        }
+
+        #[test]
+        fn checked_sub_zero() {
+            let length = PrefixLength::<Ipv6>::from_primitive(64).unwrap();
+            assert_eq!(length.checked_sub(0).unwrap(), length);
         }
     }
 }
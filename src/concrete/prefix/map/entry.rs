@@ -0,0 +1,201 @@
+use super::{node::Node, Map};
+use crate::{concrete::Prefix, traits::Afi};
+
+/// A view into a single entry of a [`PrefixMap<A, V>`][Map], which may be
+/// either occupied or vacant.
+///
+/// Returned by [`PrefixMap::entry()`][Map::entry].
+#[derive(Debug)]
+pub enum Entry<'a, A: Afi, V> {
+    /// An entry for a prefix that is present in the map.
+    Occupied(OccupiedEntry<'a, A, V>),
+    /// An entry for a prefix that is absent from the map.
+    Vacant(VacantEntry<'a, A, V>),
+}
+
+impl<'a, A: Afi, V> Entry<'a, A, V> {
+    /// Get the prefix of this entry.
+    #[must_use]
+    pub const fn key(&self) -> &Prefix<A> {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Insert `default` if the entry is vacant, and return a mutable
+    /// reference to the value of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv4, Prefix};
+    /// let mut map = PrefixMap::<Ipv4, u32>::new();
+    /// let prefix = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    /// *map.entry(prefix).or_insert(0) += 1;
+    /// *map.entry(prefix).or_insert(0) += 1;
+    /// assert_eq!(map.get(prefix), Some(&2));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Insert the result of calling `f` if the entry is vacant, and return a
+    /// mutable reference to the value of the entry.
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Insert [`V::default()`][Default::default] if the entry is vacant,
+    /// and return a mutable reference to the value of the entry.
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Call `f` with a mutable reference to the value of the entry if it is
+    /// occupied, leaving a vacant entry untouched.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv6, Prefix};
+    /// let mut map = PrefixMap::<Ipv6, Vec<u32>>::new();
+    /// let prefix = "2001:db8::/32".parse::<Prefix<Ipv6>>()?;
+    /// map.entry(prefix)
+    ///     .and_modify(|asns| asns.push(64496))
+    ///     .or_insert_with(|| vec![64497]);
+    /// map.entry(prefix)
+    ///     .and_modify(|asns| asns.push(64498))
+    ///     .or_insert_with(|| vec![64499]);
+    /// assert_eq!(map.get(prefix), Some(&vec![64497, 64498]));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Self::Occupied(ref mut entry) = self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+/// A view into an occupied entry of a [`PrefixMap<A, V>`][Map].
+///
+/// See [`Entry`].
+#[derive(Debug)]
+pub struct OccupiedEntry<'a, A: Afi, V> {
+    map: &'a mut Map<A, V>,
+    key: Prefix<A>,
+}
+
+impl<'a, A: Afi, V> OccupiedEntry<'a, A, V> {
+    pub(super) const fn new(map: &'a mut Map<A, V>, key: Prefix<A>) -> Self {
+        Self { map, key }
+    }
+
+    /// Get the prefix of this entry.
+    #[must_use]
+    pub const fn key(&self) -> &Prefix<A> {
+        &self.key
+    }
+
+    /// Get a reference to the value of this entry.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn get(&self) -> &V {
+        Node::get(self.map.root.as_deref(), &self.key)
+            // Ok to unwrap because an `OccupiedEntry` is only constructed
+            // for a prefix that is present in the map, and holds the only
+            // reference to it.
+            .unwrap()
+    }
+
+    /// Get a mutable reference to the value of this entry.
+    ///
+    /// Use [`OccupiedEntry::into_mut()`] to obtain a reference that
+    /// outlives the entry.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn get_mut(&mut self) -> &mut V {
+        Node::get_mut(self.map.root.as_deref_mut(), &self.key)
+            // Ok to unwrap because an `OccupiedEntry` is only constructed
+            // for a prefix that is present in the map, and holds the only
+            // reference to it.
+            .unwrap()
+    }
+
+    /// Convert this entry into a mutable reference to its value, bound to
+    /// the lifetime of the map.
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn into_mut(self) -> &'a mut V {
+        Node::get_mut(self.map.root.as_deref_mut(), &self.key)
+            // Ok to unwrap because an `OccupiedEntry` is only constructed
+            // for a prefix that is present in the map, and holds the only
+            // reference to it.
+            .unwrap()
+    }
+
+    /// Replace the value of this entry with `value`, returning the old
+    /// value.
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Remove this entry from the map, returning its value.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn remove(self) -> V {
+        self.map
+            .remove(self.key)
+            // Ok to unwrap because an `OccupiedEntry` is only constructed
+            // for a prefix that is present in the map, and holds the only
+            // reference to it.
+            .unwrap()
+    }
+}
+
+/// A view into a vacant entry of a [`PrefixMap<A, V>`][Map].
+///
+/// See [`Entry`].
+#[derive(Debug)]
+pub struct VacantEntry<'a, A: Afi, V> {
+    map: &'a mut Map<A, V>,
+    key: Prefix<A>,
+}
+
+impl<'a, A: Afi, V> VacantEntry<'a, A, V> {
+    pub(super) const fn new(map: &'a mut Map<A, V>, key: Prefix<A>) -> Self {
+        Self { map, key }
+    }
+
+    /// Get the prefix of this entry.
+    #[must_use]
+    pub const fn key(&self) -> &Prefix<A> {
+        &self.key
+    }
+
+    /// Take ownership of the prefix of this entry.
+    #[must_use]
+    pub const fn into_key(self) -> Prefix<A> {
+        self.key
+    }
+
+    /// Insert `value` into the map at the prefix of this entry, returning a
+    /// mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.map.len += 1;
+        Node::value_slot(&mut self.map.root, self.key).insert(value)
+    }
+}
@@ -0,0 +1,239 @@
+use core::iter::FusedIterator;
+use std::boxed::Box;
+use std::vec::Vec;
+
+use super::node::Node;
+use crate::{
+    concrete::{BranchPoint, Prefix},
+    traits::Afi,
+};
+
+/// Non-consuming iterator returned by [`PrefixMap<A, V>::iter()`].
+///
+/// [`PrefixMap<A, V>::iter()`]: super::Map::iter
+#[derive(Debug)]
+pub struct Iter<'a, A: Afi, V> {
+    walk: Walk<'a, A, V>,
+    remaining: usize,
+}
+
+impl<'a, A: Afi, V> Iter<'a, A, V> {
+    pub(super) fn new(root: Option<&'a Node<A, V>>, len: usize) -> Self {
+        Self {
+            walk: Walk::new(root),
+            remaining: len,
+        }
+    }
+}
+
+impl<A: Afi, V> Clone for Iter<'_, A, V> {
+    fn clone(&self) -> Self {
+        Self {
+            walk: self.walk.clone(),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<'a, A: Afi, V> Iterator for Iter<'a, A, V> {
+    type Item = (Prefix<A>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.walk.next();
+        if next.is_some() {
+            self.remaining -= 1;
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<A: Afi, V> ExactSizeIterator for Iter<'_, A, V> {}
+
+impl<A: Afi, V> FusedIterator for Iter<'_, A, V> {}
+
+// Pre-order, left-first walk over the entries of a subtree, which yields
+// entries in prefix order.
+#[derive(Debug)]
+pub(super) struct Walk<'a, A: Afi, V> {
+    stack: Vec<&'a Node<A, V>>,
+}
+
+impl<'a, A: Afi, V> Walk<'a, A, V> {
+    pub(super) fn new(root: Option<&'a Node<A, V>>) -> Self {
+        Self {
+            stack: root.into_iter().collect(),
+        }
+    }
+}
+
+impl<A: Afi, V> Clone for Walk<'_, A, V> {
+    fn clone(&self) -> Self {
+        Self {
+            stack: self.stack.clone(),
+        }
+    }
+}
+
+impl<'a, A: Afi, V> Iterator for Walk<'a, A, V> {
+    type Item = (Prefix<A>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            self.stack.extend(node.right.as_deref());
+            self.stack.extend(node.left.as_deref());
+            if let Some(value) = node.value.as_ref() {
+                return Some((node.prefix, value));
+            }
+        }
+        None
+    }
+}
+
+impl<A: Afi, V> FusedIterator for Walk<'_, A, V> {}
+
+/// Iterator returned by [`PrefixMap<A, V>::matches()`].
+///
+/// [`PrefixMap<A, V>::matches()`]: super::Map::matches
+#[derive(Debug)]
+pub struct Matches<'a, A: Afi, V> {
+    cursor: Option<&'a Node<A, V>>,
+    query: Prefix<A>,
+}
+
+impl<'a, A: Afi, V> Matches<'a, A, V> {
+    pub(super) const fn new(root: Option<&'a Node<A, V>>, query: Prefix<A>) -> Self {
+        Self {
+            cursor: root,
+            query,
+        }
+    }
+}
+
+impl<A: Afi, V> Clone for Matches<'_, A, V> {
+    fn clone(&self) -> Self {
+        Self {
+            cursor: self.cursor,
+            query: self.query,
+        }
+    }
+}
+
+impl<'a, A: Afi, V> Iterator for Matches<'a, A, V> {
+    type Item = (Prefix<A>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.cursor.take() {
+            match node.prefix.branch_point(&self.query) {
+                BranchPoint::Equal => {}
+                BranchPoint::Contains => self.cursor = node.child_towards(&self.query),
+                BranchPoint::ContainedBy | BranchPoint::Diverges { .. } => return None,
+            }
+            if let Some(value) = node.value.as_ref() {
+                return Some((node.prefix, value));
+            }
+        }
+        None
+    }
+}
+
+impl<A: Afi, V> FusedIterator for Matches<'_, A, V> {}
+
+/// Mutable iterator returned by [`PrefixMap<A, V>::iter_mut()`].
+///
+/// [`PrefixMap<A, V>::iter_mut()`]: super::Map::iter_mut
+#[derive(Debug)]
+pub struct IterMut<'a, A: Afi, V> {
+    stack: Vec<&'a mut Node<A, V>>,
+    remaining: usize,
+}
+
+impl<'a, A: Afi, V> IterMut<'a, A, V> {
+    pub(super) fn new(root: Option<&'a mut Node<A, V>>, len: usize) -> Self {
+        Self {
+            stack: root.into_iter().collect(),
+            remaining: len,
+        }
+    }
+}
+
+impl<'a, A: Afi, V> Iterator for IterMut<'a, A, V> {
+    type Item = (Prefix<A>, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            let Node {
+                prefix,
+                value,
+                left,
+                right,
+            } = node;
+            self.stack.extend(right.as_deref_mut());
+            self.stack.extend(left.as_deref_mut());
+            if let Some(value) = value.as_mut() {
+                self.remaining -= 1;
+                return Some((*prefix, value));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<A: Afi, V> ExactSizeIterator for IterMut<'_, A, V> {}
+
+impl<A: Afi, V> FusedIterator for IterMut<'_, A, V> {}
+
+/// Consuming iterator returned by [`PrefixMap<A, V>::into_iter()`].
+///
+/// [`PrefixMap<A, V>::into_iter()`]: super::Map#impl-IntoIterator-for-Map<A,+V>
+#[derive(Debug)]
+pub struct IntoIter<A: Afi, V> {
+    stack: Vec<Box<Node<A, V>>>,
+    remaining: usize,
+}
+
+impl<A: Afi, V> IntoIter<A, V> {
+    pub(super) fn new(root: Option<Box<Node<A, V>>>, len: usize) -> Self {
+        Self {
+            stack: root.into_iter().collect(),
+            remaining: len,
+        }
+    }
+}
+
+impl<A: Afi, V> Iterator for IntoIter<A, V> {
+    type Item = (Prefix<A>, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            let Node {
+                prefix,
+                value,
+                left,
+                right,
+            } = *node;
+            self.stack.extend(right);
+            self.stack.extend(left);
+            if let Some(value) = value {
+                self.remaining -= 1;
+                return Some((prefix, value));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<A: Afi, V> ExactSizeIterator for IntoIter<A, V> {}
+
+impl<A: Afi, V> FusedIterator for IntoIter<A, V> {}
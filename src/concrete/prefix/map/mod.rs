@@ -0,0 +1,468 @@
+//! Types associated with [`PrefixMap<A, V>`][Map].
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use std::boxed::Box;
+
+use super::{Address, Prefix};
+use crate::traits::Afi;
+
+mod entry;
+pub use self::entry::{Entry, OccupiedEntry, VacantEntry};
+
+mod iter;
+use self::iter::Walk;
+pub use self::iter::{IntoIter, Iter, IterMut, Matches};
+
+mod node;
+use self::node::Node;
+
+/// A map from IP prefixes to values of type `V`, providing fast lookup and
+/// iteration in prefix order.
+///
+/// Like [`PrefixSet<A>`](crate::concrete::PrefixSet), a map is built on a
+/// binary trie keyed by prefix. Unlike a set, the prefixes in a map are
+/// never aggregated: each [`Prefix<A>`] inserted into the map is stored
+/// exactly as given, and is only removed by an explicit call to
+/// [`PrefixMap::remove()`][Self::remove].
+///
+/// This makes [`PrefixMap<A, V>`][Self] suitable for building routing
+/// tables and similar structures, where each prefix is associated with some
+/// data (a next-hop, an origin ASN, etc).
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::PrefixMap, Address, Ipv4, Prefix};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct RibEntry {
+///     next_hop: Address<Ipv4>,
+///     origin: u32,
+/// }
+///
+/// let mut rib = PrefixMap::<Ipv4, RibEntry>::new();
+/// rib.insert(
+///     "192.0.2.0/24".parse()?,
+///     RibEntry {
+///         next_hop: "198.51.100.1".parse()?,
+///         origin: 64496,
+///     },
+/// );
+/// rib.insert(
+///     "192.0.2.0/25".parse()?,
+///     RibEntry {
+///         next_hop: "198.51.100.2".parse()?,
+///         origin: 64497,
+///     },
+/// );
+///
+/// // both prefixes are kept, despite one covering the other
+/// assert_eq!(rib.len(), 2);
+/// assert_eq!(
+///     rib.get("192.0.2.0/25".parse::<Prefix<Ipv4>>()?)
+///         .map(|entry| entry.origin),
+///     Some(64497),
+/// );
+/// assert_eq!(rib.get("192.0.2.128/25".parse::<Prefix<Ipv4>>()?), None);
+/// # Ok::<(), ip::Error>(())
+/// ```
+///
+/// Iteration yields entries in prefix order: by network address, and then
+/// by prefix length (see [`Prefix::cmp_canonical()`]):
+///
+/// ``` rust
+/// # use ip::{concrete::PrefixMap, Ipv6, Prefix};
+/// let map = ["2001:db8:f00::/48", "2001:db8::/32", "2001:db8::/48"]
+///     .into_iter()
+///     .enumerate()
+///     .map(|(i, s)| Ok((s.parse::<Prefix<Ipv6>>()?, i)))
+///     .collect::<Result<PrefixMap<Ipv6, _>, ip::Error>>()?;
+/// let entries: Vec<_> = map
+///     .iter()
+///     .map(|(prefix, i)| format!("{prefix} => {i}"))
+///     .collect();
+/// assert_eq!(
+///     entries,
+///     [
+///         "2001:db8::/32 => 1",
+///         "2001:db8::/48 => 2",
+///         "2001:db8:f00::/48 => 0"
+///     ]
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct Map<A: Afi, V> {
+    root: Option<Box<Node<A, V>>>,
+    len: usize,
+}
+
+impl<A: Afi, V> Map<A, V> {
+    /// Construct a new, empty [`PrefixMap<A, V>`][Self].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Get the number of entries in `self`.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns [`true`] if `self` contains no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all entries from `self`.
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.len = 0;
+    }
+
+    /// Insert `value` into `self` at `prefix`.
+    ///
+    /// If `self` already contained a value for `prefix`, it is replaced and
+    /// the old value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv4, Prefix};
+    /// let mut map = PrefixMap::<Ipv4, &str>::new();
+    /// let prefix = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    /// assert_eq!(map.insert(prefix, "foo"), None);
+    /// assert_eq!(map.insert(prefix, "bar"), Some("foo"));
+    /// assert_eq!(map.get(prefix), Some(&"bar"));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn insert(&mut self, prefix: Prefix<A>, value: V) -> Option<V> {
+        let old = Node::value_slot(&mut self.root, prefix).replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Get a reference to the value associated with `prefix`, if any.
+    ///
+    /// Only an exact match for `prefix` is returned: values associated with
+    /// prefixes covering `prefix` are not considered.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv6, Prefix};
+    /// let map = PrefixMap::<Ipv6, u32>::from_iter([("2001:db8::/32".parse()?, 64496)]);
+    /// assert_eq!(map.get("2001:db8::/32".parse()?), Some(&64496));
+    /// assert_eq!(map.get("2001:db8::/48".parse()?), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn get(&self, prefix: Prefix<A>) -> Option<&V> {
+        Node::get(self.root.as_deref(), &prefix)
+    }
+
+    /// Get a mutable reference to the value associated with `prefix`, if
+    /// any.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv4, Prefix};
+    /// let prefix = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    /// let mut map = PrefixMap::<Ipv4, u32>::from_iter([(prefix, 1)]);
+    /// if let Some(value) = map.get_mut(prefix) {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(map.get(prefix), Some(&2));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn get_mut(&mut self, prefix: Prefix<A>) -> Option<&mut V> {
+        Node::get_mut(self.root.as_deref_mut(), &prefix)
+    }
+
+    /// Returns [`true`] if `self` contains a value for `prefix`.
+    #[must_use]
+    pub fn contains_key(&self, prefix: Prefix<A>) -> bool {
+        self.get(prefix).is_some()
+    }
+
+    /// Remove the value associated with `prefix` from `self`, returning it
+    /// if it was present.
+    ///
+    /// Values associated with subprefixes of `prefix` are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv4, Prefix};
+    /// let mut map = PrefixMap::<Ipv4, u32>::from_iter([
+    ///     ("192.0.2.0/24".parse()?, 1),
+    ///     ("192.0.2.0/25".parse()?, 2),
+    /// ]);
+    /// assert_eq!(map.remove("192.0.2.0/24".parse()?), Some(1));
+    /// assert_eq!(map.remove("192.0.2.0/24".parse()?), None);
+    /// assert_eq!(map.len(), 1);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn remove(&mut self, prefix: Prefix<A>) -> Option<V> {
+        let old = Node::remove(&mut self.root, &prefix);
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    /// Get the [`Entry`] for `prefix`, for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::{prefix_map::Entry, PrefixMap}, Ipv4, Prefix};
+    /// let mut map = PrefixMap::<Ipv4, u32>::new();
+    /// let prefix = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    ///
+    /// match map.entry(prefix) {
+    ///     Entry::Occupied(_) => unreachable!(),
+    ///     Entry::Vacant(entry) => _ = entry.insert(1),
+    /// }
+    /// match map.entry(prefix) {
+    ///     Entry::Occupied(entry) => assert_eq!(entry.remove(), 1),
+    ///     Entry::Vacant(_) => unreachable!(),
+    /// }
+    /// assert!(map.is_empty());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn entry(&mut self, prefix: Prefix<A>) -> Entry<'_, A, V> {
+        if self.contains_key(prefix) {
+            Entry::Occupied(OccupiedEntry::new(self, prefix))
+        } else {
+            Entry::Vacant(VacantEntry::new(self, prefix))
+        }
+    }
+
+    /// Get an iterator over the entries of `self`, in prefix order.
+    ///
+    /// Entries are ordered by network address, and then by prefix length,
+    /// so that each prefix is yielded before any of its subprefixes. See
+    /// [`Prefix::cmp_canonical()`].
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, A, V> {
+        Iter::new(self.root.as_deref(), self.len)
+    }
+
+    /// Get an iterator over the entries of `self`, in prefix order, with
+    /// mutable references to the values.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv4, Prefix};
+    /// let mut map = PrefixMap::<Ipv4, u32>::from_iter([
+    ///     ("192.0.2.0/24".parse()?, 1),
+    ///     ("198.51.100.0/24".parse()?, 2),
+    /// ]);
+    /// map.iter_mut().for_each(|(_, value)| *value *= 10);
+    /// assert_eq!(map.values().sum::<u32>(), 30);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, A, V> {
+        IterMut::new(self.root.as_deref_mut(), self.len)
+    }
+
+    /// Get an iterator over the prefixes of `self`, in prefix order.
+    #[must_use]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = Prefix<A>> + '_ {
+        self.iter().map(|(prefix, _)| prefix)
+    }
+
+    /// Get an iterator over the values of `self`, in prefix order.
+    #[must_use]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Find the entry for the longest prefix in `self` that contains `addr`,
+    /// if any.
+    ///
+    /// The lookup is a single descent of the tree, so its cost is bounded by
+    /// the depth of the tree (at most the address width in bits), rather
+    /// than by the number of entries in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Address, Ipv4, Prefix};
+    /// let map = PrefixMap::<Ipv4, &str>::from_iter([
+    ///     ("0.0.0.0/0".parse()?, "default"),
+    ///     ("192.0.2.0/24".parse()?, "customer"),
+    ///     ("192.0.2.128/25".parse()?, "customer-backup"),
+    /// ]);
+    /// assert_eq!(
+    ///     map.longest_match("192.0.2.1".parse::<Address<Ipv4>>()?),
+    ///     Some(("192.0.2.0/24".parse()?, &"customer")),
+    /// );
+    /// assert_eq!(
+    ///     map.longest_match("198.51.100.1".parse::<Address<Ipv4>>()?),
+    ///     Some(("0.0.0.0/0".parse()?, &"default")),
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn longest_match(&self, addr: Address<A>) -> Option<(Prefix<A>, &V)> {
+        self.matches(addr).last()
+    }
+
+    /// Get an iterator over the entries for all of the prefixes in `self`
+    /// that contain `addr`, from shortest to longest.
+    ///
+    /// Like [`PrefixMap::longest_match()`][Self::longest_match], this is a
+    /// single descent of the tree.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Address, Ipv6, Prefix};
+    /// let map = PrefixMap::<Ipv6, u32>::from_iter([
+    ///     ("2001:db8::/32".parse()?, 32),
+    ///     ("2001:db8::/48".parse()?, 48),
+    ///     ("2001:db8:1::/48".parse()?, 0),
+    ///     ("2001:db8::/64".parse()?, 64),
+    /// ]);
+    /// let lengths: Vec<_> = map
+    ///     .matches("2001:db8::1".parse::<Address<Ipv6>>()?)
+    ///     .map(|(_, value)| *value)
+    ///     .collect();
+    /// assert_eq!(lengths, [32, 48, 64]);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn matches(&self, addr: Address<A>) -> Matches<'_, A, V> {
+        Matches::new(self.root.as_deref(), addr.into())
+    }
+
+    /// Get an iterator over the entries for all of the prefixes in `self`
+    /// that are covered by `cover`, including `cover` itself, in prefix
+    /// order.
+    ///
+    /// The subtree containing the covered prefixes is found by a single
+    /// descent of the tree, and only that subtree is then traversed.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{concrete::PrefixMap, Ipv4, Prefix};
+    /// let map = PrefixMap::<Ipv4, ()>::from_iter(
+    ///     ["10.0.0.0/8", "10.0.0.0/16", "10.1.0.0/16", "10.0.0.0/24", "172.16.0.0/12"]
+    ///         .into_iter()
+    ///         .map(|s| Ok((s.parse()?, ())))
+    ///         .collect::<Result<Vec<_>, ip::Error>>()?,
+    /// );
+    /// let covered: Vec<_> = map
+    ///     .covered_by("10.0.0.0/15".parse()?)
+    ///     .map(|(prefix, _)| prefix.to_string())
+    ///     .collect();
+    /// assert_eq!(covered, ["10.0.0.0/16", "10.0.0.0/24", "10.1.0.0/16"]);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn covered_by(&self, cover: Prefix<A>) -> impl Iterator<Item = (Prefix<A>, &V)> + '_ {
+        Walk::new(Node::within(self.root.as_deref(), &cover))
+    }
+}
+
+impl<A: Afi, V> Default for Map<A, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Afi, V: fmt::Debug> fmt::Debug for Map<A, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+impl<A: Afi, V: PartialEq> PartialEq for Map<A, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<A: Afi, V: Eq> Eq for Map<A, V> {}
+
+impl<A: Afi, V: Hash> Hash for Map<A, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+        self.iter().for_each(|entry| entry.hash(state));
+    }
+}
+
+/// Borrow a [`PrefixMap<A, V>`][Map], iterating over its entries in prefix
+/// order.
+///
+/// This is equivalent to [`PrefixMap::iter()`][Map::iter].
+impl<'a, A: Afi, V> IntoIterator for &'a Map<A, V> {
+    type Item = (Prefix<A>, &'a V);
+    type IntoIter = Iter<'a, A, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mutably borrow a [`PrefixMap<A, V>`][Map], iterating over its entries in
+/// prefix order.
+///
+/// This is equivalent to [`PrefixMap::iter_mut()`][Map::iter_mut].
+impl<'a, A: Afi, V> IntoIterator for &'a mut Map<A, V> {
+    type Item = (Prefix<A>, &'a mut V);
+    type IntoIter = IterMut<'a, A, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Consume a [`PrefixMap<A, V>`][Map], iterating over its entries in prefix
+/// order.
+///
+/// The underlying tree is dropped incrementally as the iterator advances.
+impl<A: Afi, V> IntoIterator for Map<A, V> {
+    type Item = (Prefix<A>, V);
+    type IntoIter = IntoIter<A, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.root, self.len)
+    }
+}
+
+/// Extend a [`PrefixMap<A, V>`][Map] with `(prefix, value)` pairs.
+///
+/// Values for prefixes already present in the map are replaced.
+impl<A: Afi, V> Extend<(Prefix<A>, V)> for Map<A, V> {
+    fn extend<T>(&mut self, iter: T)
+    where
+        T: IntoIterator<Item = (Prefix<A>, V)>,
+    {
+        iter.into_iter().for_each(|(prefix, value)| {
+            _ = self.insert(prefix, value);
+        });
+    }
+}
+
+impl<A: Afi, V> FromIterator<(Prefix<A>, V)> for Map<A, V> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (Prefix<A>, V)>,
+    {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests;
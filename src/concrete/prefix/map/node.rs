@@ -0,0 +1,153 @@
+use std::boxed::Box;
+
+use super::super::set::{branch_direction, Direction};
+use crate::{
+    concrete::{BranchPoint, Prefix},
+    traits::{Afi, Prefix as _},
+};
+
+// A node in the binary trie underlying a `PrefixMap<A, V>`.
+//
+// Nodes without a value are "glue" nodes, which exist only to join a pair of
+// diverging subtrees. Every glue node therefore has exactly two children.
+#[derive(Clone, Debug)]
+pub(super) struct Node<A: Afi, V> {
+    pub(super) prefix: Prefix<A>,
+    pub(super) value: Option<V>,
+    pub(super) left: Option<Box<Self>>,
+    pub(super) right: Option<Box<Self>>,
+}
+
+impl<A: Afi, V> Node<A, V> {
+    const fn new(prefix: Prefix<A>) -> Self {
+        Self {
+            prefix,
+            value: None,
+            left: None,
+            right: None,
+        }
+    }
+
+    fn boxed(self) -> Box<Self> {
+        Box::new(self)
+    }
+
+    fn child(&self, direction: &Direction) -> Option<&Self> {
+        match direction {
+            Direction::Left => self.left.as_deref(),
+            Direction::Right => self.right.as_deref(),
+        }
+    }
+
+    const fn child_mut(&mut self, direction: &Direction) -> &mut Option<Box<Self>> {
+        match direction {
+            Direction::Left => &mut self.left,
+            Direction::Right => &mut self.right,
+        }
+    }
+
+    // Returns the child of `self` in the direction of `prefix`, which must be
+    // a strict subprefix of `self.prefix`.
+    pub(super) fn child_towards(&self, prefix: &Prefix<A>) -> Option<&Self> {
+        self.child(&branch_direction(prefix, self.prefix.length()))
+    }
+
+    // Returns `true` if `self` neither holds a value nor joins a pair of
+    // subtrees, and so should be replaced by its child (if any).
+    const fn is_redundant(&self) -> bool {
+        self.value.is_none() && (self.left.is_none() || self.right.is_none())
+    }
+
+    pub(super) fn get<'a>(mut cursor: Option<&'a Self>, prefix: &Prefix<A>) -> Option<&'a V> {
+        while let Some(node) = cursor {
+            match node.prefix.branch_point(prefix) {
+                BranchPoint::Equal => return node.value.as_ref(),
+                BranchPoint::Contains => cursor = node.child_towards(prefix),
+                BranchPoint::ContainedBy | BranchPoint::Diverges { .. } => return None,
+            }
+        }
+        None
+    }
+
+    // Returns the root of the subtree containing every node covered by
+    // `cover`, if any.
+    pub(super) fn within<'a>(mut cursor: Option<&'a Self>, cover: &Prefix<A>) -> Option<&'a Self> {
+        while let Some(node) = cursor {
+            match node.prefix.branch_point(cover) {
+                BranchPoint::Equal | BranchPoint::ContainedBy => return Some(node),
+                BranchPoint::Contains => cursor = node.child_towards(cover),
+                BranchPoint::Diverges { .. } => return None,
+            }
+        }
+        None
+    }
+
+    pub(super) fn get_mut<'a>(
+        mut cursor: Option<&'a mut Self>,
+        prefix: &Prefix<A>,
+    ) -> Option<&'a mut V> {
+        while let Some(node) = cursor {
+            match node.prefix.branch_point(prefix) {
+                BranchPoint::Equal => return node.value.as_mut(),
+                BranchPoint::Contains => {
+                    let direction = branch_direction(prefix, node.prefix.length());
+                    cursor = node.child_mut(&direction).as_deref_mut();
+                }
+                BranchPoint::ContainedBy | BranchPoint::Diverges { .. } => return None,
+            }
+        }
+        None
+    }
+
+    // Returns the value slot of the node for `prefix` in the tree rooted at
+    // `slot`, creating the node (and any glue node required to attach it)
+    // if it does not already exist.
+    //
+    // The caller must ensure that the returned slot is filled, so that no
+    // redundant nodes are left in the tree.
+    pub(super) fn value_slot(slot: &mut Option<Box<Self>>, prefix: Prefix<A>) -> &mut Option<V> {
+        let node = slot.take().map_or_else(
+            || Self::new(prefix).boxed(),
+            |node| match node.prefix.branch_point(&prefix) {
+                BranchPoint::Equal | BranchPoint::Contains => node,
+                BranchPoint::ContainedBy => {
+                    let mut new = Self::new(prefix).boxed();
+                    let direction = branch_direction(&node.prefix, prefix.length());
+                    *new.child_mut(&direction) = Some(node);
+                    new
+                }
+                BranchPoint::Diverges { at } => {
+                    let mut glue = Self::new(Prefix::new(prefix.network(), at)).boxed();
+                    let direction = branch_direction(&node.prefix, at);
+                    *glue.child_mut(&direction) = Some(node);
+                    glue
+                }
+            },
+        );
+        let node = slot.insert(node);
+        if node.prefix == prefix {
+            &mut node.value
+        } else {
+            let direction = branch_direction(&prefix, node.prefix.length());
+            Self::value_slot(node.child_mut(&direction), prefix)
+        }
+    }
+
+    // Removes and returns the value of the node for `prefix` in the tree
+    // rooted at `slot`, compacting the tree along the way.
+    pub(super) fn remove(slot: &mut Option<Box<Self>>, prefix: &Prefix<A>) -> Option<V> {
+        let node = slot.as_mut()?;
+        let value = match node.prefix.branch_point(prefix) {
+            BranchPoint::Equal => node.value.take(),
+            BranchPoint::Contains => {
+                let direction = branch_direction(prefix, node.prefix.length());
+                Self::remove(node.child_mut(&direction), prefix)
+            }
+            BranchPoint::ContainedBy | BranchPoint::Diverges { .. } => None,
+        };
+        if value.is_some() && slot.as_ref().is_some_and(|node| node.is_redundant()) {
+            *slot = slot.take().and_then(|node| node.left.or(node.right));
+        }
+        value
+    }
+}
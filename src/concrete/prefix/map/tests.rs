@@ -0,0 +1,334 @@
+use std::vec::Vec;
+
+use proptest::{arbitrary::any, collection::vec, prelude::*};
+
+use super::{Entry, Map, Node};
+use crate::{
+    concrete::{Address, BranchPoint, Prefix, PrefixLength},
+    error::TestResult,
+    Ipv4, Ipv6,
+};
+
+#[allow(clippy::missing_const_for_fn)]
+fn assert_send<T: Send>(_: T) {}
+
+#[allow(clippy::missing_const_for_fn)]
+fn assert_sync<T: Sync>(_: T) {}
+
+// Assert that the tree rooted at `node` is well formed, returning the number
+// of values that it holds.
+fn check_node<A: crate::traits::Afi, V>(node: &Node<A, V>) -> usize {
+    assert!(
+        node.value.is_some() || (node.left.is_some() && node.right.is_some()),
+        "redundant node at {}",
+        node.prefix
+    );
+    [&node.left, &node.right]
+        .into_iter()
+        .flatten()
+        .inspect(|child| {
+            assert_eq!(
+                node.prefix.branch_point(&child.prefix),
+                BranchPoint::Contains
+            );
+        })
+        .fold(usize::from(node.value.is_some()), |count, child| {
+            count + check_node(child)
+        })
+}
+
+fn check<A: crate::traits::Afi, V>(map: &Map<A, V>) {
+    assert_eq!(map.root.as_deref().map_or(0, check_node), map.len());
+}
+
+mod new_ipv4_prefix_map {
+    use super::*;
+
+    const fn setup() -> Map<Ipv4, u32> {
+        Map::new()
+    }
+
+    #[test]
+    fn is_send() {
+        assert_send(setup());
+    }
+
+    #[test]
+    fn is_sync() {
+        assert_sync(setup());
+    }
+
+    #[test]
+    fn is_empty() {
+        let map = setup();
+        assert!(map.is_empty());
+        assert!(map.root.is_none());
+        assert_eq!(map.iter().next(), None);
+    }
+
+    #[test]
+    fn get_is_none() -> TestResult {
+        assert_eq!(setup().get("0.0.0.0/0".parse()?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn remove_is_none() -> TestResult {
+        assert_eq!(setup().remove("0.0.0.0/0".parse()?), None);
+        Ok(())
+    }
+}
+
+mod no_aggregation {
+    use super::*;
+
+    #[test]
+    fn sibling_prefixes_are_kept() -> TestResult {
+        let map = Map::<Ipv4, _>::from_iter([
+            ("192.0.2.0/25".parse()?, 'a'),
+            ("192.0.2.128/25".parse()?, 'b'),
+        ]);
+        check(&map);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("192.0.2.0/24".parse()?), None);
+        assert_eq!(map.get("192.0.2.128/25".parse()?), Some(&'b'));
+        Ok(())
+    }
+
+    #[test]
+    fn covered_prefixes_are_kept() -> TestResult {
+        let map = Map::<Ipv4, _>::from_iter([
+            ("192.0.2.0/24".parse()?, 'a'),
+            ("192.0.2.0/25".parse()?, 'b'),
+            ("192.0.2.0/26".parse()?, 'c'),
+        ]);
+        check(&map);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), ['a', 'b', 'c']);
+        Ok(())
+    }
+}
+
+mod compaction {
+    use super::*;
+
+    #[test]
+    fn removing_a_branch_removes_glue() -> TestResult {
+        let mut map = Map::<Ipv6, _>::from_iter([
+            ("2001:db8::/48".parse()?, 1),
+            ("2001:db8:1::/48".parse()?, 2),
+        ]);
+        check(&map);
+        assert_eq!(map.root.as_ref().map(|root| root.value), Some(None));
+
+        assert_eq!(map.remove("2001:db8::/48".parse()?), Some(1));
+        check(&map);
+        assert_eq!(
+            map.root.as_ref().map(|root| root.prefix),
+            Some("2001:db8:1::/48".parse()?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn removing_a_parent_keeps_glue() -> TestResult {
+        let mut map = Map::<Ipv6, _>::from_iter([
+            ("2001:db8::/32".parse()?, 0),
+            ("2001:db8::/48".parse()?, 1),
+            ("2001:db8:1::/48".parse()?, 2),
+        ]);
+        assert_eq!(map.remove("2001:db8::/32".parse()?), Some(0));
+        check(&map);
+        assert_eq!(
+            map.root.as_ref().map(|root| root.prefix),
+            Some("2001:db8::/47".parse()?)
+        );
+        assert_eq!(map.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn removing_everything_empties_the_tree() -> TestResult {
+        let prefixes = ["10.0.0.0/8", "10.0.0.0/16", "10.1.0.0/16", "10.0.0.0/24"]
+            .into_iter()
+            .map(str::parse::<Prefix<Ipv4>>)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut map: Map<Ipv4, _> = prefixes.iter().map(|p| (*p, ())).collect();
+        for prefix in prefixes {
+            assert_eq!(map.remove(prefix), Some(()));
+            check(&map);
+        }
+        assert!(map.root.is_none());
+        Ok(())
+    }
+}
+
+mod entry {
+    use super::*;
+
+    #[test]
+    fn vacant_then_occupied() -> TestResult {
+        let mut map = Map::<Ipv4, u32>::new();
+        let prefix = "198.51.100.0/24".parse()?;
+        match map.entry(prefix) {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), &prefix);
+                *entry.insert(1) += 1;
+            }
+            Entry::Occupied(_) => panic!("expected vacant entry"),
+        }
+        match map.entry(prefix) {
+            Entry::Occupied(mut entry) => {
+                assert_eq!(entry.get(), &2);
+                assert_eq!(entry.insert(3), 2);
+            }
+            Entry::Vacant(_) => panic!("expected occupied entry"),
+        }
+        assert_eq!(map.get(prefix), Some(&3));
+        assert_eq!(map.len(), 1);
+        check(&map);
+        Ok(())
+    }
+
+    #[test]
+    fn or_default_counts() -> TestResult {
+        let mut map = Map::<Ipv4, usize>::new();
+        for s in ["192.0.2.0/24", "192.0.2.0/25", "192.0.2.0/24"] {
+            *map.entry(s.parse()?).or_default() += 1;
+        }
+        assert_eq!(map.get("192.0.2.0/24".parse()?), Some(&2));
+        assert_eq!(map.get("192.0.2.0/25".parse()?), Some(&1));
+        check(&map);
+        Ok(())
+    }
+
+    #[test]
+    fn occupied_remove() -> TestResult {
+        let mut map = Map::<Ipv4, _>::from_iter([
+            ("192.0.2.0/25".parse()?, 'a'),
+            ("192.0.2.128/25".parse()?, 'b'),
+        ]);
+        if let Entry::Occupied(entry) = map.entry("192.0.2.128/25".parse()?) {
+            assert_eq!(entry.remove(), 'b');
+        }
+        assert_eq!(map.len(), 1);
+        check(&map);
+        Ok(())
+    }
+}
+
+mod iteration {
+    use super::*;
+
+    #[test]
+    fn into_iter_yields_owned_values() -> TestResult {
+        let map = Map::<Ipv4, _>::from_iter([
+            ("10.0.0.0/8".parse()?, std::string::String::from("a")),
+            ("0.0.0.0/0".parse()?, std::string::String::from("b")),
+        ]);
+        let mut iter = map.into_iter();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(("0.0.0.0/0".parse()?, "b".into())));
+        assert_eq!(iter.next(), Some(("10.0.0.0/8".parse()?, "a".into())));
+        assert_eq!(iter.next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn host_routes_are_supported() -> TestResult {
+        let map = Map::<Ipv6, _>::from_iter([
+            ("::/0".parse()?, 0),
+            ("::1/128".parse()?, 1),
+            ("::/128".parse()?, 2),
+        ]);
+        check(&map);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), [0, 2, 1]);
+        Ok(())
+    }
+}
+
+// Generate prefixes within 10.0.0.0/8, with a small enough spread of
+// lengths and networks that they frequently overlap.
+fn prefix() -> impl Strategy<Value = Prefix<Ipv4>> {
+    (any::<u8>(), 8u8..=20).prop_map(|(bits, len)| {
+        Prefix::new(
+            Address::new(0x0a00_0000 | (u32::from(bits) << 12)),
+            PrefixLength::from_primitive(len).unwrap(),
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_model(
+        ops in vec((any::<bool>(), prefix(), any::<u16>()), 0..64),
+    ) {
+        let mut map = Map::<Ipv4, u16>::new();
+        let mut model: Vec<(Prefix<Ipv4>, u16)> = Vec::new();
+        for (insert, prefix, value) in ops {
+            let position = model.iter().position(|(p, _)| *p == prefix);
+            if insert {
+                let expected = position.map(|i| core::mem::replace(&mut model[i].1, value));
+                if expected.is_none() {
+                    model.push((prefix, value));
+                }
+                prop_assert_eq!(map.insert(prefix, value), expected);
+            } else {
+                let expected = position.map(|i| model.swap_remove(i).1);
+                prop_assert_eq!(map.remove(prefix), expected);
+            }
+            check(&map);
+        }
+        model.sort_by(|(p, _), (q, _)| p.cmp_canonical(q));
+        prop_assert_eq!(map.len(), model.len());
+        prop_assert_eq!(map.iter().map(|(p, v)| (p, *v)).collect::<Vec<_>>(), model.clone());
+        for (prefix, value) in &model {
+            prop_assert_eq!(map.get(*prefix), Some(value));
+        }
+        prop_assert_eq!(map.into_iter().collect::<Vec<_>>(), model);
+    }
+}
+
+proptest! {
+    #[test]
+    fn lookups_match_model(
+        entries in vec((prefix(), any::<u16>()), 0..64),
+        host in any::<u32>(),
+        cover in prefix(),
+    ) {
+        let map: Map<Ipv4, u16> = entries.iter().copied().collect();
+        let mut model: Vec<(Prefix<Ipv4>, u16)> = Vec::new();
+        entries.into_iter().rev().for_each(|(prefix, value)| {
+            if model.iter().all(|(p, _)| *p != prefix) {
+                model.push((prefix, value));
+            }
+        });
+        model.sort_by(|(p, _), (q, _)| p.cmp_canonical(q));
+
+        let addr = Address::new(0x0a00_0000 | (host & 0x00ff_ffff));
+        let matches: Vec<_> = model
+            .iter()
+            .copied()
+            .filter(|(p, _)| Prefix::new(addr, p.length()) == *p)
+            .collect();
+        prop_assert_eq!(map.longest_match(addr).map(|(p, v)| (p, *v)), matches.last().copied());
+        prop_assert_eq!(
+            map.matches(addr).map(|(p, v)| (p, *v)).collect::<Vec<_>>(),
+            matches
+        );
+
+        let covered: Vec<_> = model
+            .iter()
+            .copied()
+            .filter(|(p, _)| {
+                matches!(
+                    cover.branch_point(p),
+                    BranchPoint::Equal | BranchPoint::Contains
+                )
+            })
+            .collect();
+        prop_assert_eq!(
+            map.covered_by(cover).map(|(p, v)| (p, *v)).collect::<Vec<_>>(),
+            covered
+        );
+    }
+}
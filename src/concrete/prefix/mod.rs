@@ -1,15 +1,16 @@
 #![allow(clippy::module_name_repetitions)]
 
-use core::cmp::min;
+use core::borrow::Borrow;
+use core::cmp::{min, Ordering};
 use core::fmt;
 use core::str::FromStr;
 
-use super::{common_length, impl_try_from_any, Address, Bitmask, Hostmask, Interface, Netmask};
+use super::{common_length, fnv1a, impl_try_from_any, Address, Hostmask, Interface, Netmask};
 use crate::{
     any,
-    error::Error,
+    error::{err, Error, Kind},
     fmt::AddressDisplay,
-    traits::{self, primitive::Address as _, Afi, PrefixLength as _},
+    traits::{self, primitive::Address as _, Afi, Prefix as _, PrefixLength as _},
     Ipv4, Ipv6,
 };
 
@@ -17,11 +18,13 @@ mod len;
 pub use self::len::PrefixLength;
 
 mod ord;
-pub use self::ord::PrefixOrdering;
+pub use self::ord::{BranchPoint, PrefixOrdering};
 
 mod range;
 pub use self::range::Range;
 
+#[cfg(feature = "std")]
+pub mod map;
 #[cfg(feature = "std")]
 // TODO: remove `unknown_lints` dance when `clippy::unnecessary_box_returns` is stabilised
 #[allow(unknown_lints)]
@@ -29,6 +32,11 @@ pub use self::range::Range;
 #[warn(unknown_lints)]
 mod set;
 #[cfg(feature = "std")]
+pub use self::map::Map;
+
+#[cfg(feature = "std")]
+pub(crate) use self::set::ListItems;
+#[cfg(feature = "std")]
 pub use self::set::Set;
 
 mod subprefixes;
@@ -39,6 +47,41 @@ mod private {
     use super::*;
 
     /// An IP prefix, consisting of a network address and prefix length.
+    ///
+    /// # Ordering
+    ///
+    /// The [`PartialOrd`] implementation orders prefixes by *containment*,
+    /// not numerically: `p < q` means that `p` is a strict subprefix of `q`.
+    /// Prefixes that do not overlap are not comparable, so [`Prefix<A>`]
+    /// does not implement [`Ord`], and `partial_cmp` must not be used to
+    /// sort prefixes.
+    ///
+    /// Use [`Prefix::cmp_canonical()`] to sort prefixes instead. This orders
+    /// prefixes by network address, and then by prefix length (the
+    /// conventional "CIDR order"), and is the order used by
+    /// [`sort_prefixes()`][super::sort_prefixes].
+    ///
+    /// ``` rust
+    /// use std::cmp::Ordering;
+    ///
+    /// use ip::{Ipv4, Prefix};
+    ///
+    /// let p = "10.0.0.0/8".parse::<Prefix<Ipv4>>()?;
+    /// let q = "10.1.0.0/16".parse::<Prefix<Ipv4>>()?;
+    /// let r = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    ///
+    /// assert!(q < p);
+    /// assert_eq!(p.partial_cmp(&r), None);
+    /// assert_eq!(p.cmp_canonical(&q), Ordering::Less);
+    /// assert_eq!(p.cmp_canonical(&r), Ordering::Less);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    ///
+    /// # Hashing
+    ///
+    /// As for [`Address<A>`], the [`Hash`] implementation is not guaranteed
+    /// to be stable across versions of this crate. Use
+    /// [`Prefix::fingerprint()`] where a stable hash is required.
     #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
     pub struct Prefix<A: Afi> {
         prefix: Address<A>,
@@ -55,6 +98,8 @@ mod private {
         /// Construct a new [`Prefix<A>`] from an address and prefix length.
         ///
         /// The host bits of `prefix` will be automatically set to zero.
+        ///
+        /// See [`Prefix::new_checked()`] for a constructor that fails instead.
         pub fn new(mut prefix: Address<A>, length: PrefixLength<A>) -> Self {
             prefix &= Netmask::from(length);
             Self { prefix, length }
@@ -75,19 +120,506 @@ mod private {
 pub use self::private::Prefix;
 
 impl<A: Afi> Prefix<A> {
-    fn common_with(&self, other: &Self) -> Self {
+    /// Construct a new [`Prefix<A>`] from an address and prefix length,
+    /// without modifying `addr`.
+    ///
+    /// This is equivalent to the [`TryFrom<(Address<A>, PrefixLength<A>)>`]
+    /// implementation.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of kind
+    /// [`NonZeroHostBits`][crate::error::Kind::NonZeroHostBits] is returned
+    /// if any of the host bits of `addr` are set.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{error::Kind, Address, Ipv4, Prefix, PrefixLength};
+    ///
+    /// let length = PrefixLength::<Ipv4>::from_primitive(24)?;
+    ///
+    /// assert_eq!(
+    ///     Prefix::<Ipv4>::new_checked("192.0.2.0".parse::<Address<Ipv4>>()?, length)?,
+    ///     "192.0.2.0/24".parse::<Prefix<Ipv4>>()?,
+    /// );
+    /// assert_eq!(
+    ///     Prefix::<Ipv4>::new_checked("192.0.2.5".parse::<Address<Ipv4>>()?, length)
+    ///         .unwrap_err()
+    ///         .kind(),
+    ///     Kind::NonZeroHostBits,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn new_checked(addr: Address<A>, length: PrefixLength<A>) -> Result<Self, Error> {
+        let prefix = Self::new(addr, length);
+        if prefix.prefix() == addr {
+            Ok(prefix)
+        } else {
+            Err(err!(Kind::NonZeroHostBits))
+        }
+    }
+
+    /// Returns the longest [`Prefix<A>`] that contains both `self` and
+    /// `other`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.0/25"
+    ///         .parse::<Prefix<Ipv4>>()?
+    ///         .common(&"192.0.3.0/24".parse()?),
+    ///     "192.0.2.0/23".parse::<Prefix<Ipv4>>()?,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     "2001:db8::/32"
+    ///         .parse::<Prefix<Ipv6>>()?
+    ///         .common(&"2001:db8:f00::/48".parse()?),
+    ///     "2001:db8::/32".parse::<Prefix<Ipv6>>()?,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn common(&self, other: &Self) -> Self {
         let min_length = min(self.length(), other.length());
         let common_length = common_length(self.prefix(), other.prefix());
         let length = min(min_length, common_length);
         Self::new(self.prefix(), length)
     }
 
-    fn map_addr<F>(&self, f: F) -> Option<Self>
+    /// Returns [`true`] if `self` and `other` have any addresses in common,
+    /// i.e. if either prefix contains the other.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Prefix};
+    ///
+    /// let p = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    ///
+    /// assert!(p.overlaps(&"192.0.0.0/16".parse()?));
+    /// assert!(p.overlaps(&"192.0.2.128/25".parse()?));
+    /// assert!(!p.overlaps(&"192.0.3.0/24".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.contains(other) || other.contains(self)
+    }
+
+    /// Returns the [`Prefix<A>`] containing exactly those addresses contained
+    /// in both `self` and `other`, or [`None`] if the prefixes are disjoint.
+    ///
+    /// Since any two overlapping prefixes are nested, this is the more
+    /// specific of `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv6, Prefix};
+    ///
+    /// let p = "2001:db8::/32".parse::<Prefix<Ipv6>>()?;
+    /// let q = "2001:db8:f00::/48".parse::<Prefix<Ipv6>>()?;
+    ///
+    /// assert_eq!(p.intersection(&q), Some(q));
+    /// assert_eq!(q.intersection(&p), Some(q));
+    /// assert_eq!(p.intersection(&"2001:db9::/32".parse()?), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if self.contains(other) {
+            Some(*other)
+        } else if other.contains(self) {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    /// Returns [`true`] if `self` contains exactly one address, i.e. if its
+    /// length is [`PrefixLength::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert!("192.0.2.1/32".parse::<Prefix<Ipv4>>()?.is_host());
+    /// assert!(!"192.0.2.0/24".parse::<Prefix<Ipv4>>()?.is_host());
+    /// assert!("2001:db8::1/128".parse::<Prefix<Ipv6>>()?.is_host());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_host(&self) -> bool {
+        self.length() == PrefixLength::MAX
+    }
+
+    /// Returns [`true`] if `self` is the "default" prefix containing all
+    /// addresses of the address family, i.e. if its length is
+    /// [`PrefixLength::MIN`].
+    ///
+    /// See also [`Prefix::DEFAULT`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert!("0.0.0.0/0".parse::<Prefix<Ipv4>>()?.is_default());
+    /// assert!(!"0.0.0.0/1".parse::<Prefix<Ipv4>>()?.is_default());
+    /// assert!(Prefix::<Ipv6>::DEFAULT.is_default());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.length() == PrefixLength::MIN
+    }
+
+    /// Returns an iterator over the equal-sized subprefixes of `self`
+    /// obtained by splitting it into at least `count` subnets.
+    ///
+    /// The number of subnets is `count` rounded up to the next power of two,
+    /// and the length of the resulting subprefixes is chosen accordingly. A
+    /// `count` of zero or one yields `self` alone.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of kind [`PrefixLength`][Kind::PrefixLength] is returned
+    /// if `self` does not have enough host bits to be split into `count`
+    /// subnets.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Prefix};
+    ///
+    /// let prefix = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    ///
+    /// assert_eq!(
+    ///     prefix.subnets(3)?.map(|p| p.to_string()).collect::<Vec<_>>(),
+    ///     ["192.0.2.0/26", "192.0.2.64/26", "192.0.2.128/26", "192.0.2.192/26"],
+    /// );
+    /// assert!(prefix.subnets(257).is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn subnets(&self, count: usize) -> Result<Subprefixes<A>, Error> {
+        let bits = count
+            .checked_next_power_of_two()
+            .map(usize::trailing_zeros)
+            .and_then(|bits| u8::try_from(bits).ok())
+            .ok_or_else(|| err!(Kind::PrefixLength))?;
+        self.subprefixes(self.length().checked_add(bits)?)
+    }
+
+    /// Compare `self` and `other` in canonical "CIDR order": by network
+    /// address, and then by prefix length.
+    ///
+    /// A covering prefix is ordered *before* its subprefixes. This is
+    /// consistent with [`Eq`], and is the order used by
+    /// [`sort_prefixes()`][super::sort_prefixes].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Prefix};
+    ///
+    /// let mut prefixes = ["10.0.0.0/16", "192.0.2.0/24", "10.0.0.0/8", "10.255.0.0/16"]
+    ///     .into_iter()
+    ///     .map(str::parse::<Prefix<Ipv4>>)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// prefixes.sort_by(Prefix::<Ipv4>::cmp_canonical);
+    ///
+    /// assert_eq!(
+    ///     prefixes.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+    ///     ["10.0.0.0/8", "10.0.0.0/16", "10.255.0.0/16", "192.0.2.0/24"],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn cmp_canonical(&self, other: &Self) -> Ordering {
+        self.prefix()
+            .cmp(&other.prefix())
+            .then_with(|| self.length().cmp(&other.length()))
+    }
+
+    /// Returns the [`Prefix<A>`] of the same length as `self`, located `n`
+    /// sibling positions after (or, for negative `n`, before) `self`.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of kind [`Overflow`][crate::error::Kind::Overflow] is
+    /// returned if the resulting prefix would fall outside of the address
+    /// family.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Prefix};
+    ///
+    /// let p = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+    ///
+    /// assert_eq!(p.offset(1)?, "192.0.3.0/24".parse::<Prefix<Ipv4>>()?);
+    /// assert_eq!(p.offset(-2)?, "192.0.0.0/24".parse::<Prefix<Ipv4>>()?);
+    /// assert!("255.255.255.0/24".parse::<Prefix<Ipv4>>()?.offset(1).is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn offset(&self, n: i128) -> Result<Self, Error> {
+        let host_bits = u32::from(self.host_bits());
+        let length = u32::from(self.network_bits());
+        let index = Self::bits_of(self.prefix())
+            .checked_shr(host_bits)
+            .unwrap_or_default()
+            .checked_add_signed(n)
+            .filter(|index| index.checked_shr(length).unwrap_or_default() == 0)
+            .ok_or_else(|| err!(Kind::Overflow))?;
+        let network = index
+            .checked_shl(host_bits)
+            .unwrap_or_default()
+            .try_into()
+            .map_err(|_| err!(Kind::Overflow))?;
+        Ok(Self::new(Address::new(network), self.length()))
+    }
+
+    /// Returns the index of `self` amongst the subprefixes of `parent` of the
+    /// same length as `self`.
+    ///
+    /// This is the inverse of stepping through
+    /// [`parent.subprefixes(self.length())`][traits::Prefix::subprefixes].
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of kind
+    /// [`PrefixNotContained`][crate::error::Kind::PrefixNotContained] is
+    /// returned if `self` is not contained by `parent`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv6, Prefix};
+    ///
+    /// let parent = "2001:db8::/32".parse::<Prefix<Ipv6>>()?;
+    ///
+    /// assert_eq!("2001:db8::/48".parse::<Prefix<Ipv6>>()?.index_within(&parent)?, 0);
+    /// assert_eq!("2001:db8:f00::/48".parse::<Prefix<Ipv6>>()?.index_within(&parent)?, 0xf00);
+    /// assert!("2001:db9::/48".parse::<Prefix<Ipv6>>()?.index_within(&parent).is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn index_within(&self, parent: &Self) -> Result<u128, Error> {
+        if parent.contains(self) {
+            let offset = Self::bits_of(self.prefix()) - Self::bits_of(parent.prefix());
+            Ok(offset
+                .checked_shr(self.host_bits().into())
+                .unwrap_or_default())
+        } else {
+            Err(err!(Kind::PrefixNotContained))
+        }
+    }
+
+    /// Convert `self` into the corresponding [`any::Prefix`].
+    ///
+    /// This is equivalent to [`any::Prefix::from()`][From::from], and is the inverse
+    /// of [`any::Prefix::into_concrete()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Any, Ipv6, Prefix};
+    ///
+    /// let prefix = "2001:db8::/32".parse::<Prefix<Ipv6>>()?;
+    ///
+    /// assert_eq!(prefix.into_any(), "2001:db8::/32".parse::<Prefix<Any>>()?);
+    /// assert_eq!(prefix.into_any().into_concrete(), Some(prefix));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn into_any(self) -> any::Prefix
     where
-        F: FnOnce(Address<A>) -> Option<Address<A>>,
+        any::Prefix: From<Self>,
     {
-        f(self.prefix()).map(|addr| Self::new(addr, self.length()))
+        self.into()
+    }
+
+    fn bits_of(addr: Address<A>) -> u128 {
+        addr.into_primitive().into()
+    }
+
+    /// Returns a fingerprint of `self`, suitable for persistent use.
+    ///
+    /// The fingerprint is the 64-bit [FNV-1a] hash of the big-endian octets
+    /// of the network address of `self`, followed by a single octet
+    /// containing the prefix length. Like
+    /// [`Address::fingerprint()`], it is guaranteed never to change.
+    ///
+    /// [FNV-1a]: http://www.isthe.com/chongo/tech/comp/fnv/index.html
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.0/24".parse::<Prefix<Ipv4>>()?.fingerprint(),
+    ///     0x7939_bdb7_2a8b_d9ad,
+    /// );
+    /// assert_eq!(
+    ///     "2001:db8::/32".parse::<Prefix<Ipv6>>()?.fingerprint(),
+    ///     0x99c8_a50b_3c0c_cf85,
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        let length: usize = self.length().into_primitive().into();
+        fnv1a(
+            self.prefix()
+                .octets()
+                .borrow()
+                .iter()
+                .chain([&(length as u8)]),
+        )
+    }
+
+    /// Returns the number of leading bits of `self` that identify the
+    /// network: that is, the prefix length of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!("192.0.2.0/24".parse::<Prefix<Ipv4>>()?.network_bits(), 24);
+    /// assert_eq!("2001:db8::/32".parse::<Prefix<Ipv6>>()?.network_bits(), 32);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn network_bits(&self) -> u8 {
+        let length: usize = self.length().into_primitive().into();
+        length as u8
+    }
+
+    /// Returns the number of trailing bits of `self` that identify a host
+    /// within the network: that is, the maximum prefix length for the
+    /// address family less the prefix length of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!("192.0.2.0/24".parse::<Prefix<Ipv4>>()?.host_bits(), 8);
+    /// assert_eq!("2001:db8::/32".parse::<Prefix<Ipv6>>()?.host_bits(), 96);
+    /// assert_eq!("192.0.2.1/32".parse::<Prefix<Ipv4>>()?.host_bits(), 0);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub fn host_bits(&self) -> u8 {
+        let max: usize = A::Primitive::MAX_LENGTH.into();
+        max as u8 - self.network_bits()
     }
+
+    /// Returns the total number of addresses covered by `self`: that is,
+    /// two raised to the power of [`host_bits()`][Self::host_bits].
+    ///
+    /// This counts every address in `self`, including the network and
+    /// broadcast addresses. It is therefore not the same as the number of
+    /// usable host addresses, which for IPv4 prefixes shorter than `/31`
+    /// conventionally excludes those two addresses.
+    ///
+    /// The count for `::/0`, which is one greater than [`u128::MAX`],
+    /// saturates at [`u128::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!("192.0.2.0/24".parse::<Prefix<Ipv4>>()?.num_addresses(), 256);
+    /// assert_eq!("192.0.2.1/32".parse::<Prefix<Ipv4>>()?.num_addresses(), 1);
+    /// assert_eq!("2001:db8::/64".parse::<Prefix<Ipv6>>()?.num_addresses(), 1 << 64);
+    /// assert_eq!("::/0".parse::<Prefix<Ipv6>>()?.num_addresses(), u128::MAX);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn num_addresses(&self) -> u128 {
+        1u128
+            .checked_shl(self.host_bits().into())
+            .unwrap_or(u128::MAX)
+    }
+}
+
+macro_rules! impl_const_accessors {
+    ( $( $afi:ident => $primitive:ty { $example:literal => $network:literal, $netmask:literal, $hostmask:literal, $broadcast:literal } ),* $(,)? ) => {
+        $(
+            /// `const` equivalents of the accessor methods of
+            /// [`traits::Prefix`], for use in `const` contexts.
+            ///
+            /// # Examples
+            ///
+            /// ``` rust
+            #[doc = concat!("use ip::{concrete::{Address, Hostmask, Netmask, Prefix}, ", stringify!($afi), "};")]
+            ///
+            #[doc = concat!("const DEFAULT: Prefix<", stringify!($afi), "> = Prefix::DEFAULT;")]
+            #[doc = concat!("const BROADCAST: Address<", stringify!($afi), "> = DEFAULT.broadcast_const();")]
+            ///
+            #[doc = concat!("let prefix = \"", $example, "\".parse::<Prefix<", stringify!($afi), ">>()?;")]
+            #[doc = concat!("assert_eq!(prefix.network_const(), \"", $network, "\".parse::<Address<", stringify!($afi), ">>()?);")]
+            #[doc = concat!("assert_eq!(prefix.netmask_const(), \"", $netmask, "\".parse::<Netmask<", stringify!($afi), ">>()?);")]
+            #[doc = concat!("assert_eq!(prefix.hostmask_const(), \"", $hostmask, "\".parse::<Hostmask<", stringify!($afi), ">>()?);")]
+            #[doc = concat!("assert_eq!(prefix.broadcast_const(), \"", $broadcast, "\".parse::<Address<", stringify!($afi), ">>()?);")]
+            #[doc = concat!("assert_eq!(BROADCAST, Address::<", stringify!($afi), ">::ONES);")]
+            /// # Ok::<(), ip::Error>(())
+            /// ```
+            impl Prefix<$afi> {
+                /// Returns the network address of the prefix.
+                ///
+                /// See [`traits::Prefix::network()`].
+                #[must_use]
+                pub const fn network_const(&self) -> Address<$afi> {
+                    self.prefix()
+                }
+
+                /// Returns the netmask of the prefix.
+                ///
+                /// See [`traits::Prefix::netmask()`].
+                #[must_use]
+                pub const fn netmask_const(&self) -> Netmask<$afi> {
+                    Netmask::new(!self.hostmask_const().into_primitive())
+                }
+
+                /// Returns the hostmask of the prefix.
+                ///
+                /// See [`traits::Prefix::hostmask()`].
+                #[must_use]
+                pub const fn hostmask_const(&self) -> Hostmask<$afi> {
+                    Hostmask::new(match <$primitive>::MAX.checked_shr(self.length().get() as u32) {
+                        Some(bits) => bits,
+                        None => 0,
+                    })
+                }
+
+                /// Returns the broadcast address of the prefix.
+                ///
+                /// See [`traits::Prefix::broadcast()`].
+                #[must_use]
+                pub const fn broadcast_const(&self) -> Address<$afi> {
+                    Address::new(self.prefix().into_primitive() | self.hostmask_const().into_primitive())
+                }
+            }
+        )*
+    };
+}
+
+impl_const_accessors! {
+    Ipv4 => u32 { "192.0.2.0/26" => "192.0.2.0", "255.255.255.192", "0.0.0.63", "192.0.2.63" },
+    Ipv6 => u128 { "2001:db8::/48" => "2001:db8::", "ffff:ffff:ffff::", "::ffff:ffff:ffff:ffff:ffff", "2001:db8:0:ffff:ffff:ffff:ffff:ffff" },
 }
 
 impl<A: Afi> traits::Prefix for Prefix<A> {
@@ -139,6 +671,22 @@ impl<A: Afi> traits::Prefix for Prefix<A> {
     fn new_prefix_length(&self, length: u8) -> Result<Self::Length, Error> {
         (length as usize).try_into()
     }
+
+    #[cfg(feature = "std")]
+    fn to_reverse_dns(&self) -> Result<std::string::String, Error> {
+        use crate::error::{err, Kind};
+
+        let bits: usize = self.length().into_primitive().into();
+        let label_bits = A::as_afi().reverse_dns_label_bits();
+        if bits.is_multiple_of(label_bits) {
+            Ok(self.prefix().reverse_dns_name(bits))
+        } else {
+            Err(err!(
+                Kind::PrefixLength,
+                "prefix-length does not fall on a reverse DNS label boundary"
+            ))
+        }
+    }
 }
 
 impl<A: Afi> From<Address<A>> for Prefix<A> {
@@ -153,6 +701,14 @@ impl<A: Afi> From<Interface<A>> for Prefix<A> {
     }
 }
 
+impl<A: Afi> TryFrom<(Address<A>, PrefixLength<A>)> for Prefix<A> {
+    type Error = Error;
+
+    fn try_from((addr, length): (Address<A>, PrefixLength<A>)) -> Result<Self, Self::Error> {
+        Self::new_checked(addr, length)
+    }
+}
+
 impl<A: Afi> FromStr for Prefix<A> {
     type Err = Error;
 
@@ -202,6 +758,42 @@ impl From<ipnet::Ipv6Net> for Prefix<Ipv6> {
     }
 }
 
+/// Sort a slice of [`Prefix<A>`] into "CIDR order": by network address,
+/// and then by prefix length.
+///
+/// The [`PartialOrd`] implementation for [`Prefix<A>`] orders prefixes by
+/// containment, so that unrelated prefixes are incomparable, and is not
+/// suitable for sorting. This function sorts by a total order instead, in
+/// which a covering prefix always precedes its subprefixes, and equal
+/// prefixes are adjacent, so that the result is ready for
+/// [`dedup()`][std::vec::Vec::dedup].
+///
+/// See [`any::SortablePrefix`] for the equivalent ordering of prefixes of
+/// either address family.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::sort_prefixes, Ipv4, Prefix};
+///
+/// let mut prefixes = ["192.0.2.0/25", "10.0.0.0/8", "192.0.2.0/24", "10.0.0.0/8"]
+///     .into_iter()
+///     .map(str::parse::<Prefix<Ipv4>>)
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// sort_prefixes(&mut prefixes);
+/// prefixes.dedup();
+///
+/// assert_eq!(
+///     prefixes.iter().map(|p| p.to_string()).collect::<Vec<_>>(),
+///     ["10.0.0.0/8", "192.0.2.0/24", "192.0.2.0/25"],
+/// );
+/// # Ok::<(), ip::Error>(())
+/// ```
+pub fn sort_prefixes<A: Afi>(prefixes: &mut [Prefix<A>]) {
+    prefixes.sort_unstable_by(Prefix::cmp_canonical);
+}
+
 #[cfg(any(test, feature = "arbitrary"))]
 use proptest::{
     arbitrary::{any_with, Arbitrary, ParamsFor, StrategyFor},
@@ -228,3 +820,379 @@ where
             .boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proptest::{arbitrary::any, collection::vec, proptest};
+
+    use super::*;
+    use crate::error::TestResult;
+
+    fn prefix<A: Afi>(s: &str) -> Prefix<A> {
+        s.parse().unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn fingerprint_distinguishes_length(p in any::<Prefix<Ipv6>>()) {
+            if let Some(supernet) = p.supernet() {
+                if supernet.network() == p.network() {
+                    assert_ne!(supernet.fingerprint(), p.fingerprint());
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv4_bits_sum_to_width(p in any::<Prefix<Ipv4>>()) {
+            assert_eq!(p.network_bits() + p.host_bits(), 32);
+            assert_eq!(p.hostmask().into_primitive().count_ones(), u32::from(p.host_bits()));
+        }
+
+        #[test]
+        fn ipv4_num_addresses_matches_range(p in any::<Prefix<Ipv4>>()) {
+            let size = u128::from(p.broadcast().into_primitive() - p.network().into_primitive()) + 1;
+            assert_eq!(p.num_addresses(), size);
+        }
+
+        #[test]
+        fn ipv6_bits_sum_to_width(p in any::<Prefix<Ipv6>>()) {
+            assert_eq!(p.network_bits() + p.host_bits(), 128);
+            assert_eq!(p.hostmask().into_primitive().count_ones(), u32::from(p.host_bits()));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn sort_prefixes_is_total(mut prefixes in vec(any::<Prefix<Ipv6>>(), 0..32)) {
+            let mut expected = prefixes.clone();
+            sort_prefixes(&mut prefixes);
+            assert!(prefixes.windows(2).all(|pair| {
+                (pair[0].network(), pair[0].length()) <= (pair[1].network(), pair[1].length())
+            }));
+            assert!(prefixes.windows(2).all(|pair| !pair[1].contains(&pair[0]) || pair[0] == pair[1]));
+            expected.sort_by_key(|prefix| any::SortablePrefix(prefix.into_any()));
+            assert_eq!(prefixes, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv4_const_accessors(p in any::<Prefix<Ipv4>>()) {
+            assert_eq!(p.network_const(), p.network());
+            assert_eq!(p.netmask_const(), p.netmask());
+            assert_eq!(p.hostmask_const(), p.hostmask());
+            assert_eq!(p.broadcast_const(), p.broadcast());
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ipv6_const_accessors(p in any::<Prefix<Ipv6>>()) {
+            assert_eq!(p.network_const(), p.network());
+            assert_eq!(p.netmask_const(), p.netmask());
+            assert_eq!(p.hostmask_const(), p.hostmask());
+            assert_eq!(p.broadcast_const(), p.broadcast());
+        }
+    }
+
+    #[test]
+    fn partial_cmp_is_containment() {
+        let p = prefix::<Ipv4>("10.0.0.0/8");
+        let q = prefix::<Ipv4>("10.0.0.0/16");
+        let r = prefix::<Ipv4>("192.0.2.0/24");
+        assert!(q < p);
+        assert!(p > q);
+        assert_eq!(p.partial_cmp(&p), Some(Ordering::Equal));
+        assert_eq!(p.partial_cmp(&r), None);
+        assert_eq!(r.partial_cmp(&q), None);
+    }
+
+    #[test]
+    fn cmp_canonical_orders_covering_prefix_first() {
+        let p = prefix::<Ipv4>("10.0.0.0/8");
+        let q = prefix::<Ipv4>("10.0.0.0/16");
+        let r = prefix::<Ipv4>("10.255.255.0/24");
+        let s = prefix::<Ipv4>("192.0.2.0/24");
+        assert_eq!(p.cmp_canonical(&q), Ordering::Less);
+        assert_eq!(q.cmp_canonical(&r), Ordering::Less);
+        assert_eq!(r.cmp_canonical(&s), Ordering::Less);
+        assert_eq!(p.cmp_canonical(&p), Ordering::Equal);
+    }
+
+    #[test]
+    fn host_and_default() {
+        assert!(Prefix::<Ipv4>::from(Address::LOCALHOST).is_host());
+        assert!(!Prefix::<Ipv4>::from(Address::LOCALHOST).is_default());
+        assert!(Prefix::<Ipv6>::DEFAULT.is_default());
+        assert!(!Prefix::<Ipv6>::DEFAULT.is_host());
+        assert!(!prefix::<Ipv6>("2001:db8::/127").is_host());
+    }
+
+    #[test]
+    fn new_checked_accepts_network() -> TestResult {
+        let addr = "2001:db8::".parse::<Address<Ipv6>>()?;
+        let length = PrefixLength::from_primitive(32)?;
+        assert_eq!(
+            Prefix::new_checked(addr, length)?,
+            Prefix::new(addr, length)
+        );
+        assert_eq!(Prefix::try_from((addr, length))?, Prefix::new(addr, length));
+        Ok(())
+    }
+
+    #[test]
+    fn new_checked_rejects_host_bits() -> TestResult {
+        let addr = "192.0.2.5".parse::<Address<Ipv4>>()?;
+        let length = PrefixLength::from_primitive(24)?;
+        assert_eq!(
+            Prefix::new_checked(addr, length).unwrap_err().kind(),
+            Kind::NonZeroHostBits
+        );
+        assert!(Prefix::try_from((addr, length)).is_err());
+        assert!(Prefix::new_checked(addr, PrefixLength::MAX).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn subnets_rounds_up_to_power_of_two() -> TestResult {
+        let p = "2001:db8::/32".parse::<Prefix<Ipv6>>()?;
+        assert!(p.subnets(0)?.eq([p]));
+        assert!(p.subnets(1)?.eq([p]));
+        assert_eq!(p.subnets(2)?.count(), 2);
+        assert_eq!(p.subnets(5)?.next(), Some("2001:db8::/35".parse()?));
+        assert_eq!(p.subnets(1 << 16)?.count(), 1 << 16);
+        Ok(())
+    }
+
+    #[test]
+    fn subnets_exceeding_host_bits() -> TestResult {
+        let p = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+        assert_eq!(p.subnets(256)?.count(), 256);
+        assert_eq!(p.subnets(257).unwrap_err().kind(), Kind::PrefixLength);
+        assert_eq!(
+            p.subnets(usize::MAX).unwrap_err().kind(),
+            Kind::PrefixLength
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ipv4_disjoint_do_not_overlap() {
+        let p = prefix::<Ipv4>("192.0.2.0/24");
+        let q = prefix::<Ipv4>("198.51.100.0/24");
+        assert!(!p.overlaps(&q));
+        assert!(!q.overlaps(&p));
+        assert_eq!(p.intersection(&q), None);
+        assert_eq!(q.intersection(&p), None);
+    }
+
+    #[test]
+    fn ipv4_adjacent_do_not_overlap() {
+        let p = prefix::<Ipv4>("192.0.2.0/25");
+        let q = prefix::<Ipv4>("192.0.2.128/25");
+        assert!(!p.overlaps(&q));
+        assert_eq!(p.intersection(&q), None);
+    }
+
+    #[test]
+    fn ipv4_nested_overlap() {
+        let p = prefix::<Ipv4>("192.0.0.0/16");
+        let q = prefix::<Ipv4>("192.0.2.0/24");
+        assert!(p.overlaps(&q));
+        assert!(q.overlaps(&p));
+        assert_eq!(p.intersection(&q), Some(q));
+        assert_eq!(q.intersection(&p), Some(q));
+    }
+
+    #[test]
+    fn ipv6_equal_overlap() {
+        let p = prefix::<Ipv6>("2001:db8::/32");
+        assert!(p.overlaps(&p));
+        assert_eq!(p.intersection(&p), Some(p));
+    }
+
+    #[test]
+    fn ipv4_reverse_dns_zones() {
+        assert_eq!(
+            prefix::<Ipv4>("10.0.0.0/8").to_reverse_dns().unwrap(),
+            "10.in-addr.arpa."
+        );
+        assert_eq!(
+            prefix::<Ipv4>("0.0.0.0/0").to_reverse_dns().unwrap(),
+            "in-addr.arpa."
+        );
+        assert_eq!(
+            prefix::<Ipv4>("192.0.2.1/32").to_reverse_dns().unwrap(),
+            "1.2.0.192.in-addr.arpa."
+        );
+    }
+
+    #[test]
+    fn ipv4_reverse_dns_unaligned_length() {
+        assert_eq!(
+            prefix::<Ipv4>("10.0.0.0/12")
+                .to_reverse_dns()
+                .unwrap_err()
+                .kind(),
+            Kind::PrefixLength
+        );
+    }
+
+    #[test]
+    fn ipv6_reverse_dns_zones() {
+        assert_eq!(
+            prefix::<Ipv6>("2001:db8:f00::/36")
+                .to_reverse_dns()
+                .unwrap(),
+            "0.8.b.d.0.1.0.0.2.ip6.arpa."
+        );
+        assert!(prefix::<Ipv6>("2001:db8::/30").to_reverse_dns().is_err());
+    }
+
+    #[test]
+    fn ipv4_offset_negative() {
+        let p = prefix::<Ipv4>("192.0.2.0/24");
+        assert_eq!(p.offset(-1).unwrap(), prefix("192.0.1.0/24"));
+        assert_eq!(p.offset(-(192 << 16) - 2).unwrap(), prefix("0.0.0.0/24"));
+        assert_eq!(
+            p.offset(-(192 << 16) - 3).unwrap_err().kind(),
+            Kind::Overflow
+        );
+    }
+
+    #[test]
+    fn ipv4_offset_past_top() {
+        let p = prefix::<Ipv4>("255.255.254.0/24");
+        assert_eq!(p.offset(1).unwrap(), prefix("255.255.255.0/24"));
+        assert_eq!(p.offset(2).unwrap_err().kind(), Kind::Overflow);
+        assert_eq!(
+            Prefix::<Ipv4>::DEFAULT.offset(1).unwrap_err().kind(),
+            Kind::Overflow
+        );
+        assert_eq!(Prefix::<Ipv4>::DEFAULT.offset(0).unwrap(), Prefix::DEFAULT);
+    }
+
+    #[test]
+    fn ipv6_offset_past_top() {
+        let p = prefix::<Ipv6>("ffff:ffff:ffff:ffff:ffff:ffff:ffff:fffe/128");
+        assert_eq!(
+            p.offset(1).unwrap(),
+            prefix("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128")
+        );
+        assert_eq!(p.offset(2).unwrap_err().kind(), Kind::Overflow);
+        assert_eq!(
+            p.offset(i128::MIN).unwrap(),
+            prefix("7fff:ffff:ffff:ffff:ffff:ffff:ffff:fffe/128")
+        );
+        assert_eq!(
+            Prefix::<Ipv6>::DEFAULT.offset(-1).unwrap_err().kind(),
+            Kind::Overflow
+        );
+    }
+
+    #[test]
+    fn ipv6_index_within_not_contained() {
+        let parent = prefix::<Ipv6>("2001:db8::/48");
+        for p in ["2001:db8:1::/64", "2001:db8::/32"] {
+            assert_eq!(
+                prefix::<Ipv6>(p).index_within(&parent).unwrap_err().kind(),
+                Kind::PrefixNotContained
+            );
+        }
+        assert_eq!(parent.index_within(&parent).unwrap(), 0);
+        assert_eq!(
+            parent.index_within(&Prefix::DEFAULT).unwrap(),
+            0x2001_0db8_0000
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn cmp_canonical_is_total(p in any::<Prefix<Ipv6>>(), q in any::<Prefix<Ipv6>>()) {
+            let ord = p.cmp_canonical(&q);
+            assert_eq!(q.cmp_canonical(&p), ord.reverse());
+            assert_eq!(ord == Ordering::Equal, p == q);
+            assert_eq!(ord, (p.network(), p.length()).cmp(&(q.network(), q.length())));
+            if p.partial_cmp(&q) == Some(Ordering::Greater) {
+                assert_eq!(ord, Ordering::Less);
+            }
+        }
+
+        #[test]
+        fn new_checked_agrees_with_new(addr in any::<Address<Ipv6>>(), length in any::<PrefixLength<Ipv6>>()) {
+            let prefix = Prefix::new(addr, length);
+            assert_eq!(Prefix::new_checked(addr, length).ok(), (prefix.prefix() == addr).then_some(prefix));
+            assert_eq!(Prefix::new_checked(prefix.prefix(), length).ok(), Some(prefix));
+        }
+
+        #[test]
+        fn ipv4_index_within_round_trip(parent in any::<Prefix<Ipv4>>(), extra in 0..=8u8, i in any::<u8>()) {
+            assert_index_within_round_trip(&parent, extra, i.into());
+        }
+
+        #[test]
+        fn ipv6_index_within_round_trip(parent in any::<Prefix<Ipv6>>(), extra in 0..=8u8, i in any::<u8>()) {
+            assert_index_within_round_trip(&parent, extra, i.into());
+        }
+
+        #[test]
+        fn ipv4_offset_inverse(p in any::<Prefix<Ipv4>>(), n in any::<i32>()) {
+            assert_offset_inverse(&p, n.into());
+        }
+
+        #[test]
+        fn ipv6_offset_inverse(p in any::<Prefix<Ipv6>>(), n in any::<i128>()) {
+            assert_offset_inverse(&p, n);
+        }
+
+        #[test]
+        fn ipv4_any_round_trip(p in any::<Prefix<Ipv4>>()) {
+            assert_eq!(p.into_any().into_concrete(), Some(p));
+            assert_eq!(p.into_any().into_concrete::<Ipv6>(), None);
+            assert_eq!(p.prefix().into_any().into_concrete(), Some(p.prefix()));
+            assert_eq!(p.length().into_any().into_concrete(), Some(p.length()));
+        }
+
+        #[test]
+        fn ipv6_any_round_trip(p in any::<Prefix<Ipv6>>()) {
+            assert_eq!(p.into_any().into_concrete(), Some(p));
+            assert_eq!(p.into_any().into_concrete::<Ipv4>(), None);
+            assert_eq!(p.prefix().into_any().into_concrete(), Some(p.prefix()));
+            assert_eq!(p.length().into_any().into_concrete(), Some(p.length()));
+        }
+    }
+
+    fn assert_index_within_round_trip<A: Afi>(parent: &Prefix<A>, extra: u8, i: u128) {
+        let len = parent.length().into_primitive().into() + usize::from(extra);
+        let Ok(len) = PrefixLength::<A>::try_from(len) else {
+            return;
+        };
+        let nth = parent
+            .subprefixes(len)
+            .unwrap()
+            .nth(usize::try_from(i).unwrap());
+        let count = 1u128 << extra;
+        assert_eq!(nth.is_some(), i < count);
+        if let Some(p) = nth {
+            assert_eq!(p.index_within(parent).unwrap(), i);
+            let first = Prefix::new(parent.prefix(), len);
+            assert_eq!(first.offset(i.try_into().unwrap()).unwrap(), p);
+        }
+    }
+
+    fn assert_offset_inverse<A: Afi>(p: &Prefix<A>, n: i128) {
+        if let (Ok(q), Some(m)) = (p.offset(n), n.checked_neg()) {
+            assert_eq!(q.length(), p.length());
+            assert_eq!(q.offset(m).unwrap(), *p);
+        }
+    }
+
+    #[test]
+    fn ipv6_default_overlaps_everything() {
+        let p = prefix::<Ipv6>("2001:db8::1/128");
+        assert!(Prefix::DEFAULT.overlaps(&p));
+        assert_eq!(Prefix::DEFAULT.intersection(&p), Some(p));
+    }
+}
@@ -1,6 +1,6 @@
 use core::cmp::Ordering::{self, Equal, Greater, Less};
 
-use super::{Address, Prefix};
+use super::{Address, Prefix, PrefixLength};
 use crate::traits::Afi;
 
 /// Ordering relationship between a pair of [`Prefix<A>`] `P` and `Q`.
@@ -16,11 +16,69 @@ pub enum PrefixOrdering<A: Afi> {
     Divergent(Prefix<A>),
 }
 
+/// The point at which a pair of [`Prefix<A>`] `P` and `Q` branch from each
+/// other in a binary trie.
+///
+/// See [`Prefix::branch_point()`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum BranchPoint<A: Afi> {
+    /// `Q` is equal to `P`.
+    Equal,
+    /// `P` contains `Q`, i.e. `Q` is a strict subprefix of `P`.
+    Contains,
+    /// `P` is contained by `Q`, i.e. `Q` is a strict superprefix of `P`.
+    ContainedBy,
+    /// Neither `P` nor `Q` contains the other.
+    Diverges {
+        /// The length of the longest common prefix of `P` and `Q`,
+        /// equivalently the index of the first bit in which they differ.
+        at: PrefixLength<A>,
+    },
+}
+
 impl<A: Afi> Prefix<A> {
+    /// Find the point at which `self` and `other` branch from each other.
+    ///
+    /// This is similar to [`Self::compare()`], but avoids constructing the
+    /// common prefix of `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{concrete::BranchPoint, Ipv4, Prefix, PrefixLength};
+    ///
+    /// let p = "10.0.0.0/8".parse::<Prefix<Ipv4>>()?;
+    /// let q = "10.1.0.0/16".parse::<Prefix<Ipv4>>()?;
+    /// let r = "10.2.0.0/16".parse::<Prefix<Ipv4>>()?;
+    ///
+    /// assert_eq!(p.branch_point(&p), BranchPoint::Equal);
+    /// assert_eq!(p.branch_point(&q), BranchPoint::Contains);
+    /// assert_eq!(q.branch_point(&p), BranchPoint::ContainedBy);
+    /// assert_eq!(
+    ///     q.branch_point(&r),
+    ///     BranchPoint::Diverges {
+    ///         at: PrefixLength::<Ipv4>::from_primitive(14)?
+    ///     },
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn branch_point(&self, other: &Self) -> BranchPoint<A> {
+        let at = self.prefix().common_length(other.prefix());
+        if at >= self.length().min(other.length()) {
+            match self.length().cmp(&other.length()) {
+                Equal => BranchPoint::Equal,
+                Less => BranchPoint::Contains,
+                Greater => BranchPoint::ContainedBy,
+            }
+        } else {
+            BranchPoint::Diverges { at }
+        }
+    }
+
     /// Perform ordinal comparison with another [`Prefix<A>`], calculating the
     /// longest common prefix in the process.
     pub fn compare(&self, other: &Self) -> PrefixOrdering<A> {
-        let common = self.common_with(other);
+        let common = self.common(other);
         match (
             self.length().cmp(&common.length()),
             other.length().cmp(&common.length()),
@@ -61,8 +119,81 @@ impl<A: Afi> PartialOrd<Address<A>> for Prefix<A> {
 
 #[cfg(test)]
 mod tests {
+    use proptest::{arbitrary::any, proptest};
+
     use super::*;
-    use crate::{Ipv4, Ipv6};
+    use crate::{
+        concrete::common_length,
+        traits::{Prefix as _, PrefixLength as _},
+        Ipv4, Ipv6,
+    };
+
+    proptest! {
+        #[test]
+        fn ipv4_branch_point_consistent(p in any::<Prefix<Ipv4>>(), q in any::<Prefix<Ipv4>>()) {
+            assert_branch_point_consistent(&p, &q);
+        }
+
+        #[test]
+        fn ipv6_branch_point_consistent(p in any::<Prefix<Ipv6>>(), q in any::<Prefix<Ipv6>>()) {
+            assert_branch_point_consistent(&p, &q);
+        }
+
+        #[test]
+        fn ipv4_branch_point_nested(p in any::<Prefix<Ipv4>>(), len in 0..=32u8) {
+            let len = PrefixLength::from_primitive(len).unwrap();
+            let q = Prefix::new(p.prefix(), len);
+            assert_branch_point_consistent(&p, &q);
+        }
+
+        #[test]
+        fn ipv6_branch_point_nested(p in any::<Prefix<Ipv6>>(), len in 0..=128u8) {
+            let len = PrefixLength::from_primitive(len).unwrap();
+            let q = Prefix::new(p.prefix(), len);
+            assert_branch_point_consistent(&p, &q);
+        }
+
+        #[test]
+        fn ipv4_common_is_longest_covering(p in any::<Prefix<Ipv4>>(), q in any::<Prefix<Ipv4>>()) {
+            assert_common_is_longest_covering(&p, &q);
+        }
+
+        #[test]
+        fn ipv6_common_is_longest_covering(p in any::<Prefix<Ipv6>>(), q in any::<Prefix<Ipv6>>()) {
+            assert_common_is_longest_covering(&p, &q);
+        }
+    }
+
+    fn assert_common_is_longest_covering<A: Afi>(p: &Prefix<A>, q: &Prefix<A>) {
+        let common = p.common(q);
+        assert_eq!(common, q.common(p));
+        assert!(common.contains(p) && common.contains(q));
+        if let Ok(len) = common.length().increment() {
+            let longer = Prefix::new(p.prefix(), len);
+            assert!(!(longer.contains(p) && longer.contains(q)));
+        }
+    }
+
+    fn assert_branch_point_consistent<A: Afi>(p: &Prefix<A>, q: &Prefix<A>) {
+        let common = common_length(p.prefix(), q.prefix());
+        match p.branch_point(q) {
+            BranchPoint::Equal => assert_eq!(p, q),
+            BranchPoint::Contains => {
+                assert!(p.contains(q) && p != q);
+                assert_eq!(q.branch_point(p), BranchPoint::ContainedBy);
+            }
+            BranchPoint::ContainedBy => {
+                assert!(q.contains(p) && p != q);
+                assert_eq!(q.branch_point(p), BranchPoint::Contains);
+            }
+            BranchPoint::Diverges { at } => {
+                assert!(!p.contains(q) && !q.contains(p));
+                assert_eq!(at, common);
+                assert!(at < p.length() && at < q.length());
+                assert_eq!(q.branch_point(p), BranchPoint::Diverges { at });
+            }
+        }
+    }
 
     mod ipv4 {
         use super::*;
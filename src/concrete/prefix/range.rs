@@ -1,5 +1,6 @@
 use core::cmp::{max, min, Ordering};
 use core::fmt;
+use core::iter::FusedIterator;
 use core::ops::RangeInclusive;
 use core::str::FromStr;
 
@@ -9,6 +10,8 @@ use proptest::{
     strategy::{BoxedStrategy, Just, Strategy},
 };
 
+#[cfg(feature = "std")]
+use super::Set;
 use super::{impl_try_from_any, Address, Ipv4, Ipv6, Prefix, PrefixLength, Subprefixes};
 #[cfg(any(test, feature = "arbitrary"))]
 use crate::traits::primitive;
@@ -76,6 +79,36 @@ mod private {
 
 pub use self::private::Range;
 
+impl<A: Afi> Range<A> {
+    /// Returns a [`PrefixSet<A>`][Set] containing every prefix in `self`
+    /// except `prefix`.
+    ///
+    /// Only `prefix` itself is excluded: any of its subprefixes that are in
+    /// `self` remain in the result. If `prefix` is not in `self`, the result
+    /// contains exactly the prefixes of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{traits::PrefixSet as _, Ipv4, Prefix, PrefixRange};
+    ///
+    /// let range = "192.0.2.0/24,24,26".parse::<PrefixRange<Ipv4>>()?;
+    /// let set = range.exclude("192.0.2.0/25".parse::<Prefix<Ipv4>>()?);
+    ///
+    /// assert_eq!(set.len(), 1 + 1 + 4);
+    /// assert!(!set.contains("192.0.2.0/25".parse()?));
+    /// assert!(set.contains("192.0.2.0/26".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn exclude(self, prefix: Prefix<A>) -> Set<A> {
+        let mut set = Set::from_iter([self]);
+        _ = set.remove(prefix);
+        set
+    }
+}
+
 impl<A: Afi> traits::PrefixRange for Range<A> {
     type Prefix = Prefix<A>;
     type Length = PrefixLength<A>;
@@ -92,6 +125,10 @@ impl<A: Afi> traits::PrefixRange for Range<A> {
         self.upper()
     }
 
+    fn contains_address(&self, addr: Address<A>) -> bool {
+        self.prefix().contains(&addr)
+    }
+
     fn with_intersection(self, len_range: RangeInclusive<Self::Length>) -> Option<Self> {
         let lower = max(self.lower(), *len_range.start());
         let upper = min(self.upper(), *len_range.end());
@@ -163,6 +200,33 @@ impl<A: Afi> PartialOrd for Range<A> {
     }
 }
 
+/// Iterate over the [`Prefix<A>`]s covered by a [`Range<A>`], in order of
+/// increasing prefix length.
+///
+/// The number of prefixes remaining is reported exactly, unless it exceeds
+/// [`usize::MAX`]. In that case the [`size_hint`][Iterator::size_hint] is
+/// `(usize::MAX, None)` and `checked_len()` returns [`None`]. For this reason
+/// [`ExactSizeIterator`] is only implemented for [`Ipv4`], and only on 64-bit
+/// targets.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{Ipv4, Ipv6, PrefixRange};
+///
+/// let mut prefixes = "192.0.2.0/24,25,26".parse::<PrefixRange<Ipv4>>()?.into_iter();
+/// assert_eq!(prefixes.len(), 2 + 4);
+/// _ = prefixes.next();
+/// assert_eq!(prefixes.len(), 5);
+///
+/// let prefixes = "2001:db8::/32,48,49".parse::<PrefixRange<Ipv6>>()?.into_iter();
+/// assert_eq!(prefixes.checked_len(), Some((1 << 16) + (1 << 17)));
+///
+/// let prefixes = "::/0,0,128".parse::<PrefixRange<Ipv6>>()?.into_iter();
+/// assert_eq!(prefixes.size_hint(), (usize::MAX, None));
+/// assert_eq!(prefixes.checked_len(), None);
+/// # Ok::<(), ip::Error>(())
+/// ```
 impl<A: Afi> IntoIterator for Range<A> {
     type Item = Prefix<A>;
     type IntoIter = IntoIter<A>;
@@ -209,8 +273,40 @@ impl<A: Afi> Iterator for IntoIter<A> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.checked_len()
+            .map_or((usize::MAX, None), |n| (n, Some(n)))
+    }
 }
 
+impl<A: Afi> IntoIter<A> {
+    /// Returns the number of prefixes remaining in the iterator, or [`None`]
+    /// if that number exceeds [`usize::MAX`].
+    #[must_use]
+    pub fn checked_len(&self) -> Option<usize> {
+        let Some(current_iter) = &self.current_iter else {
+            return Some(0);
+        };
+        let base: usize = self.base.length().into_primitive().into();
+        let current: usize = self.current_length.into_primitive().into();
+        let upper: usize = self.upper_length.into_primitive().into();
+        (current + 1..=upper).try_fold(current_iter.checked_len()?, |total, length| {
+            u32::try_from(length - base)
+                .ok()
+                .and_then(|shift| 1usize.checked_shl(shift))
+                .and_then(|count| total.checked_add(count))
+        })
+    }
+}
+
+// There are fewer than 2^33 IPv4 prefixes in any range, which always fits in
+// a 64-bit `usize`.
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for IntoIter<Ipv4> {}
+
+impl<A: Afi> FusedIterator for IntoIter<A> {}
+
 #[cfg(any(test, feature = "arbitrary"))]
 impl<A> Arbitrary for Range<A>
 where
@@ -246,3 +342,61 @@ where
             .boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn len_counts_every_length() {
+        let range: Range<Ipv4> = "192.0.2.0/24,24,26".parse().unwrap();
+        let mut prefixes = range.into_iter();
+        assert_eq!(prefixes.len(), 1 + 2 + 4);
+        assert_eq!(prefixes.nth(2), "192.0.2.128/25".parse().ok());
+        assert_eq!(prefixes.len(), 4);
+        assert_eq!(prefixes.by_ref().count(), 4);
+        assert_eq!(prefixes.len(), 0);
+        assert_eq!(prefixes.next(), None);
+        assert_eq!(prefixes.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn len_of_ipv4_default_range() {
+        let range: Range<Ipv4> = "0.0.0.0/0,0,32".parse().unwrap();
+        assert_eq!(range.into_iter().checked_len(), Some((1 << 33) - 1));
+    }
+
+    #[test]
+    fn checked_len_ipv6() {
+        let range: Range<Ipv6> = "2001:db8::/126,127,128".parse().unwrap();
+        let mut prefixes = range.into_iter();
+        assert_eq!(prefixes.checked_len(), Some(2 + 4));
+        assert_eq!(prefixes.size_hint(), (6, Some(6)));
+        _ = prefixes.next();
+        assert_eq!(prefixes.checked_len(), Some(5));
+    }
+
+    #[test]
+    fn checked_len_overflow_ipv6() {
+        let range: Range<Ipv6> = "::/0,64,64".parse().unwrap();
+        let mut prefixes = range.into_iter();
+        assert_eq!(prefixes.checked_len(), None);
+        assert_eq!(prefixes.size_hint(), (usize::MAX, None));
+
+        let range: Range<Ipv6> = "::/0,63,63".parse().unwrap();
+        assert_eq!(range.into_iter().checked_len(), Some(1 << 63));
+
+        let range: Range<Ipv6> = "::/0,63,64".parse().unwrap();
+        assert_eq!(range.into_iter().checked_len(), None);
+        _ = prefixes.next();
+        assert_eq!(prefixes.checked_len(), Some(usize::MAX));
+    }
+
+    #[test]
+    fn checked_len_of_all_ipv6_addresses() {
+        let range: Range<Ipv6> = "::/0,128,128".parse().unwrap();
+        let prefixes = range.into_iter();
+        assert_eq!(prefixes.checked_len(), None);
+        assert_eq!(prefixes.size_hint(), (usize::MAX, None));
+    }
+}
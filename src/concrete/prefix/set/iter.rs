@@ -1,11 +1,33 @@
+use core::iter::FusedIterator;
+
 use super::{node, Set};
-use crate::{concrete::PrefixRange, traits::Afi};
+use crate::{
+    concrete::{Prefix, PrefixLength, PrefixRange},
+    traits::Afi,
+};
 
 /// Non-consuming iterator returned by [`PrefixSet<A>::ranges()`].
+#[allow(clippy::struct_field_names)]
 #[derive(Debug)]
 pub struct Ranges<'a, A: Afi> {
     tree_iter: Option<node::Children<'a, A>>,
-    ranges_iter: Option<node::Ranges<'a, A>>,
+    ranges_iter: Option<node::Ranges<A>>,
+    // The number of prefixes in the ranges yet to be yielded, if known.
+    remaining: Remaining,
+}
+
+impl<'a, A: Afi> Ranges<'a, A> {
+    pub(super) fn within(s: &'a Set<A>, cover: &Prefix<A>) -> Self {
+        let (ranges_iter, subtree) = s.root.as_ref().map_or((None, None), |root| {
+            let (ranges, subtree) = root.within(cover);
+            (Some(ranges), subtree)
+        });
+        Self {
+            tree_iter: subtree.map(|node| node.children()),
+            ranges_iter,
+            remaining: Remaining::Unknown,
+        }
+    }
 }
 
 impl<'a, A: Afi> From<&'a Set<A>> for Ranges<'a, A> {
@@ -13,18 +35,22 @@ impl<'a, A: Afi> From<&'a Set<A>> for Ranges<'a, A> {
         Self {
             tree_iter: s.root.as_ref().map(|root| root.children()),
             ranges_iter: None,
+            remaining: s.root.as_ref().map_or(0, |root| root.count()).into(),
         }
     }
 }
 
-impl<'a, A: Afi> Iterator for Ranges<'a, A> {
-    type Item = <node::Ranges<'a, A> as Iterator>::Item;
+impl<A: Afi> Iterator for Ranges<'_, A> {
+    type Item = <node::Ranges<A> as Iterator>::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             if let Some(ref mut ranges_iter) = self.ranges_iter {
                 match ranges_iter.next() {
-                    range @ Some(_) => return range,
+                    Some(range) => {
+                        self.remaining.consume(prefix_count(&range));
+                        return Some(range);
+                    }
                     None => self.ranges_iter = None,
                 }
             }
@@ -38,17 +64,168 @@ impl<'a, A: Afi> Iterator for Ranges<'a, A> {
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every range contains at least one prefix.
+        match self.remaining.size_hint() {
+            (0, upper) => (0, upper),
+            (_, upper) => (1, upper),
+        }
+    }
 }
 
+impl<A: Afi> FusedIterator for Ranges<'_, A> {}
+
 /// Non-consuming iterator returned by [`PrefixSet<A>::prefixes()`].
 #[derive(Debug)]
 pub struct Prefixes<'a, A: Afi> {
     ranges_iter: Ranges<'a, A>,
     prefix_range_iter: Option<<PrefixRange<A> as IntoIterator>::IntoIter>,
+    remaining: Remaining,
+}
+
+impl<'a, A: Afi> From<Ranges<'a, A>> for Prefixes<'a, A> {
+    fn from(ranges_iter: Ranges<'a, A>) -> Self {
+        Self {
+            remaining: ranges_iter.remaining,
+            ranges_iter,
+            prefix_range_iter: None,
+        }
+    }
 }
 
 impl<'a, A: Afi> From<&'a Set<A>> for Prefixes<'a, A> {
     fn from(s: &'a Set<A>) -> Self {
+        Ranges::from(s).into()
+    }
+}
+
+impl<A: Afi> Iterator for Prefixes<'_, A> {
+    type Item = <PrefixRange<A> as IntoIterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut prefix_range_iter) = self.prefix_range_iter {
+                match prefix_range_iter.next() {
+                    p @ Some(_) => {
+                        self.remaining.consume(1);
+                        return p;
+                    }
+                    None => self.prefix_range_iter = None,
+                }
+            }
+            match self.ranges_iter.next() {
+                Some(range) => self.prefix_range_iter = Some(range.into_iter()),
+                None => return None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining.size_hint()
+    }
+}
+
+impl<A: Afi> FusedIterator for Prefixes<'_, A> {}
+
+// Count of the prefixes remaining in a borrowing iterator.
+//
+// The prefix count of a set saturates at `usize::MAX`, so a saturated count
+// is only a lower bound. The count is not known at all when iterating over
+// part of a set.
+#[derive(Clone, Copy, Debug)]
+enum Remaining {
+    Exact(usize),
+    AtLeast(usize),
+    Unknown,
+}
+
+impl Remaining {
+    const fn consume(&mut self, n: usize) {
+        if let Self::Exact(remaining) | Self::AtLeast(remaining) = self {
+            *remaining = remaining.saturating_sub(n);
+        }
+    }
+
+    const fn size_hint(self) -> (usize, Option<usize>) {
+        match self {
+            Self::Exact(n) => (n, Some(n)),
+            Self::AtLeast(n) => (n, None),
+            Self::Unknown => (0, None),
+        }
+    }
+}
+
+impl From<usize> for Remaining {
+    fn from(count: usize) -> Self {
+        if count == usize::MAX {
+            Self::AtLeast(count)
+        } else {
+            Self::Exact(count)
+        }
+    }
+}
+
+// Count the prefixes in `range`, saturating at `usize::MAX`.
+fn prefix_count<A: Afi>(range: &PrefixRange<A>) -> usize {
+    let shift = |length: PrefixLength<A>| {
+        let length: usize = length.into_primitive().into();
+        let base: usize = range.prefix().length().into_primitive().into();
+        u32::try_from(length - base).unwrap_or(u32::MAX)
+    };
+    (shift(range.lower())..=shift(range.upper())).fold(0, |count: usize, shift| {
+        count.saturating_add(1usize.checked_shl(shift).unwrap_or(usize::MAX))
+    })
+}
+
+/// Consuming iterator returned by [`PrefixSet<A>::into_ranges()`].
+#[derive(Debug)]
+pub struct IntoRanges<A: Afi> {
+    tree_iter: Option<node::IntoChildren<A>>,
+    ranges_iter: Option<node::Ranges<A>>,
+}
+
+impl<A: Afi> From<Set<A>> for IntoRanges<A> {
+    fn from(s: Set<A>) -> Self {
+        Self {
+            tree_iter: s.root.map(node::Node::into_children),
+            ranges_iter: None,
+        }
+    }
+}
+
+impl<A: Afi> Iterator for IntoRanges<A> {
+    type Item = <node::Ranges<A> as Iterator>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ref mut ranges_iter) = self.ranges_iter {
+                match ranges_iter.next() {
+                    range @ Some(_) => return range,
+                    None => self.ranges_iter = None,
+                }
+            }
+            if let Some(ref mut tree_iter) = self.tree_iter {
+                match tree_iter.next() {
+                    Some(node) => self.ranges_iter = Some(node.ranges()),
+                    None => return None,
+                }
+            } else {
+                return None;
+            }
+        }
+    }
+}
+
+/// Consuming iterator returned by [`PrefixSet<A>::into_iter()`].
+#[derive(Debug)]
+pub struct IntoIter<A: Afi> {
+    ranges_iter: IntoRanges<A>,
+    prefix_range_iter: Option<<PrefixRange<A> as IntoIterator>::IntoIter>,
+}
+
+impl<A: Afi> From<Set<A>> for IntoIter<A> {
+    fn from(s: Set<A>) -> Self {
         Self {
             ranges_iter: s.into(),
             prefix_range_iter: None,
@@ -56,7 +233,7 @@ impl<'a, A: Afi> From<&'a Set<A>> for Prefixes<'a, A> {
     }
 }
 
-impl<A: Afi> Iterator for Prefixes<'_, A> {
+impl<A: Afi> Iterator for IntoIter<A> {
     type Item = <PrefixRange<A> as IntoIterator>::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -1,17 +1,36 @@
+use core::fmt;
 use std::boxed::Box;
 use std::mem;
+use std::ops::RangeInclusive;
+use std::vec;
+use std::vec::Vec;
 
-use super::{Prefix, Range};
-use crate::traits::{self, Afi};
+#[cfg(any(test, feature = "arbitrary"))]
+use proptest::{
+    arbitrary::{any_with, Arbitrary, ParamsFor},
+    strategy::{BoxedStrategy, Strategy},
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use super::{Prefix, PrefixLength, Range};
+use crate::{
+    concrete::{Address, AddressRange},
+    traits::{self, primitive::Address as _, Afi, Prefix as _, PrefixSet as _},
+};
 
 mod iter;
-use self::iter::{Prefixes, Ranges};
+use self::iter::{IntoIter, IntoRanges, Prefixes, Ranges};
 
 mod node;
 use self::node::Node;
+pub(super) use self::node::{branch_direction, Direction};
 
 mod ops;
 
+mod parse;
+pub(crate) use self::parse::ListItems;
+
 /// A collection of IP prefixes, providing fast insertion and iteration,
 /// and set-theorectic arithmetic.
 ///
@@ -41,6 +60,20 @@ mod ops;
 /// }
 /// ```
 ///
+/// Borrowing a set iterates over the prefixes that it contains:
+///
+/// ``` rust
+/// # use ip::{Error, Ipv4, PrefixRange, PrefixSet};
+/// let set = PrefixSet::<Ipv4>::from_iter(["192.0.2.0/24,25,25".parse::<PrefixRange<Ipv4>>()?]);
+/// let mut prefixes = Vec::new();
+/// for prefix in &set {
+///     prefixes.push(prefix.to_string());
+/// }
+/// prefixes.sort();
+/// assert_eq!(prefixes, ["192.0.2.0/25", "192.0.2.128/25"]);
+/// # Ok::<_, Error>(())
+/// ```
+///
 /// Most mutating methods return `&mut Self` for easy chaining, e.g.:
 ///
 /// ``` rust
@@ -69,6 +102,12 @@ impl<A: Afi> Set<A> {
         Self { root }
     }
 
+    fn aggregated(root: Option<Box<Node<A>>>) -> Self {
+        let mut set = Self::new_with_root(root);
+        _ = set.aggregate();
+        set
+    }
+
     fn insert_node(&mut self, new: Box<Node<A>>) -> &mut Self {
         match mem::take(&mut self.root) {
             Some(root) => {
@@ -77,7 +116,7 @@ impl<A: Afi> Set<A> {
             None => {
                 self.root = Some(new);
             }
-        };
+        }
         self
     }
 
@@ -107,6 +146,81 @@ impl<A: Afi> Set<A> {
         self.insert_only(item).aggregate()
     }
 
+    /// Insert a new `item` into `self`, returning [`true`] if the set of
+    /// prefixes contained in `self` changed as a result.
+    ///
+    /// Inserting an `item` whose prefixes are all already contained in
+    /// `self` leaves `self` unchanged, and returns [`false`].
+    ///
+    /// The check walks the tree without enumerating the prefixes of `item`,
+    /// so is cheap even for very large ranges.
+    ///
+    /// Note that the change reported is to the *prefixes* contained in
+    /// `self`, rather than to the address space that they cover: inserting
+    /// a prefix covering others already present changes the set, even if
+    /// no new addresses are covered. Use
+    /// [`insert_reporting()`][Self::insert_reporting] to detect the latter.
+    ///
+    /// `T` can be either a [`Prefix<A>`](crate::concrete::Prefix) or a
+    /// [`PrefixRange<A>`](crate::concrete::PrefixRange).
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, Prefix, PrefixRange, PrefixSet};
+    /// let mut set = PrefixSet::<Ipv4>::new();
+    /// let range: PrefixRange<Ipv4> = "192.0.2.0/24,25,26".parse()?;
+    /// assert!(set.insert_changed(range));
+    /// assert!(!set.insert_changed("192.0.2.128/25".parse::<Prefix<Ipv4>>()?));
+    /// assert!(set.insert_changed("192.0.2.0/24".parse::<Prefix<Ipv4>>()?));
+    /// assert_eq!(set.address_count(), 256);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn insert_changed<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Node<A>>,
+    {
+        let new = item.into().boxed();
+        let changed = !new.is_subset(self.root.as_deref());
+        if changed {
+            _ = self.insert_node(new).aggregate();
+        }
+        changed
+    }
+
+    /// Insert a new `item` into `self`, returning [`true`] if the address
+    /// space covered by `self` grew as a result.
+    ///
+    /// Unlike [`insert_changed()`][Self::insert_changed], inserting an
+    /// `item` whose addresses are all covered by prefixes already in `self`
+    /// returns [`false`], even if new prefixes are added.
+    ///
+    /// The check walks only the part of the tree containing or contained by
+    /// `item`, so is cheap even for very large sets.
+    ///
+    /// `T` can be either a [`Prefix<A>`](crate::concrete::Prefix) or a
+    /// [`PrefixRange<A>`](crate::concrete::PrefixRange).
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, Prefix, PrefixRange, PrefixSet};
+    /// let mut set = PrefixSet::<Ipv4>::new();
+    /// let range: PrefixRange<Ipv4> = "192.0.2.0/24,25,25".parse()?;
+    /// assert!(set.insert_reporting(range));
+    /// assert!(!set.insert_reporting("192.0.2.0/24".parse::<Prefix<Ipv4>>()?));
+    /// assert!(set.contains("192.0.2.0/24".parse()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn insert_reporting<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Node<A>>,
+    {
+        let new = item.into().boxed();
+        let grew = !self
+            .root
+            .as_deref()
+            .is_some_and(|root| root.covers_addresses(new.prefix()));
+        _ = self.insert_node(new).aggregate();
+        grew
+    }
+
     /// Insert items into `self` from an iterator yielding either
     /// [`Prefix<A>`](crate::concrete::Prefix) or
     /// [`PrefixRange<A>`](crate::concrete::PrefixRange).
@@ -134,13 +248,50 @@ impl<A: Afi> Set<A> {
             .aggregate()
     }
 
-    fn remove_node(&mut self, mut old: Box<Node<A>>) -> &mut Self {
+    /// Move all of the prefixes contained in `other` into `self`.
+    ///
+    /// The tree underlying `other` is merged directly into that of `self`,
+    /// and aggregation occurs once the merge is complete. This makes
+    /// combining many sets far cheaper than re-inserting the contents of each
+    /// via [`PrefixSet::insert_from()`][Self::insert_from].
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, Prefix, PrefixSet};
+    /// let sets = ["192.0.2.0/25", "192.0.2.128/25", "198.51.100.0/24"]
+    ///     .into_iter()
+    ///     .map(|s| Ok(PrefixSet::<Ipv4>::from_iter([s.parse::<Prefix<Ipv4>>()?])))
+    ///     .collect::<Result<Vec<_>, Error>>()?;
+    /// let set = sets
+    ///     .into_iter()
+    ///     .fold(PrefixSet::<Ipv4>::new(), |mut set, other| {
+    ///         set.append(other);
+    ///         set
+    ///     });
+    /// assert_eq!(set.len(), 3);
+    /// assert_eq!(set.ranges().count(), 2);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn append(&mut self, other: Self) -> &mut Self {
+        if let Some(root) = other.root {
+            _ = self.insert_node(root).aggregate();
+        }
+        self
+    }
+
+    fn remove_node(&mut self, old: &Node<A>) -> &mut Self {
         if let Some(root) = mem::take(&mut self.root) {
-            self.root = Some(root.remove(&mut old));
-        };
+            self.root = Some(root.remove(old));
+        }
         self
     }
 
+    pub(crate) fn remove_only<T>(&mut self, item: T) -> &mut Self
+    where
+        T: Into<Node<A>>,
+    {
+        self.remove_node(&item.into())
+    }
+
     /// Remove an `item` from `self`.
     ///
     /// `T` can be either a [`Prefix<A>`](crate::concrete::Prefix) or a
@@ -161,7 +312,90 @@ impl<A: Afi> Set<A> {
     where
         T: Into<Node<A>>,
     {
-        self.remove_node(item.into().boxed()).aggregate()
+        self.remove_node(&item.into()).aggregate()
+    }
+
+    /// Remove an `item` from `self`, returning [`true`] if the set of
+    /// prefixes contained in `self` changed as a result.
+    ///
+    /// Removing an `item` none of whose prefixes are contained in `self`
+    /// leaves `self` unchanged, and returns [`false`]. This is useful for
+    /// detecting attempts to remove prefixes that were never present.
+    ///
+    /// As for [`insert_changed()`][Self::insert_changed], the change
+    /// reported is to the prefixes contained in `self`, rather than to the
+    /// address space that they cover. Use
+    /// [`remove_reporting()`][Self::remove_reporting] to detect the latter.
+    ///
+    /// `T` can be either a [`Prefix<A>`](crate::concrete::Prefix) or a
+    /// [`PrefixRange<A>`](crate::concrete::PrefixRange).
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv6, Prefix, PrefixSet};
+    /// let mut set = ["2001:db8:f00::/48", "2001:db8:baa::/48"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Ipv6>>())
+    ///     .collect::<Result<PrefixSet<Ipv6>, _>>()?;
+    /// assert!(set.remove_changed("2001:db8:f00::/48".parse::<Prefix<Ipv6>>()?));
+    /// assert!(!set.remove_changed("2001:db8:f00::/48".parse::<Prefix<Ipv6>>()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn remove_changed<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Node<A>>,
+    {
+        let old: Node<A> = item.into();
+        let changed = self
+            .root
+            .as_deref()
+            .is_some_and(|root| !old.is_disjoint(root));
+        if changed {
+            _ = self.remove_node(&old).aggregate();
+        }
+        changed
+    }
+
+    /// Remove an `item` from `self`, returning [`true`] if the address space
+    /// covered by `self` shrank as a result.
+    ///
+    /// Unlike [`remove_changed()`][Self::remove_changed], removing prefixes
+    /// whose addresses remain covered by other prefixes in `self` returns
+    /// [`false`].
+    ///
+    /// The prefixes covering addresses within `item` are collected before
+    /// `item` is removed, and each is then checked to still be covered, so
+    /// only the part of the tree covering `item` is walked.
+    ///
+    /// `T` can be either a [`Prefix<A>`](crate::concrete::Prefix) or a
+    /// [`PrefixRange<A>`](crate::concrete::PrefixRange).
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv6, Prefix, PrefixSet};
+    /// let mut set = ["2001:db8::/32", "2001:db8:f00::/48"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Ipv6>>())
+    ///     .collect::<Result<PrefixSet<Ipv6>, _>>()?;
+    /// assert!(!set.remove_reporting("2001:db8:f00::/48".parse::<Prefix<Ipv6>>()?));
+    /// assert!(set.remove_reporting("2001:db8::/32".parse::<Prefix<Ipv6>>()?));
+    /// assert!(set.is_empty());
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn remove_reporting<T>(&mut self, item: T) -> bool
+    where
+        T: Into<Node<A>>,
+    {
+        let old: Node<A> = item.into();
+        let mut covered = Vec::new();
+        if let Some(root) = &self.root {
+            root.covered_within(old.prefix(), &mut covered);
+        }
+        _ = self.remove_node(&old).aggregate();
+        covered.iter().any(|prefix| {
+            !self
+                .root
+                .as_deref()
+                .is_some_and(|root| root.covers_addresses(prefix))
+        })
     }
 
     /// Remove items from `self` from an iterator yielding either
@@ -189,10 +423,383 @@ impl<A: Afi> Set<A> {
         T: Into<Node<A>>,
     {
         iter.into_iter()
-            .fold(self, |set, item| set.remove_node(item.into().boxed()))
+            .fold(self, |set, item| set.remove_only(item))
             .aggregate()
     }
 
+    /// Remove every prefix covered by `prefix` from `self`, returning them as
+    /// a new [`PrefixSet<A>`][Self].
+    ///
+    /// This is analogous to [`BTreeMap::split_off()`][std::collections::BTreeMap::split_off],
+    /// but partitions `self` by coverage rather than by order: a prefix is
+    /// moved if it is equal to, or a subprefix of, `prefix`.
+    ///
+    /// Ranges that straddle the boundary are split, so that the union of the
+    /// two resulting sets is always equal to the original set.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{traits::PrefixSet as _, Ipv4, Prefix, PrefixRange, PrefixSet};
+    ///
+    /// let mut set = PrefixSet::<Ipv4>::from_iter(["0.0.0.0/0,8,24".parse::<PrefixRange<Ipv4>>()?]);
+    /// let ten = set.split_off("10.0.0.0/8".parse::<Prefix<Ipv4>>()?);
+    ///
+    /// assert_eq!(
+    ///     ten.ranges().collect::<Vec<_>>(),
+    ///     ["10.0.0.0/8,8,24".parse::<PrefixRange<Ipv4>>()?],
+    /// );
+    /// assert!(!set.contains("10.1.0.0/16".parse()?));
+    /// assert!(set.contains("11.1.0.0/16".parse()?));
+    /// assert_eq!(set.len() + ten.len(), (1 << 25) - (1 << 8));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn split_off(&mut self, prefix: Prefix<A>) -> Self {
+        let (rest, taken) =
+            mem::take(&mut self.root).map_or((None, None), |root| root.split_off(&prefix));
+        self.root = rest;
+        _ = self.aggregate();
+        let mut taken = Self::new_with_root(taken);
+        _ = taken.aggregate();
+        taken
+    }
+
+    /// Remove every prefix covered by `prefix` from `self`.
+    ///
+    /// This is equivalent to discarding the result of
+    /// [`PrefixSet::split_off()`][Self::split_off], and is far cheaper than
+    /// subtracting a [`PrefixSet<A>`][Self] containing the
+    /// [`PrefixRange<A>`](crate::concrete::PrefixRange) of all subprefixes of
+    /// `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{traits::PrefixSet as _, Ipv6, Prefix, PrefixRange, PrefixSet};
+    ///
+    /// let mut set = PrefixSet::<Ipv6>::from_iter(["2001:db8::/32,32,64".parse::<PrefixRange<Ipv6>>()?]);
+    /// set.remove_covered("2001:db8:f00::/40".parse::<Prefix<Ipv6>>()?);
+    ///
+    /// assert!(set.contains("2001:db8::/32".parse()?));
+    /// assert!(set.contains("2001:db8::/40".parse()?));
+    /// assert!(!set.contains("2001:db8:f00::/48".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    pub fn remove_covered(&mut self, prefix: Prefix<A>) -> &mut Self {
+        if let Some(root) = mem::take(&mut self.root) {
+            (self.root, _) = root.split_off(&prefix);
+        }
+        self.aggregate()
+    }
+
+    /// Returns [`true`] if every prefix in `self` is also contained in
+    /// `other`.
+    ///
+    /// This walks both trees simultaneously, and returns as soon as a prefix
+    /// not contained in `other` is found.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, PrefixRange, PrefixSet};
+    ///
+    /// let set = PrefixSet::<Ipv4>::from_iter(["10.0.0.0/8,16,24".parse::<PrefixRange<Ipv4>>()?]);
+    /// let subset = PrefixSet::<Ipv4>::from_iter(["10.1.0.0/16,20,24".parse::<PrefixRange<Ipv4>>()?]);
+    ///
+    /// assert!(subset.is_subset(&set));
+    /// assert!(!set.is_subset(&subset));
+    /// assert!(PrefixSet::<Ipv4>::new().is_subset(&set));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.root
+            .as_ref()
+            .is_none_or(|root| root.is_subset(other.root.as_deref()))
+    }
+
+    /// Returns [`true`] if every prefix in `other` is also contained in
+    /// `self`.
+    ///
+    /// This is equivalent to [`other.is_subset(self)`][Self::is_subset].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv6, PrefixRange, PrefixSet};
+    ///
+    /// let set = PrefixSet::<Ipv6>::from_iter(["2001:db8::/32,48,64".parse::<PrefixRange<Ipv6>>()?]);
+    /// let other = PrefixSet::<Ipv6>::from_iter(["2001:db8::/33,48,48".parse::<PrefixRange<Ipv6>>()?]);
+    ///
+    /// assert!(set.is_superset(&other));
+    /// assert!(!other.is_superset(&set));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns [`true`] if `self` and `other` have no prefixes in common.
+    ///
+    /// This walks both trees simultaneously, and returns as soon as a common
+    /// prefix is found.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, PrefixRange, PrefixSet};
+    ///
+    /// let set = PrefixSet::<Ipv4>::from_iter(["10.0.0.0/8,16,16".parse::<PrefixRange<Ipv4>>()?]);
+    /// let longer = PrefixSet::<Ipv4>::from_iter(["10.0.0.0/8,24,24".parse::<PrefixRange<Ipv4>>()?]);
+    /// let overlapping = PrefixSet::<Ipv4>::from_iter(["10.1.0.0/16,16,24".parse::<PrefixRange<Ipv4>>()?]);
+    ///
+    /// assert!(set.is_disjoint(&longer));
+    /// assert!(!set.is_disjoint(&overlapping));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        match (&self.root, &other.root) {
+            (Some(r), Some(s)) => r.is_disjoint(s),
+            _ => true,
+        }
+    }
+
+    /// Returns the number of addresses covered by the prefixes in `self`,
+    /// saturating at [`u128::MAX`].
+    ///
+    /// This is distinct from [`len()`][traits::PrefixSet::len], which counts
+    /// prefixes: addresses covered by more than one prefix are counted once.
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, Ipv6, Prefix, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Ipv4>::from_iter([
+    ///     "192.0.2.0/24,24,26".parse::<PrefixRange<Ipv4>>()?,
+    ///     "198.51.100.0/25,25,25".parse()?,
+    /// ]);
+    /// assert_eq!(set.len(), 8);
+    /// assert_eq!(set.address_count(), 256 + 128);
+    ///
+    /// let set = PrefixSet::<Ipv6>::from_iter(["::/0".parse::<Prefix<Ipv6>>()?]);
+    /// assert_eq!(set.address_count(), u128::MAX);
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn address_count(&self) -> u128 {
+        let mut bases: Vec<_> = self.ranges().map(|range| range.prefix()).collect();
+        bases.sort_unstable_by_key(|prefix| (prefix.prefix(), prefix.length()));
+        let mut covering: Option<Prefix<A>> = None;
+        bases.into_iter().fold(0, |count: u128, prefix| {
+            if covering.is_some_and(|covering| covering.contains(&prefix)) {
+                count
+            } else {
+                covering = Some(prefix);
+                count.saturating_add(prefix.num_addresses())
+            }
+        })
+    }
+
+    /// Returns an iterator over the number of prefixes in `self` of each
+    /// prefix length, in ascending order of length.
+    ///
+    /// Only lengths for which `self` contains at least one prefix are
+    /// yielded. Counts are computed from the [`Range<A>`]s of `self`,
+    /// without expanding them into their member prefixes, and saturate at
+    /// [`usize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, PrefixLength, PrefixRange, PrefixSet};
+    ///
+    /// let set = ["192.0.2.0/24,24,25", "198.51.100.0/24,24,24", "10.0.0.0/8,10,10"]
+    ///     .into_iter()
+    ///     .map(str::parse::<PrefixRange<Ipv4>>)
+    ///     .collect::<Result<PrefixSet<Ipv4>, _>>()?;
+    ///
+    /// assert_eq!(
+    ///     set.length_histogram().collect::<Vec<_>>(),
+    ///     [
+    ///         (PrefixLength::<Ipv4>::from_primitive(10)?, 4),
+    ///         (PrefixLength::<Ipv4>::from_primitive(24)?, 2),
+    ///         (PrefixLength::<Ipv4>::from_primitive(25)?, 2),
+    ///     ],
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn length_histogram(&self) -> impl Iterator<Item = (PrefixLength<A>, usize)> {
+        let max: usize = A::Primitive::MAX_LENGTH.into();
+        let mut counts = vec![0usize; max + 1];
+        self.ranges().for_each(|range| {
+            let base: usize = range.prefix().length().into_primitive().into();
+            let lower: usize = range.lower().into_primitive().into();
+            let upper: usize = range.upper().into_primitive().into();
+            (lower..=upper).for_each(|length| {
+                let shift = u32::try_from(length - base).unwrap_or(u32::MAX);
+                let count = 1usize.checked_shl(shift).unwrap_or(usize::MAX);
+                counts[length] = counts[length].saturating_add(count);
+            });
+        });
+        counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            // Ok to unwrap because indices of `counts` are within the bounds
+            // of `PrefixLength<A>`
+            .map(|(length, count)| (length.try_into().unwrap(), count))
+    }
+
+    /// Returns the number of prefixes in `self` with a length within
+    /// `lengths`, saturating at [`usize::MAX`].
+    ///
+    /// See [`Self::length_histogram()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, PrefixLength, PrefixRange, PrefixSet};
+    ///
+    /// let set = ["192.0.2.0/24,24,25", "198.51.100.0/24,24,24", "10.0.0.0/8,10,10"]
+    ///     .into_iter()
+    ///     .map(str::parse::<PrefixRange<Ipv4>>)
+    ///     .collect::<Result<PrefixSet<Ipv4>, _>>()?;
+    ///
+    /// let lengths = PrefixLength::<Ipv4>::from_primitive(17)?..=PrefixLength::<Ipv4>::from_primitive(32)?;
+    /// assert_eq!(set.count_where(lengths), 4);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn count_where(&self, lengths: RangeInclusive<PrefixLength<A>>) -> usize {
+        self.length_histogram()
+            .filter(|(length, _)| lengths.contains(length))
+            .fold(0, |total, (_, count)| total.saturating_add(count))
+    }
+
+    /// Returns the smallest single prefix that contains every member of
+    /// `self`, or [`None`] if `self` is empty.
+    ///
+    /// This is read directly from the root of the underlying trie, without
+    /// iterating over the members of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv6, Prefix, PrefixSet};
+    ///
+    /// let set = ["2001:db8:f00::/48", "2001:db8:ba2::/48", "2001:db8:ba3::/48"]
+    ///     .into_iter()
+    ///     .map(str::parse::<Prefix<Ipv6>>)
+    ///     .collect::<Result<PrefixSet<Ipv6>, _>>()?;
+    ///
+    /// assert_eq!(set.spanning_prefix(), Some("2001:db8:800::/37".parse()?));
+    /// assert_eq!(PrefixSet::<Ipv6>::new().spanning_prefix(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn spanning_prefix(&self) -> Option<Prefix<A>> {
+        self.root.as_ref().map(|root| *root.prefix())
+    }
+
+    /// Returns the first and last members of `self` in network order, or
+    /// [`None`] if `self` is empty.
+    ///
+    /// Members are ordered by network address, and then by prefix length,
+    /// so that the first member is the shortest of those with the lowest
+    /// network address, and the last member is the longest of those with the
+    /// highest network address.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Ipv4, Prefix, PrefixRange, PrefixSet};
+    ///
+    /// let set = ["192.0.2.0/24,24,25", "198.51.100.0/24,24,24"]
+    ///     .into_iter()
+    ///     .map(str::parse::<PrefixRange<Ipv4>>)
+    ///     .collect::<Result<PrefixSet<Ipv4>, _>>()?;
+    ///
+    /// assert_eq!(
+    ///     set.bounds(),
+    ///     Some(("192.0.2.0/24".parse()?, "198.51.100.0/24".parse()?)),
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn bounds(&self) -> Option<(Prefix<A>, Prefix<A>)> {
+        let root = self.root.as_ref()?;
+        root.first().zip(root.last())
+    }
+
+    /// Retain only the prefixes in `self` for which the predicate `f`
+    /// returns [`true`].
+    ///
+    /// All failing prefixes are removed before `self` is re-aggregated.
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, PrefixRange, PrefixSet};
+    /// let mut set = PrefixSet::<Ipv4>::new()
+    ///     .insert("192.0.2.0/24,24,26".parse::<PrefixRange<Ipv4>>()?)
+    ///     .to_owned();
+    /// assert_eq!(set.len(), 7);
+    /// set.retain(|prefix| prefix.length().get() != 25);
+    /// assert_eq!(set.len(), 5);
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Prefix<A>) -> bool,
+    {
+        let failed: Vec<_> = self.prefixes().filter(|prefix| !f(*prefix)).collect();
+        _ = self.remove_from(failed);
+    }
+
+    /// Construct a new [`PrefixSet<A>`][Self] from a parallel iterator.
+    ///
+    /// The items of `iter` are split into chunks, each of which is inserted
+    /// into a separate sub-set on the [`rayon`] thread-pool. The sub-sets are
+    /// then merged pair-wise using the set union operator.
+    ///
+    /// The resulting set is equal to that constructed from the same items by
+    /// [`FromIterator::from_iter()`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{traits::PrefixSet as _, Ipv4, Prefix, PrefixSet};
+    ///
+    /// let prefixes = ["192.0.2.0/25", "192.0.2.128/25", "198.51.100.0/24"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Ipv4>>())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// let set = PrefixSet::<Ipv4>::par_from_iter(prefixes.clone());
+    ///
+    /// assert_eq!(set, prefixes.into_iter().collect());
+    /// # Ok::<_, ip::Error>(())
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn par_from_iter<I, T>(iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+        T: Into<Node<A>> + Send,
+        Self: Send,
+    {
+        iter.into_par_iter()
+            .fold(Self::new, |mut set, item| {
+                _ = set.insert_only(item);
+                set
+            })
+            .map(|mut set| {
+                _ = set.aggregate();
+                set
+            })
+            .reduce(Self::new, |lhs, rhs| lhs | rhs)
+    }
+
     pub(crate) fn aggregate(&mut self) -> &mut Self {
         if let Some(root) = mem::take(&mut self.root) {
             self.root = root.aggregate(None);
@@ -200,6 +807,110 @@ impl<A: Afi> Set<A> {
         self
     }
 
+    /// Returns an iterator over the aggregated entries of `self`, each as a
+    /// base [`Prefix<A>`] and the inclusive range of [`PrefixLength<A>`]
+    /// covered beneath it.
+    ///
+    /// This yields the same items as
+    /// [`ranges()`][traits::PrefixSet::ranges], for consumers that prefer to
+    /// work with `(prefix, lengths)` tuples. The nodes of the tree are walked
+    /// directly, without the per-range prefix counting that `ranges()` does
+    /// to provide an exact [`size_hint()`][Iterator::size_hint].
+    ///
+    /// ``` rust
+    /// # use ip::{Error, Ipv6, Prefix, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Ipv6>::from_iter(["2001:db8::/32,48,64".parse::<PrefixRange<Ipv6>>()?]);
+    /// let (prefix, lengths) = set.entries().next().unwrap();
+    /// assert_eq!(prefix, "2001:db8::/32".parse::<Prefix<Ipv6>>()?);
+    /// assert_eq!((lengths.start().get(), lengths.end().get()), (48, 64));
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn entries(
+        &self,
+    ) -> impl Iterator<Item = (Prefix<A>, RangeInclusive<PrefixLength<A>>)> + '_ {
+        self.root
+            .iter()
+            .flat_map(|root| root.children())
+            .flat_map(Node::ranges)
+            .map(|range| (range.prefix(), range.lower()..=range.upper()))
+    }
+
+    /// Returns an iterator over the [`PrefixRange<A>`](crate::concrete::PrefixRange)s
+    /// in `self` that are covered by `cover`.
+    ///
+    /// Rather than filtering a full iteration over `self`, this descends
+    /// directly to the part of the tree beneath `cover`. Ranges in `self`
+    /// that straddle `cover` are truncated to its subprefixes.
+    ///
+    /// ``` rust
+    /// # use ip::{Error, Ipv6, Prefix, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Ipv6>::from_iter([
+    ///     "2001:db8::/32,48,48".parse::<PrefixRange<Ipv6>>()?,
+    ///     "2001:db8:f00::/40,56,56".parse()?,
+    ///     "2001:db9::/32,32,32".parse()?,
+    /// ]);
+    /// let cover = "2001:db8:f00::/40".parse::<Prefix<Ipv6>>()?;
+    /// assert_eq!(
+    ///     set.ranges_within(cover).collect::<Vec<_>>(),
+    ///     [
+    ///         "2001:db8:f00::/40,48,48".parse::<PrefixRange<Ipv6>>()?,
+    ///         "2001:db8:f00::/40,56,56".parse()?,
+    ///     ],
+    /// );
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn ranges_within(&self, cover: Prefix<A>) -> impl Iterator<Item = Range<A>> + '_ {
+        Ranges::within(self, &cover)
+    }
+
+    /// Returns an iterator over the [`Prefix<A>`]s in `self` that are
+    /// covered by `cover`.
+    ///
+    /// See [`PrefixSet::ranges_within()`][Self::ranges_within].
+    ///
+    /// ``` rust
+    /// # use ip::{Error, Ipv4, Prefix, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Ipv4>::from_iter(["0.0.0.0/0,8,16".parse::<PrefixRange<Ipv4>>()?]);
+    /// let within = set.prefixes_within("10.0.0.0/15".parse()?);
+    /// assert_eq!(
+    ///     within.map(|p| p.to_string()).collect::<Vec<_>>(),
+    ///     ["10.0.0.0/15", "10.0.0.0/16", "10.1.0.0/16"],
+    /// );
+    /// # Ok::<_, Error>(())
+    /// ```
+    pub fn prefixes_within(&self, cover: Prefix<A>) -> impl Iterator<Item = Prefix<A>> + '_ {
+        Prefixes::from(Ranges::within(self, &cover))
+    }
+
+    /// Returns the complement of `self` within `cover`: that is, the set of
+    /// all prefixes covered by `cover` that are not contained in `self`.
+    ///
+    /// Unlike [`!self`][core::ops::Not], which complements `self` against
+    /// the entire address space, the result contains only `cover` and its
+    /// subprefixes. Only the part of `self` beneath `cover` is considered,
+    /// and the full address space is never materialised.
+    ///
+    /// ``` rust
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, Prefix, PrefixSet};
+    /// let set = PrefixSet::<Ipv4>::from_iter([
+    ///     "10.0.0.0/9".parse::<Prefix<Ipv4>>()?,
+    ///     "192.0.2.0/24".parse()?,
+    /// ]);
+    /// let complement = set.complement_within("10.0.0.0/8".parse()?);
+    /// assert!(complement.contains("10.0.0.0/8".parse()?));
+    /// assert!(complement.contains("10.128.0.0/9".parse()?));
+    /// assert!(!complement.contains("10.0.0.0/9".parse()?));
+    /// assert!(!complement.contains("192.0.2.0/24".parse()?));
+    /// assert!(!complement.contains("0.0.0.0/0".parse()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn complement_within(&self, cover: Prefix<A>) -> Self {
+        let mut complement = Self::from_iter([traits::PrefixRange::or_longer(Range::from(cover))]);
+        complement -= self.ranges_within(cover).collect();
+        complement
+    }
+
     /// Clear the contents of `self`
     ///
     /// ``` rust
@@ -215,6 +926,36 @@ impl<A: Afi> Set<A> {
     pub fn clear(&mut self) {
         self.root = None;
     }
+
+    /// Consume `self`, returning an iterator over its [`Range<A>`]s.
+    ///
+    /// This is the consuming counterpart of
+    /// [`ranges()`][traits::PrefixSet::ranges]. The underlying tree is
+    /// dropped incrementally as the iterator advances.
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// # use ip::{traits::Prefix as _, Error, Ipv4, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Ipv4>::from_iter([
+    ///     "192.0.2.0/24,25,26".parse::<PrefixRange<Ipv4>>()?,
+    ///     "198.51.100.0/24,24,24".parse()?,
+    /// ]);
+    /// let mut ranges = set.into_ranges().collect::<Vec<_>>();
+    /// ranges.sort_by_key(|r| (r.prefix().network(), r.prefix().length()));
+    /// assert_eq!(
+    ///     ranges,
+    ///     [
+    ///         "192.0.2.0/24,25,26".parse::<PrefixRange<Ipv4>>()?,
+    ///         "198.51.100.0/24,24,24".parse()?,
+    ///     ]
+    /// );
+    /// # Ok::<_, Error>(())
+    /// ```
+    #[must_use]
+    pub fn into_ranges(self) -> IntoRanges<A> {
+        self.into()
+    }
 }
 
 impl<'a, A: Afi> traits::PrefixSet<'a> for Set<A> {
@@ -231,10 +972,99 @@ impl<'a, A: Afi> traits::PrefixSet<'a> for Set<A> {
         self.into()
     }
 
+    fn len(&'a self) -> usize {
+        self.root.as_ref().map_or(0, |root| root.count())
+    }
+
+    fn is_empty(&'a self) -> bool {
+        self.len() == 0
+    }
+
+    fn insert(&mut self, prefix: Self::Prefix) -> &mut Self {
+        Self::insert(self, prefix)
+    }
+
+    fn insert_range(&mut self, range: Self::Range) -> &mut Self {
+        Self::insert(self, range)
+    }
+
+    fn remove(&mut self, prefix: Self::Prefix) -> &mut Self {
+        Self::remove(self, prefix)
+    }
+
+    fn remove_range(&mut self, range: Self::Range) -> &mut Self {
+        Self::remove(self, range)
+    }
+
+    fn clear(&mut self) {
+        Self::clear(self);
+    }
+
     fn contains(&self, prefix: Self::Prefix) -> bool {
         self.root
             .as_ref()
-            .map_or(false, |root| root.search(&prefix.into()).is_some())
+            .is_some_and(|root| root.search(&prefix.into()).is_some())
+    }
+
+    fn contains_range(&self, range: &Self::Range) -> bool {
+        self.root
+            .as_ref()
+            .is_some_and(|root| Node::from(range.clone()).is_subset(Some(root)))
+    }
+
+    fn contains_address(&self, addr: Address<A>) -> bool {
+        self.root
+            .as_ref()
+            .is_some_and(|root| root.covers(&addr.into()))
+    }
+
+    fn longest_match(&self, addr: Address<A>) -> Option<Self::Prefix> {
+        self.longest_match_prefix(addr.into())
+    }
+
+    fn longest_match_prefix(&self, prefix: Self::Prefix) -> Option<Self::Prefix> {
+        self.root
+            .as_ref()
+            .and_then(|root| root.longest_match(&prefix))
+            .map(|length| Prefix::new(prefix.network(), length))
+    }
+}
+
+/// Borrow a [`PrefixSet<A>`][Set], iterating over its [`Prefix<A>`]s.
+///
+/// This is equivalent to [`prefixes()`][traits::PrefixSet::prefixes].
+#[allow(clippy::into_iter_without_iter)]
+impl<'a, A: Afi> IntoIterator for &'a Set<A> {
+    type Item = Prefix<A>;
+    type IntoIter = Prefixes<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
+    }
+}
+
+/// Consume a [`PrefixSet<A>`][Set], iterating over its [`Prefix<A>`]s.
+///
+/// The underlying tree is dropped incrementally as the iterator advances.
+///
+/// # Examples
+///
+/// ``` rust
+/// # use ip::{Error, Ipv6, PrefixRange, PrefixSet};
+/// let set = PrefixSet::<Ipv6>::from_iter(["2001:db8::/32,33,33".parse::<PrefixRange<Ipv6>>()?]);
+/// let mut prefixes = Vec::new();
+/// for p in set {
+///     prefixes.push(p.to_string());
+/// }
+/// assert_eq!(prefixes, ["2001:db8::/33", "2001:db8:8000::/33"]);
+/// # Ok::<_, Error>(())
+/// ```
+impl<A: Afi> IntoIterator for Set<A> {
+    type Item = Prefix<A>;
+    type IntoIter = IntoIter<A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into()
     }
 }
 
@@ -244,6 +1074,25 @@ impl<A: Afi> Default for Set<A> {
     }
 }
 
+/// Extend a [`PrefixSet<A>`][Set] with [`Prefix<A>`]s or [`Range<A>`]s,
+/// either owned or borrowed.
+///
+/// # Examples
+///
+/// ``` rust
+/// # use ip::{traits::PrefixSet as _, Error, Ipv4, Prefix, PrefixRange, PrefixSet};
+/// let prefixes = ["192.0.2.0/25", "192.0.2.128/25"]
+///     .into_iter()
+///     .map(str::parse::<Prefix<Ipv4>>)
+///     .collect::<Result<Vec<_>, _>>()?;
+/// let ranges = vec!["198.51.100.0/24,24,25".parse::<PrefixRange<Ipv4>>()?];
+///
+/// let mut set = PrefixSet::<Ipv4>::from_iter(&prefixes);
+/// set.extend(ranges.iter());
+///
+/// assert_eq!(set.len(), 2 + 3);
+/// # Ok::<_, Error>(())
+/// ```
 impl<A: Afi, U> Extend<U> for Set<A>
 where
     U: Into<Node<A>>,
@@ -269,5 +1118,138 @@ where
     }
 }
 
+/// Construct a [`PrefixSet<A>`][Set] containing the minimal set of prefixes
+/// that exactly cover an [`AddressRange<A>`].
+///
+/// See [`AddressRange::to_prefixes()`].
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{concrete::AddressRange, traits::PrefixSet as _, Ipv4, PrefixSet};
+///
+/// let range = "10.0.0.3-10.0.0.10".parse::<AddressRange<Ipv4>>()?;
+/// let set = PrefixSet::<Ipv4>::from(range);
+///
+/// assert_eq!(set.prefixes().count(), 4);
+/// # Ok::<_, ip::Error>(())
+/// ```
+impl<A: Afi> From<AddressRange<A>> for Set<A> {
+    fn from(range: AddressRange<A>) -> Self {
+        range.to_prefixes().collect()
+    }
+}
+
+impl<A: Afi> FromIterator<AddressRange<A>> for Set<A> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = AddressRange<A>>,
+    {
+        Self::new()
+            .insert_from(iter.into_iter().flat_map(|range| range.to_prefixes()))
+            .clone()
+    }
+}
+
+/// Write the contents of the set, one item per line, in the tree's
+/// deterministic traversal order.
+///
+/// By default, each line is a [`PrefixRange<A>`][Range] in the
+/// `prefix,lower,upper` form accepted by its [`FromStr`][core::str::FromStr]
+/// implementation, so that the output can be parsed back into an equal set.
+///
+/// The alternate flag (`{:#}`) writes every [`Prefix<A>`] contained in the
+/// set instead.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{Error, Ipv4, PrefixRange, PrefixSet};
+///
+/// let set = ["192.0.2.0/24,25,26", "198.51.100.0/24,24,24"]
+///     .into_iter()
+///     .map(str::parse::<PrefixRange<Ipv4>>)
+///     .collect::<Result<PrefixSet<Ipv4>, _>>()?;
+///
+/// let displayed = set.to_string();
+/// assert_eq!(displayed, "198.51.100.0/24,24,24\n192.0.2.0/24,25,26");
+///
+/// let parsed = displayed
+///     .lines()
+///     .map(str::parse::<PrefixRange<Ipv4>>)
+///     .collect::<Result<PrefixSet<Ipv4>, _>>()?;
+/// assert_eq!(parsed, set);
+///
+/// assert_eq!(
+///     format!("{set:#}").lines().collect::<Vec<_>>(),
+///     [
+///         "198.51.100.0/24",
+///         "192.0.2.0/25",
+///         "192.0.2.128/25",
+///         "192.0.2.0/26",
+///         "192.0.2.64/26",
+///         "192.0.2.128/26",
+///         "192.0.2.192/26",
+///     ],
+/// );
+/// # Ok::<_, Error>(())
+/// ```
+impl<A: Afi> fmt::Display for Set<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write_lines(f, self.prefixes(), |f, prefix| write!(f, "{prefix}"))
+        } else {
+            write_lines(f, self.ranges(), |f, range| {
+                write!(f, "{},{},{}", range.prefix(), range.lower(), range.upper())
+            })
+        }
+    }
+}
+
+fn write_lines<I, F>(f: &mut fmt::Formatter<'_>, iter: I, mut write_item: F) -> fmt::Result
+where
+    I: IntoIterator,
+    F: FnMut(&mut fmt::Formatter<'_>, I::Item) -> fmt::Result,
+{
+    iter.into_iter().enumerate().try_for_each(|(i, item)| {
+        if i > 0 {
+            f.write_str("\n")?;
+        }
+        write_item(f, item)
+    })
+}
+
+/// Generate arbitrary [`PrefixSet<A>`][Set]s by inserting a collection of
+/// arbitrary [`PrefixRange<A>`](crate::concrete::PrefixRange)s.
+///
+/// The parameters control the number of ranges inserted, and are passed on
+/// to the strategy for each range. Since generation goes through
+/// [`Set::insert_from()`], generated sets are always in canonical form.
+/// Shrinking proceeds by removing ranges, and then by shrinking each of
+/// those that remain.
+///
+/// Generated ranges may span many prefix lengths, so operations that
+/// deaggregate large ranges, such as subtraction, can be expensive on
+/// generated sets.
+#[cfg(any(test, feature = "arbitrary"))]
+impl<A> Arbitrary for Set<A>
+where
+    A: Afi + 'static,
+    Range<A>: Arbitrary + 'static,
+{
+    type Parameters = ParamsFor<Vec<Range<A>>>;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+        any_with::<Vec<Range<A>>>(params)
+            .prop_map(|ranges| {
+                let mut set = Self::new();
+                _ = set.insert_from(ranges);
+                set
+            })
+            .boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -15,10 +15,22 @@ impl<A: Afi> From<PrefixRange<A>> for Node<A> {
     }
 }
 
+impl<A: Afi> From<&Prefix<A>> for Node<A> {
+    fn from(prefix: &Prefix<A>) -> Self {
+        (*prefix).into()
+    }
+}
+
+impl<A: Afi> From<&PrefixRange<A>> for Node<A> {
+    fn from(range: &PrefixRange<A>) -> Self {
+        range.clone().into()
+    }
+}
+
 impl<A: Afi> FromStr for Node<A> {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.parse::<Prefix<_>>().map(Self::from).map_err(Error::from)
+        s.parse::<Prefix<_>>().map(Self::from)
     }
 }
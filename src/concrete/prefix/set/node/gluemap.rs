@@ -76,6 +76,38 @@ impl<A: Afi> GlueMap<A> {
         self.inner.count_ones()
     }
 
+    // Count the subprefixes of a prefix of length `length` represented by
+    // `self`, saturating at `usize::MAX`.
+    pub fn count_prefixes(&self, length: PrefixLength<A>) -> usize {
+        let base: usize = length.into_primitive().into();
+        self.inner.iter_ones().fold(0, |count, len| {
+            let shift = u32::try_from(len - base).unwrap_or(u32::MAX);
+            count.saturating_add(1usize.checked_shl(shift).unwrap_or(usize::MAX))
+        })
+    }
+
+    // Returns a gluemap with the bits for `length` and every greater length
+    // set.
+    pub fn from_length(length: PrefixLength<A>) -> Self {
+        let mut map = Self::ZERO;
+        let mut length = length;
+        loop {
+            map |= Self::singleton(length);
+            match length.increment() {
+                Ok(next) => length = next,
+                Err(_) => break map,
+            }
+        }
+    }
+
+    pub fn last_upto(&self, upto: PrefixLength<A>) -> Option<PrefixLength<A>> {
+        let end = upto.into_primitive().into();
+        let last = self.inner[..=end].last_one()?;
+        // Ok to unwrap because indices of Self are within the bounds
+        // of `PrefixLength<A>`
+        Some(last.try_into().unwrap())
+    }
+
     pub fn next_range(&self, from: PrefixLength<A>) -> Option<RangeInclusive<PrefixLength<A>>> {
         let max = || Self::MAX.into();
         let start = from.into_primitive().into();
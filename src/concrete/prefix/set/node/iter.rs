@@ -1,9 +1,9 @@
 use std::boxed::Box;
 use std::vec::Vec;
 
-use super::Node;
+use super::{GlueMap, Node};
 use crate::{
-    concrete::{PrefixLength, PrefixRange},
+    concrete::{Prefix, PrefixLength, PrefixRange},
     traits::{Afi, PrefixLength as _},
 };
 
@@ -11,7 +11,7 @@ use crate::{
 #[derive(Debug)]
 pub struct Children<'a, A: Afi> {
     this: Option<&'a Node<A>>,
-    parent: Option<Box<Children<'a, A>>>,
+    parent: Option<Box<Self>>,
     children: Vec<Option<&'a Node<A>>>,
 }
 
@@ -60,29 +60,66 @@ impl<'a, A: Afi> Iterator for Children<'a, A> {
     }
 }
 
+// Consuming counterpart of `Children`, which detaches each node from its
+// descendants as it is yielded, so that the tree is dropped incrementally.
+//
+// The stack holds at most one pending sibling per level of the tree.
 #[derive(Debug)]
-pub struct Ranges<'a, A: Afi> {
-    this: &'a Node<A>,
+pub struct IntoChildren<A: Afi> {
+    stack: Vec<Box<Node<A>>>,
+}
+
+impl<A: Afi> From<Box<Node<A>>> for IntoChildren<A> {
+    fn from(node: Box<Node<A>>) -> Self {
+        Self {
+            stack: [node].into(),
+        }
+    }
+}
+
+impl<A: Afi> Iterator for IntoChildren<A> {
+    type Item = Box<Node<A>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut node = self.stack.pop()?;
+        // visit children in the same order as `Children`
+        self.stack.extend(node.left.take());
+        self.stack.extend(node.right.take());
+        Some(node)
+    }
+}
+
+#[derive(Debug)]
+pub struct Ranges<A: Afi> {
+    prefix: Prefix<A>,
+    gluemap: GlueMap<A>,
     next_length: Option<PrefixLength<A>>,
 }
 
-impl<'a, A: Afi> From<&'a Node<A>> for Ranges<'a, A> {
-    fn from(node: &'a Node<A>) -> Self {
+impl<A: Afi> Ranges<A> {
+    pub(super) const fn new(prefix: Prefix<A>, gluemap: GlueMap<A>) -> Self {
         Self {
-            this: node,
+            prefix,
+            gluemap,
             next_length: Some(PrefixLength::MIN),
         }
     }
 }
 
-impl<A: Afi> Iterator for Ranges<'_, A> {
+impl<A: Afi> From<&Node<A>> for Ranges<A> {
+    fn from(node: &Node<A>) -> Self {
+        Self::new(node.prefix, node.gluemap)
+    }
+}
+
+impl<A: Afi> Iterator for Ranges<A> {
     type Item = PrefixRange<A>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let range = self.this.gluemap.next_range(self.next_length?)?;
+        let range = self.gluemap.next_range(self.next_length?)?;
         self.next_length = range.end().increment().ok();
-        // unwrap is safe here as long as self.map doesn't have any
-        // bits set lower than self.this.prefix.length()
-        Some(PrefixRange::new(self.this.prefix, range).unwrap())
+        // unwrap is safe here as long as self.gluemap doesn't have any
+        // bits set lower than self.prefix.length()
+        Some(PrefixRange::new(self.prefix, range).unwrap())
     }
 }
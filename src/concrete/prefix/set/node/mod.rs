@@ -1,8 +1,9 @@
 use std::boxed::Box;
+use std::vec::Vec;
 
 use super::Prefix;
 use crate::{
-    concrete::{Hostmask, Netmask, PrefixOrdering},
+    concrete::{BranchPoint, Hostmask, Netmask, PrefixLength, PrefixOrdering},
     traits::{Address as _, Afi, Prefix as _, PrefixLength as _},
 };
 
@@ -12,9 +13,9 @@ mod iter;
 mod ops;
 
 use self::gluemap::GlueMap;
-pub(super) use self::iter::{Children, Ranges};
+pub(super) use self::iter::{Children, IntoChildren, Ranges};
 
-enum Direction {
+pub(in crate::concrete::prefix) enum Direction {
     Left,
     Right,
 }
@@ -23,28 +24,50 @@ enum Direction {
 pub struct Node<A: Afi> {
     prefix: Prefix<A>,
     gluemap: GlueMap<A>,
-    left: Option<Box<Node<A>>>,
-    right: Option<Box<Node<A>>>,
+    left: Option<Box<Self>>,
+    right: Option<Box<Self>>,
+    // The number of prefixes represented by `self` and its descendants.
+    //
+    // This is only guaranteed to be accurate once the tree containing
+    // `self` has been aggregated.
+    count: usize,
 }
 
 impl<A: Afi> Node<A> {
-    const fn new(prefix: Prefix<A>, gluemap: GlueMap<A>) -> Self {
+    fn new(prefix: Prefix<A>, gluemap: GlueMap<A>) -> Self {
         Self {
             prefix,
             gluemap,
             left: None,
             right: None,
+            count: gluemap.count_prefixes(prefix.length()),
         }
     }
 
-    const fn new_glue(prefix: Prefix<A>) -> Self {
+    fn new_glue(prefix: Prefix<A>) -> Self {
         Self::new(prefix, GlueMap::ZERO)
     }
 
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    fn recount(&mut self) {
+        self.count = [&self.left, &self.right].into_iter().flatten().fold(
+            self.gluemap.count_prefixes(self.prefix.length()),
+            |count, child| count.saturating_add(child.count),
+        );
+    }
+
     pub fn boxed(self) -> Box<Self> {
         Box::new(self)
     }
 
+    // Returns a copy of `self` without any descendants.
+    fn detached(&self) -> Box<Self> {
+        Self::new(self.prefix, self.gluemap).boxed()
+    }
+
     pub const fn prefix(&self) -> &Prefix<A> {
         &self.prefix
     }
@@ -54,8 +77,8 @@ impl<A: Afi> Node<A> {
     }
 
     pub fn add(mut self: Box<Self>, mut other: Box<Self>) -> Box<Self> {
-        match self.prefix().compare(other.prefix()) {
-            PrefixOrdering::Equal => {
+        match self.prefix().branch_point(other.prefix()) {
+            BranchPoint::Equal => {
                 self.gluemap |= other.gluemap;
                 if let Some(child) = other.left {
                     self = self.add(child);
@@ -65,86 +88,112 @@ impl<A: Afi> Node<A> {
                 }
                 self
             }
-            PrefixOrdering::Subprefix(common) => {
+            BranchPoint::Contains => {
                 // mask glue map for prefix lengths already present
                 other.gluemap &= !self.gluemap;
-                match other.branch_direction(&common) {
+                let slot = match other.branch_direction(self.prefix().length()) {
+                    Direction::Left => &mut self.left,
+                    Direction::Right => &mut self.right,
+                };
+                *slot = Some(match slot.take() {
+                    Some(child) => child.add(other),
+                    None => other,
+                });
+                self
+            }
+            BranchPoint::ContainedBy => other.add(self),
+            BranchPoint::Diverges { at } => {
+                let mut glue = Self::new_glue(Prefix::new(self.prefix().network(), at)).boxed();
+                match self.branch_direction(at) {
                     Direction::Left => {
-                        if let Some(child) = self.left {
-                            let new_child = child.add(other);
-                            self.left = Some(new_child);
-                        } else {
-                            self.left = Some(other);
-                        }
+                        glue.left = Some(self);
+                        glue.right = Some(other);
                     }
                     Direction::Right => {
-                        if let Some(child) = self.right {
-                            let new_child = child.add(other);
-                            self.right = Some(new_child);
-                        } else {
-                            self.right = Some(other);
-                        }
+                        glue.left = Some(other);
+                        glue.right = Some(self);
                     }
-                };
-                self
+                }
+                glue
             }
-            PrefixOrdering::Superprefix(common) => {
-                self.gluemap &= !other.gluemap;
-                match self.branch_direction(&common) {
+        }
+    }
+
+    // Returns a new tree representing the union of the trees at `self` and
+    // `other`, walking both simultaneously so that each node is copied once.
+    //
+    // The resulting tree is not aggregated.
+    pub fn union(&self, other: &Self) -> Box<Self> {
+        match self.prefix().branch_point(other.prefix()) {
+            BranchPoint::Equal => {
+                let mut node = Self::new(self.prefix, self.gluemap | other.gluemap).boxed();
+                node.left = Self::union_children(self.left.as_deref(), other.left.as_deref());
+                node.right = Self::union_children(self.right.as_deref(), other.right.as_deref());
+                node
+            }
+            BranchPoint::Contains => {
+                let mut node = self.detached();
+                match other.branch_direction(self.prefix().length()) {
                     Direction::Left => {
-                        if let Some(child) = other.left {
-                            let new_child = child.add(self);
-                            other.left = Some(new_child);
-                        } else {
-                            other.left = Some(self);
-                        }
+                        node.left = Self::union_children(self.left.as_deref(), Some(other));
+                        node.right.clone_from(&self.right);
                     }
                     Direction::Right => {
-                        if let Some(child) = other.right {
-                            let new_child = child.add(self);
-                            other.right = Some(new_child);
-                        } else {
-                            other.right = Some(self);
-                        }
+                        node.left.clone_from(&self.left);
+                        node.right = Self::union_children(self.right.as_deref(), Some(other));
                     }
-                };
-                other
+                }
+                node
             }
-            PrefixOrdering::Divergent(common) => {
-                // unwrap is safe here because common < P::MAX_LENGTH
-                let mut glue = Self::new_glue(common).boxed();
-                match self.branch_direction(&common) {
+            BranchPoint::ContainedBy => other.union(self),
+            BranchPoint::Diverges { at } => {
+                let mut glue = Self::new_glue(Prefix::new(self.prefix().network(), at)).boxed();
+                let (this, that) = (self.clone().boxed(), other.clone().boxed());
+                match self.branch_direction(at) {
                     Direction::Left => {
-                        glue.left = Some(self);
-                        glue.right = Some(other);
+                        glue.left = Some(this);
+                        glue.right = Some(that);
                     }
                     Direction::Right => {
-                        glue.left = Some(other);
-                        glue.right = Some(self);
+                        glue.left = Some(that);
+                        glue.right = Some(this);
                     }
-                };
+                }
                 glue
             }
         }
     }
 
-    pub fn remove(mut self: Box<Self>, other: &mut Self) -> Box<Self> {
-        if let Some(mut child) = other.left.take() {
-            self = self.remove(&mut child);
+    fn union_children(this: Option<&Self>, that: Option<&Self>) -> Option<Box<Self>> {
+        match (this, that) {
+            (Some(this), Some(that)) => Some(this.union(that)),
+            (Some(node), None) | (None, Some(node)) => Some(node.clone().boxed()),
+            (None, None) => None,
         }
-        if let Some(mut child) = other.right.take() {
-            self = self.remove(&mut child);
+    }
+
+    pub fn remove(mut self: Box<Self>, other: &Self) -> Box<Self> {
+        if let Some(child) = &other.left {
+            self = self.remove(child);
         }
+        if let Some(child) = &other.right {
+            self = self.remove(child);
+        }
+        self.remove_one(other)
+    }
+
+    // Remove the prefixes represented by `other`, ignoring its descendants.
+    fn remove_one(mut self: Box<Self>, other: &Self) -> Box<Self> {
         match self.prefix().compare(other.prefix()) {
             PrefixOrdering::Superprefix(_) | PrefixOrdering::Equal => {
                 // clear gluemap bits and recurse down
                 self.gluemap &= !other.gluemap;
                 if let Some(child) = self.left.take() {
-                    self.left = Some(child.remove(other));
-                };
+                    self.left = Some(child.remove_one(other));
+                }
                 if let Some(child) = self.right.take() {
-                    self.right = Some(child.remove(other));
-                };
+                    self.right = Some(child.remove_one(other));
+                }
             }
             PrefixOrdering::Subprefix(common) => {
                 let deaggr_mask = self.gluemap & other.gluemap;
@@ -158,24 +207,78 @@ impl<A: Afi> Node<A> {
                         .map(|p| Box::new(Self::new(p, deaggr_mask)))
                         .fold(self, Self::add);
                 }
-                match other.branch_direction(&common) {
+                match other.branch_direction(common.length()) {
                     Direction::Left => {
                         if let Some(child) = self.left.take() {
-                            self.left = Some(child.remove(other));
-                        };
+                            self.left = Some(child.remove_one(other));
+                        }
                     }
                     Direction::Right => {
                         if let Some(child) = self.right.take() {
-                            self.right = Some(child.remove(other));
-                        };
+                            self.right = Some(child.remove_one(other));
+                        }
                     }
                 }
             }
             PrefixOrdering::Divergent(_) => (),
-        };
+        }
         self
     }
 
+    // Split `self` into the trees of prefixes not covered and covered by
+    // `prefix`, respectively.
+    //
+    // Subtrees contained by `prefix` are detached whole. The gluemap bits
+    // of a node strictly containing `prefix` that straddle the boundary are
+    // moved to a new node at `prefix`, and re-added to each of the siblings
+    // along the path from the node down to `prefix`.
+    //
+    // The resulting trees are not aggregated.
+    pub fn split_off(
+        mut self: Box<Self>,
+        prefix: &Prefix<A>,
+    ) -> (Option<Box<Self>>, Option<Box<Self>>) {
+        match self.prefix().compare(prefix) {
+            PrefixOrdering::Equal | PrefixOrdering::Superprefix(_) => (None, Some(self)),
+            PrefixOrdering::Divergent(_) => (Some(self), None),
+            PrefixOrdering::Subprefix(common) => {
+                let slot = match branch_direction(prefix, common.length()) {
+                    Direction::Left => &mut self.left,
+                    Direction::Right => &mut self.right,
+                };
+                let (rest, mut taken) = slot
+                    .take()
+                    .map_or((None, None), |child| child.split_off(prefix));
+                *slot = rest;
+                let straddling = self.gluemap & GlueMap::from_length(prefix.length());
+                if straddling != GlueMap::ZERO {
+                    self.gluemap &= !straddling;
+                    let mut length = self.prefix().length();
+                    while length < prefix.length() {
+                        // ok to unwrap, because `length < prefix.length()`.
+                        let next = length.increment().unwrap();
+                        let ancestor = Prefix::new(prefix.network(), next);
+                        let sibling = match branch_direction(prefix, length) {
+                            Direction::Left => ancestor.offset(1),
+                            Direction::Right => ancestor.offset(-1),
+                        }
+                        // ok to unwrap, because `ancestor` and its sibling
+                        // are both contained by the prefix at `length`.
+                        .unwrap();
+                        self = self.add(Self::new(sibling, straddling).boxed());
+                        length = next;
+                    }
+                    let new = Self::new(*prefix, straddling).boxed();
+                    taken = Some(match taken {
+                        Some(taken) => taken.add(new),
+                        None => new,
+                    });
+                }
+                (Some(self), taken)
+            }
+        }
+    }
+
     pub fn aggregate(mut self: Box<Self>, mut mask: Option<GlueMap<A>>) -> Option<Box<Self>> {
         // set mask to zero if None given
         if mask.is_none() {
@@ -213,8 +316,11 @@ impl<A: Afi> Node<A> {
                 // unset the bits in each child gluemap
                 l.gluemap &= !aggr_bits;
                 r.gluemap &= !aggr_bits;
+                l.recount();
+                r.recount();
                 // set them in self.gluemap
                 self.gluemap |= aggr_bits;
+                self.recount();
                 // check whether any aggregation occurred
                 if aggr_bits == GlueMap::ZERO {
                     // no aggregation occurred, so self may now be unnecessary glue.
@@ -225,14 +331,17 @@ impl<A: Afi> Node<A> {
                     // cannot be a glue node.
                     if let Some(child) = self.left.take() {
                         self.left = child.clean();
-                    };
+                    }
                     if let Some(child) = self.right.take() {
                         self.right = child.clean();
-                    };
+                    }
                     Some(self)
                 }
             }
-            _ => self.clean(),
+            _ => {
+                self.recount();
+                self.clean()
+            }
         }
     }
 
@@ -256,7 +365,7 @@ impl<A: Afi> Node<A> {
             {
                 Some(self)
             }
-            PrefixOrdering::Subprefix(common) => match qnode.branch_direction(&common) {
+            PrefixOrdering::Subprefix(common) => match qnode.branch_direction(common.length()) {
                 Direction::Left => self.left.as_ref().and_then(|child| child.search(qnode)),
                 Direction::Right => self.right.as_ref().and_then(|child| child.search(qnode)),
             },
@@ -264,6 +373,216 @@ impl<A: Afi> Node<A> {
         }
     }
 
+    // Descend from `cursor` towards `prefix`, accumulating the gluemaps of
+    // the nodes whose prefix contains `prefix`.
+    //
+    // Returns the node at which the descent stopped, from which the search
+    // may be resumed for subprefixes of `prefix`, and the accumulated
+    // gluemap.
+    fn descend<'a>(
+        mut cursor: Option<&'a Self>,
+        prefix: &Prefix<A>,
+        mut covered: GlueMap<A>,
+    ) -> (Option<&'a Self>, GlueMap<A>) {
+        while let Some(node) = cursor {
+            match node.prefix().compare(prefix) {
+                PrefixOrdering::Equal => {
+                    covered |= node.gluemap;
+                    break;
+                }
+                PrefixOrdering::Subprefix(common) => {
+                    covered |= node.gluemap;
+                    cursor = match branch_direction(prefix, common.length()) {
+                        Direction::Left => node.left.as_deref(),
+                        Direction::Right => node.right.as_deref(),
+                    };
+                }
+                _ => break,
+            }
+        }
+        (cursor, covered)
+    }
+
+    // Returns `true` if every prefix represented by `self` and its
+    // descendants is also represented by the tree at `other`.
+    //
+    // This relies on `other` being aggregated, so that if all the
+    // subprefixes of some node's prefix at a given length are present, then
+    // they are represented in the gluemap of a single node containing that
+    // prefix.
+    pub fn is_subset(&self, other: Option<&Self>) -> bool {
+        self.is_subset_within(other, GlueMap::ZERO)
+    }
+
+    fn is_subset_within(&self, other: Option<&Self>, covered: GlueMap<A>) -> bool {
+        let (cursor, covered) = Self::descend(other, self.prefix(), covered);
+        self.gluemap & !covered == GlueMap::ZERO
+            && [&self.left, &self.right]
+                .into_iter()
+                .flatten()
+                .all(|child| child.is_subset_within(cursor, covered))
+    }
+
+    // Returns `true` if no prefix is represented by both `self` and `other`.
+    //
+    // A prefix common to both trees is represented by a node in each, one of
+    // which must contain the other, so it is sufficient to check each node
+    // against the nodes in the other tree that contain it.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.is_disjoint_within(Some(other), GlueMap::ZERO)
+            && other.is_disjoint_within(Some(self), GlueMap::ZERO)
+    }
+
+    fn is_disjoint_within(&self, other: Option<&Self>, covered: GlueMap<A>) -> bool {
+        let (cursor, covered) = Self::descend(other, self.prefix(), covered);
+        self.gluemap & covered == GlueMap::ZERO
+            && [&self.left, &self.right]
+                .into_iter()
+                .flatten()
+                .all(|child| child.is_disjoint_within(cursor, covered))
+    }
+
+    // Descend from `self` towards `cover`, returning the ranges of prefixes
+    // covered by `cover` that are represented by nodes strictly containing
+    // `cover`, and the first node (if any) contained by `cover`, all of whose
+    // descendants are then also covered.
+    pub fn within(&self, cover: &Prefix<A>) -> (Ranges<A>, Option<&Self>) {
+        let lengths = GlueMap::from_length(cover.length());
+        let mut straddling = GlueMap::ZERO;
+        let mut cursor = Some(self);
+        while let Some(node) = cursor {
+            match node.prefix().compare(cover) {
+                PrefixOrdering::Equal | PrefixOrdering::Superprefix(_) => break,
+                PrefixOrdering::Subprefix(common) => {
+                    straddling |= node.gluemap & lengths;
+                    cursor = match branch_direction(cover, common.length()) {
+                        Direction::Left => node.left.as_deref(),
+                        Direction::Right => node.right.as_deref(),
+                    };
+                }
+                PrefixOrdering::Divergent(_) => cursor = None,
+            }
+        }
+        (Ranges::new(*cover, straddling), cursor)
+    }
+
+    pub fn covers(&self, query: &Prefix<A>) -> bool {
+        self.prefix().contains(query)
+            && (self.gluemap.last_upto(query.length()).is_some()
+                || self.left.as_ref().is_some_and(|child| child.covers(query))
+                || self.right.as_ref().is_some_and(|child| child.covers(query)))
+    }
+
+    // Returns `true` if every address within `query` is covered by some
+    // prefix represented by `self` or its descendants.
+    //
+    // A non-glue node covers every address within its own prefix, so the
+    // walk need only continue through glue nodes.
+    pub fn covers_addresses(&self, query: &Prefix<A>) -> bool {
+        match self.prefix().compare(query) {
+            PrefixOrdering::Equal | PrefixOrdering::Subprefix(_) if !self.is_glue() => true,
+            PrefixOrdering::Subprefix(common) => match branch_direction(query, common.length()) {
+                Direction::Left => self
+                    .left
+                    .as_ref()
+                    .is_some_and(|child| child.covers_addresses(query)),
+                Direction::Right => self
+                    .right
+                    .as_ref()
+                    .is_some_and(|child| child.covers_addresses(query)),
+            },
+            PrefixOrdering::Equal => match (&self.left, &self.right, query.length().increment()) {
+                (Some(left), Some(right), Ok(length)) => {
+                    left.covers_addresses(&Prefix::new(query.prefix(), length))
+                        && right.covers_addresses(&Prefix::new(query.broadcast(), length))
+                }
+                _ => false,
+            },
+            PrefixOrdering::Superprefix(_) | PrefixOrdering::Divergent(_) => false,
+        }
+    }
+
+    // Collect the prefixes of the outermost non-glue nodes within `query`,
+    // whose union is the set of addresses within `query` covered by `self`
+    // and its descendants. If some node containing `query` covers it
+    // entirely, then `query` itself is collected instead.
+    pub fn covered_within(&self, query: &Prefix<A>, bases: &mut Vec<Prefix<A>>) {
+        match self.prefix().compare(query) {
+            PrefixOrdering::Equal | PrefixOrdering::Subprefix(_) if !self.is_glue() => {
+                bases.push(*query);
+            }
+            PrefixOrdering::Subprefix(common) => {
+                let child = match branch_direction(query, common.length()) {
+                    Direction::Left => &self.left,
+                    Direction::Right => &self.right,
+                };
+                if let Some(child) = child {
+                    child.covered_within(query, bases);
+                }
+            }
+            PrefixOrdering::Superprefix(_) if !self.is_glue() => bases.push(*self.prefix()),
+            PrefixOrdering::Equal | PrefixOrdering::Superprefix(_) => {
+                [&self.left, &self.right]
+                    .into_iter()
+                    .flatten()
+                    .for_each(|child| child.covered_within(query, bases));
+            }
+            PrefixOrdering::Divergent(_) => (),
+        }
+    }
+
+    pub fn longest_match(&self, query: &Prefix<A>) -> Option<PrefixLength<A>> {
+        if self.prefix().contains(query) {
+            let here = self.gluemap.last_upto(query.length());
+            let below = self
+                .left
+                .as_ref()
+                .and_then(|child| child.longest_match(query))
+                .or_else(|| {
+                    self.right
+                        .as_ref()
+                        .and_then(|child| child.longest_match(query))
+                });
+            here.max(below)
+        } else {
+            None
+        }
+    }
+
+    // The member prefix with the lowest network address, breaking ties in
+    // favour of the shortest prefix.
+    pub fn first(&self) -> Option<Prefix<A>> {
+        let here = self
+            .gluemap
+            .next_range(PrefixLength::MIN)
+            .map(|lengths| Prefix::new(self.prefix().prefix(), *lengths.start()));
+        let below = self
+            .left
+            .as_ref()
+            .or(self.right.as_ref())
+            .and_then(|child| child.first());
+        here.into_iter()
+            .chain(below)
+            .min_by_key(|prefix| (prefix.prefix(), prefix.length()))
+    }
+
+    // The member prefix with the highest network address, breaking ties in
+    // favour of the longest prefix.
+    pub fn last(&self) -> Option<Prefix<A>> {
+        let here = self
+            .gluemap
+            .last_upto(PrefixLength::MAX)
+            .map(|length| Prefix::new(self.prefix().broadcast(), length));
+        let below = self
+            .right
+            .as_ref()
+            .or(self.left.as_ref())
+            .and_then(|child| child.last());
+        here.into_iter()
+            .chain(below)
+            .max_by_key(|prefix| (prefix.prefix(), prefix.length()))
+    }
+
     fn intersect_nodes(&self, qnode: &Self) -> Option<Box<Self>> {
         match self.prefix().compare(qnode.prefix()) {
             PrefixOrdering::Divergent(_) => None,
@@ -277,41 +596,51 @@ impl<A: Afi> Node<A> {
                 if let Some(child) = &self.left {
                     if let Some(intersect_child) = child.intersect_nodes(qnode) {
                         new = new.add(intersect_child);
-                    };
-                };
+                    }
+                }
                 if let Some(child) = &self.right {
                     if let Some(intersect_child) = child.intersect_nodes(qnode) {
                         new = new.add(intersect_child);
-                    };
-                };
+                    }
+                }
                 Some(new)
             }
         }
     }
 
-    fn branch_direction(&self, from: &Prefix<A>) -> Direction {
-        let mask = Hostmask::from(from.length())
-            & Netmask::from(
-                from.length()
-                    .increment()
-                    // ok to unwrap, because `from.length() < MAX_LENGTH`.
-                    .unwrap(),
-            );
-        if (self.prefix().network() & mask).is_unspecified() {
-            Direction::Left
-        } else {
-            Direction::Right
-        }
+    fn branch_direction(&self, at: PrefixLength<A>) -> Direction {
+        branch_direction(self.prefix(), at)
     }
 
-    pub fn ranges(&self) -> Ranges<'_, A> {
+    pub fn ranges(&self) -> Ranges<A> {
         self.into()
     }
 
     pub fn children(&self) -> Children<'_, A> {
         self.into()
     }
+
+    pub fn into_children(self: Box<Self>) -> IntoChildren<A> {
+        self.into()
+    }
 }
 
 #[cfg(test)]
 mod tests;
+
+pub(in crate::concrete::prefix) fn branch_direction<A: Afi>(
+    prefix: &Prefix<A>,
+    at: PrefixLength<A>,
+) -> Direction {
+    let mask = Hostmask::from(at)
+        & Netmask::from(
+            at.increment()
+                // ok to unwrap, because `at < MAX_LENGTH`.
+                .unwrap(),
+        );
+    if (prefix.network() & mask).is_unspecified() {
+        Direction::Left
+    } else {
+        Direction::Right
+    }
+}
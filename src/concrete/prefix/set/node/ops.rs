@@ -13,6 +13,14 @@ impl<A: Afi> PartialEq for Node<A> {
 impl<A: Afi> BitAnd for Box<Node<A>> {
     type Output = Option<Self>;
 
+    fn bitand(self, rhs: Self) -> Self::Output {
+        &*self & &*rhs
+    }
+}
+
+impl<A: Afi> BitAnd for &Node<A> {
+    type Output = Option<Box<Node<A>>>;
+
     fn bitand(self, rhs: Self) -> Self::Output {
         self.children()
             .fold(None, |root, node| match rhs.intersect_nodes(node) {
@@ -36,10 +44,27 @@ impl<A: Afi> BitOr for Box<Node<A>> {
     }
 }
 
+impl<A: Afi> BitOr for &Node<A> {
+    type Output = Option<Box<Node<A>>>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Some(self.union(rhs))
+    }
+}
+
 impl<A: Afi> Sub for Box<Node<A>> {
     type Output = Option<Self>;
 
-    fn sub(self, mut rhs: Self) -> Self::Output {
-        Some(self.remove(&mut rhs))
+    fn sub(self, rhs: Self) -> Self::Output {
+        Some(self.remove(&rhs))
+    }
+}
+
+// The copy of `self` is consumed by the removal, while `rhs` is only walked.
+impl<A: Afi> Sub for &Node<A> {
+    type Output = Option<Box<Node<A>>>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Some(self.clone().boxed().remove(rhs))
     }
 }
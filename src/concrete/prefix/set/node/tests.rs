@@ -36,12 +36,18 @@ mod subtree_of_three_prefixes {
         let n = setup();
         assert!(is_glue(&n));
         let (l, r) = (n.left.unwrap(), n.right.unwrap());
-        assert_eq!(l.gluemap, GlueMap::singleton(16.try_into()?));
+        assert_eq!(l.gluemap, GlueMap::singleton(16u8.try_into()?));
         assert!(l.left.is_none());
         assert!(l.right.is_none());
         assert!(is_glue(&r));
-        assert_eq!(r.left.unwrap().gluemap, GlueMap::singleton(16.try_into()?));
-        assert_eq!(r.right.unwrap().gluemap, GlueMap::singleton(16.try_into()?));
+        assert_eq!(
+            r.left.unwrap().gluemap,
+            GlueMap::singleton(16u8.try_into()?)
+        );
+        assert_eq!(
+            r.right.unwrap().gluemap,
+            GlueMap::singleton(16u8.try_into()?)
+        );
         Ok(())
     }
 
@@ -57,10 +63,10 @@ mod subtree_of_three_prefixes {
             let n = setup();
             assert!(is_glue(&n));
             let (l, r) = (n.left.unwrap(), n.right.unwrap());
-            assert_eq!(l.gluemap, GlueMap::singleton(16.try_into()?));
+            assert_eq!(l.gluemap, GlueMap::singleton(16u8.try_into()?));
             assert!(l.left.is_none());
             assert!(l.right.is_none());
-            assert_eq!(r.gluemap, GlueMap::singleton(16.try_into()?));
+            assert_eq!(r.gluemap, GlueMap::singleton(16u8.try_into()?));
             assert!(r.left.is_none());
             assert!(r.right.is_none());
             Ok(())
@@ -91,7 +97,7 @@ mod new_ipv4_singleton {
     #[test]
     fn becomes_glue_after_removal() {
         let n = setup();
-        assert!(is_glue(&n.remove(&mut "192.0.2.0/24".parse().unwrap())));
+        assert!(is_glue(&n.remove(&"192.0.2.0/24".parse().unwrap())));
     }
 
     mod added_with_self {
@@ -154,7 +160,7 @@ mod new_ipv4_singleton {
         #[test]
         fn becomes_glue_after_removal() {
             let n = setup();
-            assert!(is_glue(&n.remove(&mut "192.0.2.0/24".parse().unwrap())));
+            assert!(is_glue(&n.remove(&"192.0.2.0/24".parse().unwrap())));
         }
     }
 
@@ -230,7 +236,7 @@ mod new_ipv4_singleton {
         #[test]
         fn is_unchanged_after_subprefix_removal() {
             let n = setup();
-            let m = n.clone().remove(&mut "192.0.2.0/24".parse().unwrap());
+            let m = n.clone().remove(&"192.0.2.0/24".parse().unwrap());
             assert_eq!(m, n);
         }
     }
@@ -291,7 +297,7 @@ mod new_ipv4_singleton {
             #[test]
             fn is_aggregate() -> TestResult {
                 let n = setup();
-                assert_eq!(n.gluemap, GlueMap::singleton(24.try_into()?));
+                assert_eq!(n.gluemap, GlueMap::singleton(24u8.try_into()?));
                 Ok(())
             }
 
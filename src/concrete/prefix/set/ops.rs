@@ -1,5 +1,9 @@
 use core::cmp::Ordering;
-use core::ops::{Add, BitAnd, BitOr, BitXor, Mul, Not, Sub};
+use core::hash::{Hash, Hasher};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign,
+    Not, Sub, SubAssign,
+};
 
 use num_traits::{One, Zero};
 
@@ -12,7 +16,7 @@ impl<A: Afi> Zero for Set<A> {
     }
 
     fn is_zero(&self) -> bool {
-        self.root.is_some()
+        self.is_empty()
     }
 }
 
@@ -27,34 +31,51 @@ impl<A: Afi> One for Set<A> {
 impl<A: Afi> BitAnd for Set<A> {
     type Output = Self;
 
-    fn bitand(self, rhs: Self) -> Self::Output {
-        match (self.root, rhs.root) {
-            (Some(r), Some(s)) => Self::Output::new_with_root(r & s).aggregate().clone(),
-            _ => Self::Output::zero(),
-        }
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+
+impl<A: Afi> BitAndAssign for Set<A> {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.root = match (self.root.take(), rhs.root) {
+            (Some(r), Some(s)) => r & s,
+            _ => None,
+        };
+        _ = self.aggregate();
     }
 }
 
 impl<A: Afi> BitOr for Set<A> {
     type Output = Self;
 
-    fn bitor(self, rhs: Self) -> Self::Output {
-        match (&self.root, &rhs.root) {
-            (Some(r), Some(s)) => Self::Output::new_with_root(r.clone() | s.clone())
-                .aggregate()
-                .clone(),
-            (Some(_), None) => self,
-            (None, Some(_)) => rhs,
-            (None, None) => Self::Output::zero(),
-        }
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl<A: Afi> BitOrAssign for Set<A> {
+    fn bitor_assign(&mut self, rhs: Self) {
+        _ = self.append(rhs);
     }
 }
 
 impl<A: Afi> BitXor for Set<A> {
     type Output = Self;
 
-    fn bitxor(self, rhs: Self) -> Self::Output {
-        (self.clone() | rhs.clone()) - (self & rhs)
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+
+impl<A: Afi> BitXorAssign for Set<A> {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        let both = &*self & &rhs;
+        *self |= rhs;
+        *self -= both;
     }
 }
 
@@ -75,15 +96,30 @@ impl<A: Afi> Add for Set<A> {
     }
 }
 
+impl<A: Afi> AddAssign for Set<A> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self |= rhs;
+    }
+}
+
 impl<A: Afi> Sub for Set<A> {
     type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        match (&self.root, &rhs.root) {
-            (Some(r), Some(s)) => Self::Output::new_with_root(r.clone() - s.clone())
-                .aggregate()
-                .clone(),
-            _ => self,
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<A: Afi> SubAssign for Set<A> {
+    fn sub_assign(&mut self, rhs: Self) {
+        match (self.root.take(), rhs.root) {
+            (Some(r), Some(s)) => {
+                self.root = r - s;
+                _ = self.aggregate();
+            }
+            (r, _) => self.root = r,
         }
     }
 }
@@ -97,13 +133,67 @@ impl<A: Afi> Mul for Set<A> {
     }
 }
 
+impl<A: Afi> MulAssign for Set<A> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self &= rhs;
+    }
+}
+
+impl<A: Afi> BitAnd for &Set<A> {
+    type Output = Set<A>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match (&self.root, &rhs.root) {
+            (Some(r), Some(s)) => Set::aggregated(&**r & &**s),
+            _ => Set::new(),
+        }
+    }
+}
+
+impl<A: Afi> BitOr for &Set<A> {
+    type Output = Set<A>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        match (&self.root, &rhs.root) {
+            (Some(r), Some(s)) => Set::aggregated(&**r | &**s),
+            (Some(_), None) => self.clone(),
+            (None, _) => rhs.clone(),
+        }
+    }
+}
+
+impl<A: Afi> BitXor for &Set<A> {
+    type Output = Set<A>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        let mut set = self | rhs;
+        set -= self & rhs;
+        set
+    }
+}
+
+impl<A: Afi> Sub for &Set<A> {
+    type Output = Set<A>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        match (&self.root, &rhs.root) {
+            (Some(r), Some(s)) => Set::aggregated(&**r - &**s),
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Two sets are equal if they contain exactly the same prefixes.
+///
+/// Every public mutation of a set finishes by aggregating its contents into
+/// a canonical form, which depends only on the prefixes that the set
+/// contains and not on the sequence of operations used to construct it.
+/// Equality is decided by comparing the canonical sequences of ranges
+/// yielded by [`PrefixSet::ranges()`][Set::ranges].
 impl<A: Afi> PartialEq for Set<A> {
     fn eq(&self, other: &Self) -> bool {
-        match (&self.root, &other.root) {
-            (Some(r), Some(s)) => r.children().zip(s.children()).all(|(m, n)| m == n),
-            (None, None) => true,
-            _ => false,
-        }
+        self.ranges().eq(other.ranges())
     }
 }
 
@@ -111,9 +201,9 @@ impl<A: Afi> PartialOrd for Set<A> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         if self == other {
             Some(Ordering::Equal)
-        } else if self.prefixes().all(|p| other.contains(p)) {
+        } else if self.is_subset(other) {
             Some(Ordering::Less)
-        } else if other.prefixes().all(|p| self.contains(p)) {
+        } else if self.is_superset(other) {
             Some(Ordering::Greater)
         } else {
             None
@@ -123,48 +213,57 @@ impl<A: Afi> PartialOrd for Set<A> {
 
 impl<A: Afi> Eq for Set<A> {}
 
+/// Hash the canonical sequence of ranges yielded by
+/// [`PrefixSet::ranges()`][Set::ranges], so that sets that compare equal
+/// always hash identically.
+///
+/// # Examples
+///
+/// ``` rust
+/// use std::collections::HashMap;
+///
+/// use ip::{Error, Ipv4, Prefix, PrefixSet};
+///
+/// let prefixes = ["192.0.2.0/25", "192.0.2.128/25", "192.0.2.0/24"]
+///     .into_iter()
+///     .map(str::parse::<Prefix<Ipv4>>)
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// let mut memo = HashMap::new();
+/// memo.insert(PrefixSet::<Ipv4>::from_iter(&prefixes), "computed");
+///
+/// let reversed = PrefixSet::<Ipv4>::from_iter(prefixes.iter().rev());
+/// assert_eq!(memo.get(&reversed), Some(&"computed"));
+/// # Ok::<_, Error>(())
+/// ```
+impl<A: Afi> Hash for Set<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let count = self.ranges().fold(0usize, |count, range| {
+            range.hash(state);
+            count + 1
+        });
+        count.hash(state);
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use core::str::FromStr;
     use std::{dbg, vec};
 
     use paste::paste;
 
-    use super::super::Node;
     use super::*;
-    use crate::{
-        concrete::{Prefix, PrefixRange},
-        error::{Error, TestResult},
-        Ipv4, Ipv6,
-    };
+    use crate::{error::TestResult, Ipv4, Ipv6};
 
     impl<A: Afi> FromIterator<&'static str> for Set<A> {
         fn from_iter<T: IntoIterator<Item = &'static str>>(iter: T) -> Self {
-            enum Insertable<A: Afi> {
-                Prefix(Prefix<A>),
-                Range(PrefixRange<A>),
-            }
-            impl<A: Afi> FromStr for Insertable<A> {
-                type Err = Error;
-                fn from_str(s: &str) -> Result<Self, Self::Err> {
-                    s.parse()
-                        .map(Insertable::Range)
-                        .or_else(|_| s.parse().map(Insertable::Prefix))
-                }
-            }
-            #[allow(clippy::from_over_into)]
-            impl<A: Afi> Into<Node<A>> for Insertable<A> {
-                fn into(self) -> Node<A> {
-                    match self {
-                        Self::Prefix(prefix) => prefix.into(),
-                        Self::Range(range) => range.into(),
-                    }
-                }
-            }
-            iter.into_iter()
-                .map(Insertable::from_str)
-                .collect::<Result<_, _>>()
-                .unwrap()
+            iter.into_iter().map(|s| s.parse::<Self>().unwrap()).fold(
+                Self::new(),
+                |mut set, other| {
+                    _ = set.append(other);
+                    set
+                },
+            )
         }
     }
 
@@ -254,6 +353,18 @@ mod tests {
         assert_eq!(Set::<Ipv6>::zero().prefixes().count(), 0);
     }
 
+    #[test]
+    fn ipv4_zero_set_is_zero() {
+        assert!(Set::<Ipv4>::zero().is_zero());
+        assert!(!Set::<Ipv4>::one().is_zero());
+    }
+
+    #[test]
+    fn ipv6_zero_set_is_zero() {
+        assert!(Set::<Ipv6>::zero().is_zero());
+        assert!(!Set::<Ipv6>::one().is_zero());
+    }
+
     test_unary_op!(!zero == one, !one == zero);
 
     test_binary_op!(
@@ -470,7 +581,17 @@ mod tests {
         xor_overlapping_set_with_covered_child {
             vec!["1.0.0.0/8", "1.0.0.0/16"].into_iter().collect::<Set<_>>()
                 ^ vec!["1.0.0.0/8,16,16"].into_iter().collect(),
-            vec!["1.0.0.0/8"].into_iter().collect()
+            vec![
+                "1.0.0.0/8",
+                "1.1.0.0/16",
+                "1.2.0.0/15,16,16",
+                "1.4.0.0/14,16,16",
+                "1.8.0.0/13,16,16",
+                "1.16.0.0/12,16,16",
+                "1.32.0.0/11,16,16",
+                "1.64.0.0/10,16,16",
+                "1.128.0.0/9,16,16",
+            ].into_iter().collect()
         };
         sub_disjoint_nodes {
             vec!["2.0.0.0/8,8,16"].into_iter().collect::<Set<_>>()
@@ -534,17 +655,7 @@ mod tests {
         sub_overlapping_set_with_covered_child {
             vec!["1.0.0.0/8", "1.0.0.0/16"].into_iter().collect::<Set<_>>()
                 - vec!["1.0.0.0/8,16,16"].into_iter().collect(),
-            vec![
-                "1.0.0.0/8",
-                "1.1.0.0/16",
-                "1.2.0.0/15,16,16",
-                "1.4.0.0/14,16,16",
-                "1.8.0.0/13,16,16",
-                "1.16.0.0/12,16,16",
-                "1.32.0.0/11,16,16",
-                "1.64.0.0/10,16,16",
-                "1.128.0.0/9,16,16",
-            ].into_iter().collect()
+            vec!["1.0.0.0/8"].into_iter().collect()
         };
         sub_complex_deaggregation {
             vec!["2.0.0.0/8,8,10", "3.0.0.0/8,8,9"].into_iter().collect::<Set<_>>()
@@ -571,6 +682,48 @@ mod tests {
                 "128.0.0.0/1,8,8"
             ].into_iter().collect()
         };
+        complement_within_singleton {
+            vec!["10.1.0.0/16", "192.0.2.0/24"].into_iter().collect::<Set<_>>()
+                .complement_within("10.0.0.0/8".parse()?),
+            vec![
+                "10.0.0.0/8,8,15",
+                "10.0.0.0/8,17,32",
+                "10.0.0.0/16",
+                "10.2.0.0/15,16,16",
+                "10.4.0.0/14,16,16",
+                "10.8.0.0/13,16,16",
+                "10.16.0.0/12,16,16",
+                "10.32.0.0/11,16,16",
+                "10.64.0.0/10,16,16",
+                "10.128.0.0/9,16,16"
+            ].into_iter().collect()
+        };
+        complement_within_range {
+            vec!["10.1.0.0/16,16,24"].into_iter().collect::<Set<_>>()
+                .complement_within("10.0.0.0/8".parse()?),
+            vec![
+                "10.0.0.0/8,8,15",
+                "10.0.0.0/8,25,32",
+                "10.0.0.0/16,16,24",
+                "10.2.0.0/15,16,24",
+                "10.4.0.0/14,16,24",
+                "10.8.0.0/13,16,24",
+                "10.16.0.0/12,16,24",
+                "10.32.0.0/11,16,24",
+                "10.64.0.0/10,16,24",
+                "10.128.0.0/9,16,24",
+            ].into_iter().collect()
+        };
+        complement_within_covering_set {
+            vec!["0.0.0.0/0,0,32"].into_iter().collect::<Set<_>>()
+                .complement_within("10.0.0.0/8".parse()?),
+            Set::zero()
+        };
+        complement_within_disjoint_set {
+            vec!["11.0.0.0/8,8,32"].into_iter().collect::<Set<_>>()
+                .complement_within("10.0.0.0/8".parse()?),
+            vec!["10.0.0.0/8,8,32"].into_iter().collect()
+        };
         not_range {
             ! vec!["1.0.0.0/8,8,16"].into_iter().collect::<Set<_>>(),
             vec![
@@ -0,0 +1,193 @@
+use core::str::FromStr;
+
+use super::{Node, Set};
+use crate::{
+    any,
+    concrete::{Prefix, PrefixRange},
+    error::{err, Error, Kind},
+    traits::Afi,
+};
+
+/// Iterator over the items of a whitespace and/or comma separated list of
+/// prefixes and prefix ranges, yielding the byte offset and text of each.
+///
+/// Since the `prefix,lower,upper` syntax of a prefix range itself contains
+/// commas, a pair of comma-joined integers immediately following an item is
+/// taken to be part of that item.
+#[derive(Clone, Debug)]
+pub(crate) struct ListItems<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> ListItems<'a> {
+    pub(crate) const fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    const fn is_separator(c: char) -> bool {
+        c.is_whitespace() || c == ','
+    }
+
+    // Returns the length of a `,lower,upper` suffix at the start of `s`, if
+    // there is one.
+    fn length_bounds(s: &str) -> Option<usize> {
+        let mut len = 0;
+        for _ in 0..2 {
+            let rest = s[len..].strip_prefix(',')?;
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits == 0 {
+                return None;
+            }
+            len += 1 + digits;
+        }
+        s[len..]
+            .chars()
+            .next()
+            .is_none_or(Self::is_separator)
+            .then_some(len)
+    }
+}
+
+impl<'a> Iterator for ListItems<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.pos..];
+        let start = self.input.len() - rest.trim_start_matches(Self::is_separator).len();
+        if start == self.input.len() {
+            self.pos = start;
+            return None;
+        }
+        let mut end = self.input[start..]
+            .find(Self::is_separator)
+            .map_or(self.input.len(), |len| start + len);
+        if let Some(len) = Self::length_bounds(&self.input[end..]) {
+            end += len;
+        }
+        self.pos = end;
+        Some((start, &self.input[start..end]))
+    }
+}
+
+/// Parse a [`PrefixSet<A>`][Set] from a list of prefixes and prefix ranges,
+/// separated by whitespace and/or commas.
+///
+/// Each item may be either a [`Prefix<A>`] or a [`PrefixRange<A>`] in
+/// `prefix,lower,upper` form.
+///
+/// # Errors
+///
+/// Parsing fails on the first item that is not a valid prefix or prefix
+/// range, with the [`position()`][Error::position] of the returned error
+/// giving the byte offset into the input at which the failure occurred.
+///
+/// An error of kind [`AfiMismatch`][Kind::AfiMismatch] is returned for an
+/// item that is valid for the other address family.
+///
+/// # Examples
+///
+/// ``` rust
+/// use ip::{error::Kind, traits::PrefixSet as _, Ipv4, PrefixSet};
+///
+/// let set = "192.0.2.0/24 10.0.0.0/8,8,10, 198.51.100.0/24".parse::<PrefixSet<Ipv4>>()?;
+/// assert_eq!(set.len(), 2 + 1 + 2 + 4);
+///
+/// let err = "192.0.2.0/24, 2001:db8::/32".parse::<PrefixSet<Ipv4>>().unwrap_err();
+/// assert_eq!(err.kind(), Kind::AfiMismatch);
+/// assert_eq!(err.position(), Some(14));
+///
+/// let err = "192.0.2.0/24 192.0.2.0/33".parse::<PrefixSet<Ipv4>>().unwrap_err();
+/// assert_eq!(err.kind(), Kind::PrefixLength);
+/// assert_eq!(err.position(), Some(13));
+/// # Ok::<(), ip::Error>(())
+/// ```
+impl<A: Afi> FromStr for Set<A> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = Self::new();
+        for (offset, item) in ListItems::new(s) {
+            let node = if item.contains(',') {
+                item.parse::<PrefixRange<A>>()
+                    .map(Node::from)
+                    .map_err(|err| {
+                        item.parse::<any::PrefixRange>()
+                            .map_or(err, |_| err!(Kind::AfiMismatch))
+                    })
+            } else {
+                item.parse::<Prefix<A>>().map(Node::from).map_err(|err| {
+                    item.parse::<any::Prefix>()
+                        .map_or(err, |_| err!(Kind::AfiMismatch))
+                })
+            }
+            .map_err(|err| err.at(offset + err.position().unwrap_or_default()))?;
+            _ = set.insert_only(node);
+        }
+        _ = set.aggregate();
+        Ok(set)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::{error::TestResult, Ipv4, Ipv6};
+
+    fn items(s: &str) -> Vec<(usize, &str)> {
+        ListItems::new(s).collect()
+    }
+
+    #[test]
+    fn split_items() {
+        assert_eq!(items(""), []);
+        assert_eq!(items(" ,\t"), []);
+        assert_eq!(
+            items("192.0.2.0/24 10.0.0.0/8,16,24"),
+            [(0, "192.0.2.0/24"), (13, "10.0.0.0/8,16,24")],
+        );
+        assert_eq!(
+            items("10.0.0.0/8,16,24,192.0.2.0/24,"),
+            [(0, "10.0.0.0/8,16,24"), (17, "192.0.2.0/24")],
+        );
+        assert_eq!(
+            items("\n2001:db8::/32, ::/0,0,0\n"),
+            [(1, "2001:db8::/32"), (16, "::/0,0,0")],
+        );
+    }
+
+    #[test]
+    fn incomplete_bounds_are_separate_items() {
+        assert_eq!(
+            items("10.0.0.0/8,16 24"),
+            [(0, "10.0.0.0/8"), (11, "16"), (14, "24")],
+        );
+        assert_eq!(
+            items("10.0.0.0/8,16,24x"),
+            [(0, "10.0.0.0/8"), (11, "16"), (14, "24x")],
+        );
+    }
+
+    #[test]
+    fn parse_matches_insert() -> TestResult {
+        let parsed = "2001:db8::/32,48,48 2001:db8:f00::/40".parse::<Set<Ipv6>>()?;
+        let mut expected = Set::new();
+        _ = expected.insert("2001:db8::/32,48,48".parse::<PrefixRange<Ipv6>>()?);
+        _ = expected.insert("2001:db8:f00::/40".parse::<Prefix<Ipv6>>()?);
+        assert_eq!(parsed, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = |s: &str| s.parse::<Set<Ipv4>>().unwrap_err();
+        assert_eq!(err("10.0.0.0/8,16").kind(), Kind::ParserError);
+        assert_eq!(err("10.0.0.0/8,16").position(), Some(13));
+        assert_eq!(err("192.0.2.0/24 ::/0,0,128").kind(), Kind::AfiMismatch);
+        assert_eq!(err("192.0.2.0/24 ::/0,0,128").position(), Some(13));
+        assert_eq!(err("192.0.2.0/24 10.0.0.256").kind(), Kind::ParserError);
+        assert_eq!(err("192.0.2.0/24 10.0.0.256").position(), Some(20));
+    }
+}
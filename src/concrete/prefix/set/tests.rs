@@ -3,7 +3,7 @@ use std::{dbg, vec};
 
 use super::Set;
 use crate::{
-    concrete::{Prefix, PrefixRange},
+    concrete::{Address, AddressRange, Prefix, PrefixLength, PrefixRange},
     error::TestResult,
     traits::PrefixSet as _,
     Ipv4,
@@ -245,3 +245,1684 @@ mod new_ipv4_prefix_set {
         }
     }
 }
+
+mod change_detection {
+    use proptest::{arbitrary::any, proptest};
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    fn setup() -> Set<Ipv4> {
+        let r: PrefixRange<Ipv4> = "192.0.2.0/24,25,25".parse().unwrap();
+        Set::new().insert(r).clone()
+    }
+
+    #[test]
+    fn novel_insert_is_changed() -> TestResult {
+        let mut s = setup();
+        assert!(s.insert_changed("198.51.100.0/24".parse::<Prefix<Ipv4>>()?));
+        assert!(s.contains("198.51.100.0/24".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn covered_prefix_insert_is_unchanged() -> TestResult {
+        let mut s = setup();
+        let before = s.clone();
+        assert!(!s.insert_changed("192.0.2.128/25".parse::<Prefix<Ipv4>>()?));
+        assert_eq!(s, before);
+        Ok(())
+    }
+
+    #[test]
+    fn covered_range_insert_is_unchanged() -> TestResult {
+        let mut s = setup();
+        let before = s.clone();
+        assert!(!s.insert_changed("192.0.2.0/24,25,25".parse::<PrefixRange<Ipv4>>()?));
+        assert_eq!(s, before);
+        Ok(())
+    }
+
+    #[test]
+    fn partially_covered_range_insert_is_changed() -> TestResult {
+        let mut s = setup();
+        assert!(s.insert_changed("192.0.2.0/24,24,25".parse::<PrefixRange<Ipv4>>()?));
+        assert_eq!(s.prefixes().count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn covering_prefix_insert_is_changed_without_new_addresses() -> TestResult {
+        let mut s = setup();
+        let addresses = s.address_count();
+        assert!(s.insert_changed("192.0.2.0/24".parse::<Prefix<Ipv4>>()?));
+        assert_eq!(s.prefixes().count(), 3);
+        assert_eq!(s.address_count(), addresses);
+        Ok(())
+    }
+
+    #[test]
+    fn covered_prefix_insert_is_not_reported() -> TestResult {
+        let mut s = setup();
+        assert!(!s.insert_reporting("192.0.2.0/24".parse::<Prefix<Ipv4>>()?));
+        assert!(s.contains("192.0.2.0/24".parse()?));
+        assert!(s.insert_reporting("198.51.100.0/24".parse::<Prefix<Ipv4>>()?));
+        Ok(())
+    }
+
+    #[test]
+    fn partially_present_range_remove_is_reported() -> TestResult {
+        let mut s = setup();
+        assert!(s.remove_reporting("192.0.2.0/25,25,26".parse::<PrefixRange<Ipv4>>()?));
+        assert_eq!(s.address_count(), 128);
+        assert!(!s.remove_reporting("192.0.2.0/25".parse::<Prefix<Ipv4>>()?));
+        Ok(())
+    }
+
+    #[test]
+    fn last_address_is_reported() -> TestResult {
+        let last = "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128".parse::<Prefix<crate::Ipv6>>()?;
+        // the sibling of each prefix on the path from the root to `last`
+        let mut s = (1..=128u8)
+            .map(|length| {
+                let addr =
+                    Address::new(u128::MAX.checked_shl(129 - u32::from(length)).unwrap_or(0));
+                PrefixLength::from_primitive(length).map(|length| Prefix::new(addr, length))
+            })
+            .collect::<Result<Set<_>, _>>()?;
+        assert_eq!(s.address_count(), u128::MAX);
+        assert!(s.insert_reporting(last));
+        assert!(s.remove_reporting(last));
+        assert!(!s.remove_reporting(last));
+        Ok(())
+    }
+
+    #[test]
+    fn present_remove_is_changed() -> TestResult {
+        let mut s = setup();
+        assert!(s.remove_changed("192.0.2.0/25".parse::<Prefix<Ipv4>>()?));
+        assert_eq!(s.prefixes().count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn partially_present_range_remove_is_changed() -> TestResult {
+        let mut s = setup();
+        assert!(s.remove_changed("192.0.2.0/24,24,25".parse::<PrefixRange<Ipv4>>()?));
+        assert!(s.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn absent_remove_is_unchanged() -> TestResult {
+        let mut s = setup();
+        let before = s.clone();
+        assert!(!s.remove_changed("192.0.2.0/24".parse::<Prefix<Ipv4>>()?));
+        assert!(!s.remove_changed("192.0.2.0/26".parse::<Prefix<Ipv4>>()?));
+        assert!(!s.remove_changed("198.51.100.0/24,24,32".parse::<PrefixRange<Ipv4>>()?));
+        assert_eq!(s, before);
+        Ok(())
+    }
+
+    #[test]
+    fn remove_from_empty_is_unchanged() -> TestResult {
+        let mut s = Set::<Ipv4>::new();
+        assert!(!s.remove_changed("192.0.2.0/24".parse::<Prefix<Ipv4>>()?));
+        Ok(())
+    }
+
+    #[test]
+    fn large_ranges() -> TestResult {
+        let range = |s: &str| s.parse::<PrefixRange<crate::Ipv6>>();
+        let mut s = Set::from_iter([range("2001:db8::/32,32,128")?]);
+        assert!(!s.insert_changed(range("2001:db8::/32,40,128")?));
+        assert!(!s.remove_changed(range("2001:db9::/32,32,128")?));
+        assert!(s.remove_changed(range("2001:db8::/32,48,128")?));
+        assert!(s.insert_changed(range("2001:db8::/32,32,128")?));
+        assert!(s.remove_changed(range("2001:db8::/32,32,128")?));
+        assert!(!s.remove_changed(range("2001:db8::/32,32,128")?));
+        Ok(())
+    }
+
+    #[test]
+    fn nested_range_insert_is_changed() -> TestResult {
+        let mut s = Set::<Ipv4>::new();
+        assert!(s.insert_changed("10.0.0.0/8".parse::<Prefix<Ipv4>>()?));
+        assert!(s.insert_changed("10.0.0.0/10,11,11".parse::<PrefixRange<Ipv4>>()?));
+        assert_eq!(s.len(), 3);
+        assert!(!s.insert_changed("10.0.0.0/10,11,11".parse::<PrefixRange<Ipv4>>()?));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn reports_change(set in small_ranges(), items in small_ranges(), insert in any::<bool>()) {
+            let mut s = set;
+            for range in items.ranges() {
+                let before = s.clone();
+                let changed = if insert {
+                    s.insert_changed(range)
+                } else {
+                    s.remove_changed(range)
+                };
+                assert_eq!(changed, s != before);
+            }
+        }
+
+        #[test]
+        fn reports_address_space_change(set in small_ranges(), items in small_ranges(), insert in any::<bool>()) {
+            let mut s = set;
+            for range in items.ranges() {
+                let before = s.address_count();
+                let reported = if insert {
+                    s.insert_reporting(range)
+                } else {
+                    s.remove_reporting(range)
+                };
+                assert_eq!(reported, s.address_count() != before);
+            }
+        }
+    }
+}
+
+mod from_address_ranges {
+    use super::*;
+
+    #[test]
+    fn single_range() -> TestResult {
+        let s = Set::from("10.0.0.3-10.0.0.10".parse::<AddressRange<Ipv4>>()?);
+        assert_eq!(s.prefixes().count(), 4);
+        assert!(s.contains("10.0.0.4/30".parse::<Prefix<Ipv4>>()?));
+        assert!(!s.contains("10.0.0.2/32".parse::<Prefix<Ipv4>>()?));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_ranges() -> TestResult {
+        let s: Set<Ipv4> = ["192.0.2.0-192.0.2.99", "192.0.2.100-192.0.2.255"]
+            .into_iter()
+            .map(str::parse::<AddressRange<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(s.prefixes().count(), 7);
+        assert!(s.contains("192.0.2.96/30".parse::<Prefix<Ipv4>>()?));
+        assert!(s.contains("192.0.2.100/30".parse::<Prefix<Ipv4>>()?));
+        assert!(!s.contains("192.0.2.96/29".parse::<Prefix<Ipv4>>()?));
+        Ok(())
+    }
+}
+
+mod entries {
+    use super::*;
+
+    #[test]
+    fn empty_set_has_no_entries() {
+        assert_eq!(Set::<Ipv4>::new().entries().count(), 0);
+    }
+
+    #[test]
+    fn entries_match_ranges() -> TestResult {
+        let s: Set<Ipv4> = ["192.0.2.0/24,24,25", "198.51.100.0/24,26,28"]
+            .into_iter()
+            .map(str::parse::<PrefixRange<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        let entries: Vec<_> = s.entries().collect();
+        assert_eq!(entries.len(), s.ranges().count());
+        for ((prefix, lengths), range) in entries.into_iter().zip(s.ranges()) {
+            assert_eq!(prefix, range.prefix());
+            assert_eq!(lengths, range.lower()..=range.upper());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn single_prefix_entry() -> TestResult {
+        let prefix = "192.0.2.0/24".parse::<Prefix<Ipv4>>()?;
+        let s = Set::from_iter([prefix]);
+        let length = PrefixLength::from_primitive(24)?;
+        assert_eq!(s.entries().collect::<Vec<_>>(), [(prefix, length..=length)]);
+        Ok(())
+    }
+}
+
+mod retain {
+    use super::*;
+
+    fn setup() -> Set<Ipv4> {
+        Set::from_iter([
+            "192.0.2.0/24,24,26".parse::<PrefixRange<Ipv4>>().unwrap(),
+            "198.51.100.0/24,24,24"
+                .parse::<PrefixRange<Ipv4>>()
+                .unwrap(),
+        ])
+    }
+
+    #[test]
+    fn retain_all_is_unchanged() {
+        let mut s = setup();
+        let before = s.clone();
+        s.retain(|_| true);
+        assert_eq!(s, before);
+    }
+
+    #[test]
+    fn retain_none_is_empty() {
+        let mut s = setup();
+        s.retain(|_| false);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn retain_deaggregates_covered_child() -> TestResult {
+        let mut s = setup();
+        let removed = "192.0.2.64/26".parse::<Prefix<Ipv4>>()?;
+        s.retain(|prefix| prefix != removed);
+        assert_eq!(s.len(), 7);
+        assert!(!s.contains(removed));
+        assert!(s.contains("192.0.2.0/26".parse::<Prefix<Ipv4>>()?));
+        assert!(s.contains("192.0.2.0/25".parse::<Prefix<Ipv4>>()?));
+        Ok(())
+    }
+
+    #[test]
+    fn retain_by_length() -> TestResult {
+        let mut s = setup();
+        s.retain(|prefix| prefix.length() == PrefixLength::from_primitive(24).unwrap());
+        assert_eq!(s.len(), 2);
+        assert!(s.contains("192.0.2.0/24".parse::<Prefix<Ipv4>>()?));
+        assert!(s.contains("198.51.100.0/24".parse::<Prefix<Ipv4>>()?));
+        Ok(())
+    }
+}
+
+mod address_count {
+    use super::*;
+    use crate::Ipv6;
+
+    #[test]
+    fn empty_set_covers_no_addresses() {
+        assert_eq!(Set::<Ipv4>::new().address_count(), 0);
+    }
+
+    #[test]
+    fn nested_prefixes_are_counted_once() -> TestResult {
+        let s: Set<Ipv4> = ["10.0.0.0/8", "10.1.0.0/16", "10.1.2.0/24", "192.0.2.0/32"]
+            .into_iter()
+            .map(str::parse::<Prefix<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(s.address_count(), (1 << 24) + 1);
+        Ok(())
+    }
+
+    #[test]
+    fn ranges_count_base_prefix_addresses() -> TestResult {
+        let s = Set::from_iter(["2001:db8::/32,48,64".parse::<PrefixRange<Ipv6>>()?]);
+        assert_eq!(s.address_count(), 1 << 96);
+        Ok(())
+    }
+
+    #[test]
+    fn whole_space_saturates() -> TestResult {
+        let s: Set<Ipv6> = ["::/1", "8000::/1"]
+            .into_iter()
+            .map(str::parse::<Prefix<Ipv6>>)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(s.address_count(), u128::MAX);
+        let s = Set::from_iter(["0.0.0.0/0".parse::<Prefix<Ipv4>>()?]);
+        assert_eq!(s.address_count(), 1 << 32);
+        Ok(())
+    }
+}
+
+mod length_histogram {
+    use super::*;
+    use crate::Ipv6;
+
+    #[test]
+    fn empty_set_has_empty_histogram() {
+        assert_eq!(Set::<Ipv4>::new().length_histogram().count(), 0);
+        assert_eq!(
+            Set::<Ipv4>::new().count_where(PrefixLength::MIN..=PrefixLength::MAX),
+            0
+        );
+    }
+
+    #[test]
+    fn histogram_matches_prefixes() -> TestResult {
+        let s: Set<Ipv4> = ["10.0.0.0/8,8,12", "10.1.0.0/16,16,16", "192.0.2.0/24,30,32"]
+            .into_iter()
+            .map(str::parse::<PrefixRange<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        let mut expected = vec![0; 33];
+        s.prefixes()
+            .for_each(|prefix| expected[usize::from(prefix.length().into_primitive())] += 1);
+        let histogram: Vec<_> = s
+            .length_histogram()
+            .map(|(length, count)| (usize::from(length.into_primitive()), count))
+            .collect();
+        assert_eq!(
+            histogram,
+            expected
+                .into_iter()
+                .enumerate()
+                .filter(|(_, count)| *count > 0)
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn count_where_is_inclusive() -> TestResult {
+        let s = Set::from_iter(["192.0.2.0/24,24,26".parse::<PrefixRange<Ipv4>>()?]);
+        let len = PrefixLength::<Ipv4>::from_primitive;
+        assert_eq!(s.count_where(len(24)?..=len(24)?), 1);
+        assert_eq!(s.count_where(len(25)?..=len(26)?), 6);
+        assert_eq!(s.count_where(len(27)?..=len(32)?), 0);
+        assert_eq!(
+            s.count_where(PrefixLength::MIN..=PrefixLength::MAX),
+            s.len()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn large_counts_saturate() -> TestResult {
+        let s = Set::from_iter(["::/0,128,128".parse::<PrefixRange<Ipv6>>()?]);
+        assert_eq!(
+            s.length_histogram().collect::<Vec<_>>(),
+            [(PrefixLength::MAX, usize::MAX)]
+        );
+        Ok(())
+    }
+}
+
+mod membership {
+    use proptest::{arbitrary::any, collection::vec, proptest};
+
+    use super::*;
+    use crate::traits::Prefix as _;
+
+    fn setup() -> Set<Ipv4> {
+        [
+            "10.0.0.0/8,16,16",
+            "192.0.2.0/24,24,25",
+            "198.51.100.0/25,25,25",
+        ]
+        .into_iter()
+        .map(str::parse::<PrefixRange<Ipv4>>)
+        .collect::<Result<_, _>>()
+        .unwrap()
+    }
+
+    #[test]
+    fn contains_range_fully_present() -> TestResult {
+        let s = setup();
+        assert!(s.contains_range(&"10.0.0.0/8,16,16".parse()?));
+        assert!(s.contains_range(&"10.1.0.0/16,16,16".parse()?));
+        assert!(s.contains_range(&"192.0.2.128/25,25,25".parse()?));
+        assert!(s.contains_range(&"192.0.2.0/24,24,24".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn contains_range_partially_present() -> TestResult {
+        let s = setup();
+        assert!(!s.contains_range(&"10.0.0.0/8,15,16".parse()?));
+        assert!(!s.contains_range(&"192.0.2.0/24,24,26".parse()?));
+        assert!(!s.contains_range(&"198.51.100.0/24,25,25".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn contains_range_split_across_nodes() -> TestResult {
+        let s: Set<Ipv4> = ["10.0.0.0/8,16,16", "10.0.0.0/9,17,17"]
+            .into_iter()
+            .map(str::parse::<PrefixRange<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        assert!(s.contains_range(&"10.0.0.0/9,16,17".parse()?));
+        assert!(!s.contains_range(&"10.0.0.0/8,16,17".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn contains_range_after_removal() -> TestResult {
+        let mut s = setup();
+        _ = s.remove("10.1.0.0/16".parse::<Prefix<Ipv4>>()?);
+        assert!(!s.contains_range(&"10.0.0.0/8,16,16".parse()?));
+        assert!(s.contains_range(&"10.2.0.0/15,16,16".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn contains_address() -> TestResult {
+        let s = setup();
+        for addr in ["10.255.255.255", "192.0.2.1", "198.51.100.127"] {
+            assert!(s.contains_address(addr.parse::<Address<Ipv4>>()?));
+        }
+        for addr in ["11.0.0.0", "192.0.3.0", "198.51.100.128"] {
+            assert!(!s.contains_address(addr.parse::<Address<Ipv4>>()?));
+        }
+        assert!(!Set::<Ipv4>::new().contains_address(Address::UNSPECIFIED));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn contains_range_matches_expansion(
+            ranges in vec(any::<PrefixRange<Ipv4>>(), 0..8),
+            query in any::<Prefix<Ipv4>>(),
+            lower in 0..=4u8,
+            upper in 0..=4u8,
+        ) {
+            let s: Set<Ipv4> = ranges.into_iter().collect();
+            let length = |extra: u8| PrefixLength::<Ipv4>::from_primitive(
+                query.length().get().saturating_add(extra).min(32)
+            ).unwrap();
+            if let Ok(range) = PrefixRange::new(query, length(lower.min(upper))..=length(lower.max(upper))) {
+                let expected = range.clone().into_iter().all(|prefix| s.contains(prefix));
+                assert_eq!(s.contains_range(&range), expected);
+            }
+        }
+
+        #[test]
+        fn contains_address_matches_any_prefix(
+            prefixes in vec(any::<Prefix<Ipv4>>(), 0..8),
+            addr in any::<Address<Ipv4>>(),
+        ) {
+            let s: Set<Ipv4> = prefixes.iter().copied().collect();
+            let expected = prefixes.iter().any(|prefix| prefix.contains(&addr));
+            assert_eq!(s.contains_address(addr), expected);
+            assert_eq!(s.contains_address(addr), s.longest_match(addr).is_some());
+        }
+
+        #[test]
+        fn contains_address_matches_address_ranges(
+            ranges in vec((any::<Address<Ipv4>>(), any::<u16>()), 0..32),
+            addr in any::<Address<Ipv4>>(),
+        ) {
+            let ranges: Vec<_> = ranges
+                .into_iter()
+                .filter_map(|(start, len)| {
+                    let end = Address::new(start.into_primitive().checked_add(len.into())?);
+                    AddressRange::new(start, end).ok()
+                })
+                .collect();
+            let s: Set<Ipv4> = ranges.iter().cloned().collect();
+            let expected = ranges.iter().any(|range| range.contains(&addr));
+            assert_eq!(s.contains_address(addr), expected);
+        }
+    }
+}
+
+mod longest_match {
+    use proptest::{arbitrary::any, collection::vec, proptest};
+
+    use super::*;
+    use crate::{traits::Prefix as _, Ipv6};
+
+    fn setup() -> Set<Ipv4> {
+        ["10.0.0.0/8", "10.1.0.0/16", "10.1.1.0/24"]
+            .into_iter()
+            .map(str::parse::<Prefix<Ipv4>>)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    fn naive<A: crate::traits::Afi>(set: &Set<A>, prefix: Prefix<A>) -> Option<Prefix<A>> {
+        let mut candidate = Some(prefix);
+        while let Some(prefix) = candidate {
+            if set.contains(prefix) {
+                return Some(prefix);
+            }
+            candidate = prefix.supernet();
+        }
+        None
+    }
+
+    #[test]
+    fn nested_prefixes() -> TestResult {
+        let s = setup();
+        for (addr, expected) in [
+            ("10.1.1.1", Some("10.1.1.0/24")),
+            ("10.1.2.1", Some("10.1.0.0/16")),
+            ("10.2.0.1", Some("10.0.0.0/8")),
+            ("11.0.0.1", None),
+        ] {
+            let expected = expected.map(str::parse::<Prefix<Ipv4>>).transpose()?;
+            assert_eq!(s.longest_match(addr.parse::<Address<Ipv4>>()?), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn prefix_query() -> TestResult {
+        let s = setup();
+        assert_eq!(
+            s.longest_match_prefix("10.1.1.0/24".parse()?),
+            Some("10.1.1.0/24".parse()?)
+        );
+        assert_eq!(
+            s.longest_match_prefix("10.1.0.0/20".parse()?),
+            Some("10.1.0.0/16".parse()?)
+        );
+        assert_eq!(s.longest_match_prefix("10.0.0.0/7".parse()?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn aggregated_ranges() -> TestResult {
+        let s = Set::from_iter(["192.0.2.0/24,26,28".parse::<PrefixRange<Ipv4>>()?]);
+        assert_eq!(
+            s.longest_match("192.0.2.200".parse::<Address<Ipv4>>()?),
+            Some("192.0.2.192/28".parse()?)
+        );
+        assert_eq!(s.longest_match_prefix("192.0.2.128/25".parse()?), None);
+        Ok(())
+    }
+
+    #[test]
+    fn default_route() -> TestResult {
+        let s = Set::from_iter([Prefix::<Ipv6>::DEFAULT]);
+        assert_eq!(
+            s.longest_match("2001:db8::1".parse::<Address<Ipv6>>()?),
+            Some(Prefix::DEFAULT)
+        );
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_naive_supernet_probing(
+            prefixes in vec(any::<Prefix<Ipv4>>(), 0..16),
+            query in any::<Prefix<Ipv4>>(),
+        ) {
+            let s: Set<Ipv4> = prefixes.into_iter().collect();
+            assert_eq!(s.longest_match_prefix(query), naive(&s, query));
+            assert_eq!(
+                s.longest_match(query.network()),
+                naive(&s, query.network().into())
+            );
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod par_from_iter {
+    use std::format;
+
+    use proptest::{arbitrary::any, collection::vec, proptest};
+
+    use super::*;
+    use crate::Ipv6;
+
+    #[test]
+    fn empty() {
+        let s = Set::<Ipv4>::par_from_iter(Vec::<Prefix<Ipv4>>::new());
+        assert_eq!(s, Set::new());
+    }
+
+    #[test]
+    fn aggregates_across_chunks() -> TestResult {
+        let prefixes = (0..=255u8)
+            .map(|i| format!("10.0.{i}.0/24").parse::<Prefix<Ipv4>>())
+            .collect::<Result<Vec<_>, _>>()?;
+        let s = Set::par_from_iter(prefixes.clone());
+        assert_eq!(s, prefixes.into_iter().collect());
+        assert_eq!(s.ranges().count(), 1);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_sequential_ipv4(prefixes in vec(any::<Prefix<Ipv4>>(), 0..256)) {
+            let s = Set::par_from_iter(prefixes.clone());
+            assert_eq!(s, prefixes.into_iter().collect());
+        }
+
+        #[test]
+        fn matches_sequential_ipv6(prefixes in vec(any::<Prefix<Ipv6>>(), 0..256)) {
+            let s = Set::par_from_iter(prefixes.clone());
+            assert_eq!(s, prefixes.into_iter().collect());
+        }
+    }
+}
+
+mod subsets {
+    use proptest::{collection::vec, proptest, strategy::Strategy};
+
+    use super::*;
+
+    // Generate ranges within a small region of the address space, so that
+    // randomly generated sets overlap often.
+    pub(super) fn small_ranges() -> impl Strategy<Value = Set<Ipv4>> {
+        vec((0u32..16, 8u8..=12, 0u8..=2, 0u8..=2), 0..6).prop_map(|ranges| {
+            ranges
+                .into_iter()
+                .filter_map(|(bits, length, lower, width)| {
+                    let addr = Address::new(0x0a00_0000 | (bits << 20));
+                    let length = PrefixLength::from_primitive(length).ok()?;
+                    let lower = length.checked_add(lower).ok()?;
+                    let upper = lower.checked_add(width).ok()?;
+                    PrefixRange::new(Prefix::new(addr, length), lower..=upper).ok()
+                })
+                .collect()
+        })
+    }
+
+    fn brute_force_subset(s: &Set<Ipv4>, t: &Set<Ipv4>) -> bool {
+        s.prefixes().all(|p| t.contains(p))
+    }
+
+    fn brute_force_disjoint(s: &Set<Ipv4>, t: &Set<Ipv4>) -> bool {
+        !s.prefixes().any(|p| t.contains(p))
+    }
+
+    #[test]
+    fn empty_set() -> TestResult {
+        let s = Set::from_iter(["10.0.0.0/8,16,24".parse::<PrefixRange<Ipv4>>()?]);
+        let empty = Set::new();
+        assert!(empty.is_subset(&s));
+        assert!(s.is_superset(&empty));
+        assert!(s.is_disjoint(&empty));
+        assert!(empty.is_disjoint(&s));
+        Ok(())
+    }
+
+    #[test]
+    fn strict_superset_is_unequal() -> TestResult {
+        let s = Set::from_iter(["10.0.0.0/8,9,9".parse::<PrefixRange<Ipv4>>()?]);
+        let mut t = s.clone();
+        _ = t.insert("10.0.0.0/10,12,12".parse::<PrefixRange<Ipv4>>()?);
+        assert_ne!(s, t);
+        assert_ne!(t, s);
+        assert!(s < t);
+        Ok(())
+    }
+
+    #[test]
+    fn nested_gluemaps() -> TestResult {
+        let s: Set<Ipv4> = ["10.0.0.0/8,16,16", "10.0.0.0/9,17,17"]
+            .into_iter()
+            .map(str::parse::<PrefixRange<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        let t = Set::from_iter(["10.0.0.0/10,16,17".parse::<PrefixRange<Ipv4>>()?]);
+        assert!(t.is_subset(&s));
+        assert!(!s.is_subset(&t));
+        assert!(!s.is_disjoint(&t));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn is_subset_matches_brute_force(s in small_ranges(), t in small_ranges()) {
+            assert_eq!(s.is_subset(&t), brute_force_subset(&s, &t));
+            assert_eq!(s.is_superset(&t), brute_force_subset(&t, &s));
+        }
+
+        #[test]
+        fn is_disjoint_matches_brute_force(s in small_ranges(), t in small_ranges()) {
+            assert_eq!(s.is_disjoint(&t), brute_force_disjoint(&s, &t));
+            assert_eq!(t.is_disjoint(&s), s.is_disjoint(&t));
+        }
+
+        #[test]
+        fn intersection_is_subset(s in small_ranges(), t in small_ranges()) {
+            let i = s.clone() & t.clone();
+            assert!(i.is_subset(&s));
+            assert!(i.is_subset(&t));
+            assert_eq!(s.is_disjoint(&t), i.prefixes().next().is_none());
+        }
+
+        #[test]
+        fn contains_range_matches_brute_force(s in small_ranges(), t in small_ranges()) {
+            for range in t.ranges() {
+                let expected = range.clone().into_iter().all(|p| s.contains(p));
+                assert_eq!(s.contains_range(&range), expected);
+            }
+        }
+    }
+}
+
+mod len {
+    use proptest::{collection::vec, prop_oneof, proptest, strategy::Strategy};
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    enum Op {
+        Insert(Set<Ipv4>),
+        Remove(Set<Ipv4>),
+        Intersect(Set<Ipv4>),
+        Union(Set<Ipv4>),
+        Difference(Set<Ipv4>),
+        SymmetricDifference(Set<Ipv4>),
+    }
+
+    fn ops() -> impl Strategy<Value = Vec<Op>> {
+        vec(
+            prop_oneof![
+                small_ranges().prop_map(Op::Insert),
+                small_ranges().prop_map(Op::Remove),
+                small_ranges().prop_map(Op::Intersect),
+                small_ranges().prop_map(Op::Union),
+                small_ranges().prop_map(Op::Difference),
+                small_ranges().prop_map(Op::SymmetricDifference),
+            ],
+            0..8,
+        )
+    }
+
+    #[test]
+    fn large_range() -> TestResult {
+        let s = Set::from_iter(["2001:db8::/48,64,64".parse::<PrefixRange<crate::Ipv6>>()?]);
+        assert_eq!(s.len(), 1 << 16);
+        Ok(())
+    }
+
+    #[test]
+    fn saturates() {
+        assert_eq!(Set::<crate::Ipv6>::any().len(), usize::MAX);
+    }
+
+    #[test]
+    fn empty_after_removal() -> TestResult {
+        let range = "192.0.2.0/24,24,26".parse::<PrefixRange<Ipv4>>()?;
+        let mut s = Set::from_iter([range.clone()]);
+        assert_eq!(s.len(), 7);
+        _ = s.remove(range);
+        assert_eq!(s.len(), 0);
+        assert!(s.is_empty());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        // The binary operators are exercised here; the assigning operators
+        // are covered in `assign_ops`.
+        #[allow(clippy::assign_op_pattern)]
+        fn len_matches_prefix_count(ops in ops()) {
+            let mut s = Set::<Ipv4>::new();
+            for op in ops {
+                match op {
+                    Op::Insert(t) => {
+                        _ = s.insert_from(t.prefixes());
+                    }
+                    Op::Remove(t) => {
+                        _ = s.remove_from(t.prefixes());
+                    }
+                    Op::Intersect(t) => s = s & t,
+                    Op::Union(t) => s = s | t,
+                    Op::Difference(t) => s = s - t,
+                    Op::SymmetricDifference(t) => s = s ^ t,
+                }
+                assert_eq!(s.len(), s.prefixes().count());
+                assert_eq!(s.is_empty(), s.prefixes().next().is_none());
+            }
+        }
+    }
+}
+
+mod split_off {
+    use proptest::{proptest, strategy::Strategy};
+
+    use super::subsets::small_ranges;
+    use super::*;
+    use crate::{traits::Prefix as _, Ipv6};
+
+    pub(super) fn small_prefixes() -> impl Strategy<Value = Prefix<Ipv4>> {
+        (0u32..16, 6u8..=14).prop_map(|(bits, length)| {
+            Prefix::new(
+                Address::new(0x0a00_0000 | (bits << 20)),
+                PrefixLength::from_primitive(length).unwrap(),
+            )
+        })
+    }
+
+    #[test]
+    fn straddling_range() -> TestResult {
+        let original = Set::from_iter(["0.0.0.0/0,8,24".parse::<PrefixRange<Ipv4>>()?]);
+        let mut s = original.clone();
+        let taken = s.split_off("10.0.0.0/8".parse()?);
+        assert_eq!(
+            taken,
+            Set::from_iter(["10.0.0.0/8,8,24".parse::<PrefixRange<Ipv4>>()?])
+        );
+        assert_eq!(s, original.clone() - taken.clone());
+        assert_eq!(s.len() + taken.len(), original.len());
+        assert!(s.contains("11.0.0.0/8".parse()?));
+        assert!(!s.contains("10.0.0.0/8".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn straddling_range_ipv6() -> TestResult {
+        let mut s = Set::from_iter(["::/0,16,128".parse::<PrefixRange<Ipv6>>()?]);
+        let taken = s.split_off("2001:db8::/32".parse()?);
+        assert_eq!(
+            taken.ranges().collect::<Vec<_>>(),
+            vec!["2001:db8::/32,32,128".parse::<PrefixRange<Ipv6>>()?]
+        );
+        assert!(s.contains("2001:db9::/32".parse()?));
+        assert!(s.contains("2001::/16".parse()?));
+        assert!(!s.contains("2001:db8::/48".parse()?));
+        assert!(s.is_disjoint(&taken));
+        Ok(())
+    }
+
+    #[test]
+    fn detach_subtree() -> TestResult {
+        let mut s: Set<Ipv4> = ["10.0.0.0/16", "10.1.0.0/16", "192.0.2.0/24"]
+            .into_iter()
+            .map(str::parse::<Prefix<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        let taken = s.split_off("10.0.0.0/8".parse()?);
+        assert_eq!(taken.len(), 2);
+        assert_eq!(
+            s.prefixes().collect::<Vec<_>>(),
+            vec!["192.0.2.0/24".parse::<Prefix<Ipv4>>()?]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn split_everything_or_nothing() -> TestResult {
+        let original = Set::from_iter(["10.0.0.0/8,16,24".parse::<PrefixRange<Ipv4>>()?]);
+        let mut s = original.clone();
+        assert!(s.split_off("192.0.2.0/24".parse()?).is_empty());
+        assert_eq!(s, original);
+        assert_eq!(s.split_off(Prefix::DEFAULT), original);
+        assert!(s.is_empty());
+        assert!(s.split_off(Prefix::DEFAULT).is_empty());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn partitions_by_coverage(original in small_ranges(), prefix in small_prefixes()) {
+            let mut s = original.clone();
+            let taken = s.split_off(prefix);
+            assert!(taken.prefixes().all(|p| prefix.contains(&p)));
+            assert!(s.prefixes().all(|p| !prefix.contains(&p)));
+            assert_eq!(s.len() + taken.len(), original.len());
+            assert_eq!(s.clone() | taken.clone(), original);
+            assert_eq!(s.len(), s.prefixes().count());
+            assert_eq!(taken.len(), taken.prefixes().count());
+        }
+    }
+}
+
+mod remove_covered {
+    use proptest::proptest;
+
+    use super::split_off::small_prefixes;
+    use super::subsets::small_ranges;
+    use super::*;
+
+    fn set(items: &[&str]) -> Set<Ipv4> {
+        items
+            .iter()
+            .map(|s| s.parse::<PrefixRange<Ipv4>>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn covering_parent() -> TestResult {
+        let mut s = set(&["1.0.0.0/16,16,16"]);
+        assert!(s.remove_covered("1.0.0.0/8".parse()?).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn covered_child() -> TestResult {
+        let mut s = set(&["1.0.0.0/8,16,16"]);
+        _ = s.remove_covered("1.0.0.0/16".parse()?);
+        assert_eq!(
+            s,
+            set(&[
+                "1.1.0.0/16,16,16",
+                "1.2.0.0/15,16,16",
+                "1.4.0.0/14,16,16",
+                "1.8.0.0/13,16,16",
+                "1.16.0.0/12,16,16",
+                "1.32.0.0/11,16,16",
+                "1.64.0.0/10,16,16",
+                "1.128.0.0/9,16,16",
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping_set_with_covered_child() -> TestResult {
+        let mut s = set(&["1.0.0.0/8,8,8", "1.0.0.0/16,16,16"]);
+        _ = s.remove_covered("1.0.0.0/16".parse()?);
+        assert_eq!(s, set(&["1.0.0.0/8,8,8"]));
+        Ok(())
+    }
+
+    #[test]
+    fn disjoint() -> TestResult {
+        let mut s = set(&["1.0.0.0/8,8,16"]);
+        _ = s.remove_covered("2.0.0.0/8".parse()?);
+        assert_eq!(s, set(&["1.0.0.0/8,8,16"]));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_difference(original in small_ranges(), prefix in small_prefixes()) {
+            let mut s = original.clone();
+            _ = s.remove_covered(prefix);
+            let range = PrefixRange::new(prefix, prefix.length()..=PrefixLength::MAX).unwrap();
+            assert_eq!(s, original - Set::from_iter([range]));
+        }
+    }
+}
+
+mod within {
+    use proptest::proptest;
+
+    use super::split_off::small_prefixes;
+    use super::subsets::small_ranges;
+    use super::*;
+    use crate::{traits::Prefix as _, Ipv6};
+
+    #[test]
+    fn straddling_and_nested() -> TestResult {
+        let s = Set::<Ipv6>::from_iter([
+            "::/0,16,128".parse::<PrefixRange<Ipv6>>()?,
+            "2000::/8,8,8".parse()?,
+        ]);
+        assert_eq!(
+            s.ranges_within("2001:db8::/32".parse()?)
+                .collect::<Vec<_>>(),
+            vec!["2001:db8::/32,32,128".parse::<PrefixRange<Ipv6>>()?]
+        );
+        assert_eq!(
+            s.ranges_within("2000::/8".parse()?).collect::<Set<_>>(),
+            Set::from_iter([
+                "2000::/8,8,8".parse::<PrefixRange<Ipv6>>()?,
+                "2000::/8,16,128".parse()?,
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> TestResult {
+        let s = Set::<Ipv4>::new();
+        assert_eq!(s.prefixes_within(Prefix::DEFAULT).count(), 0);
+        let s = Set::from_iter(["10.0.0.0/8,16,16".parse::<PrefixRange<Ipv4>>()?]);
+        assert_eq!(s.prefixes_within("192.0.2.0/24".parse()?).count(), 0);
+        assert_eq!(s.prefixes_within("10.0.0.0/16".parse()?).count(), 1);
+        assert_eq!(s.prefixes_within("10.0.0.0/17".parse()?).count(), 0);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn matches_filter(s in small_ranges(), cover in small_prefixes()) {
+            let mut within = s.prefixes_within(cover).collect::<Vec<_>>();
+            let mut filtered = s.prefixes().filter(|p| cover.contains(p)).collect::<Vec<_>>();
+            within.sort_by_key(|p| (p.network(), p.length()));
+            filtered.sort_by_key(|p| (p.network(), p.length()));
+            assert_eq!(within, filtered);
+        }
+    }
+}
+
+mod bounds {
+    use proptest::proptest;
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let s = Set::<Ipv4>::new();
+        assert_eq!(s.spanning_prefix(), None);
+        assert_eq!(s.bounds(), None);
+    }
+
+    #[test]
+    fn single_range() -> TestResult {
+        let s = Set::from_iter(["10.0.0.0/8,16,24".parse::<PrefixRange<Ipv4>>()?]);
+        assert_eq!(s.spanning_prefix(), Some("10.0.0.0/8".parse()?));
+        assert_eq!(
+            s.bounds(),
+            Some(("10.0.0.0/16".parse()?, "10.255.255.0/24".parse()?))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn shadowed_by_descendant() -> TestResult {
+        let s = Set::from_iter([
+            "10.0.0.0/8".parse::<Prefix<Ipv4>>()?,
+            "10.128.0.0/9".parse()?,
+            "10.0.0.0/16".parse()?,
+        ]);
+        assert_eq!(s.spanning_prefix(), Some("10.0.0.0/8".parse()?));
+        assert_eq!(
+            s.bounds(),
+            Some(("10.0.0.0/8".parse()?, "10.128.0.0/9".parse()?))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn descendant_shorter_than_ancestor_range() -> TestResult {
+        let s: Set<Ipv4> = ["10.0.0.0/9,11,11", "10.0.0.0/10,10,10"]
+            .into_iter()
+            .map(str::parse::<PrefixRange<Ipv4>>)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(s.spanning_prefix(), Some("10.0.0.0/9".parse()?));
+        assert_eq!(
+            s.bounds(),
+            Some(("10.0.0.0/10".parse()?, "10.96.0.0/11".parse()?))
+        );
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn spanning_prefix_matches_common(s in small_ranges()) {
+            assert_eq!(
+                s.spanning_prefix(),
+                s.prefixes().reduce(|p, q| p.common(&q))
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn bounds_match_brute_force(s in small_ranges()) {
+            let key = |p: &Prefix<Ipv4>| (p.prefix(), p.length());
+            assert_eq!(
+                s.bounds(),
+                s.prefixes().min_by_key(key).zip(s.prefixes().max_by_key(key))
+            );
+        }
+    }
+}
+
+mod into_iter {
+    use proptest::proptest;
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(Set::<Ipv4>::new().into_iter().next(), None);
+        assert_eq!(Set::<Ipv4>::new().into_ranges().next(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_borrowing_prefixes(s in small_ranges()) {
+            let borrowed = s.prefixes().collect::<Vec<_>>();
+            assert_eq!(s.into_iter().collect::<Vec<_>>(), borrowed);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn matches_borrowing_ranges(s in small_ranges()) {
+            let borrowed = s.ranges().collect::<Vec<_>>();
+            assert_eq!(s.into_ranges().collect::<Vec<_>>(), borrowed);
+        }
+    }
+}
+
+mod size_hint {
+    use proptest::proptest;
+
+    use super::split_off::small_prefixes;
+    use super::subsets::small_ranges;
+    use super::*;
+
+    #[test]
+    fn saturated_count_is_lower_bound() -> TestResult {
+        let s = Set::from_iter(["::/0,0,128".parse::<PrefixRange<crate::Ipv6>>()?]);
+        let mut prefixes = s.prefixes();
+        assert_eq!(prefixes.size_hint(), (usize::MAX, None));
+        _ = prefixes.next();
+        assert_eq!(prefixes.size_hint(), (usize::MAX - 1, None));
+        assert_eq!(s.ranges().size_hint(), (1, None));
+        Ok(())
+    }
+
+    #[test]
+    fn borrowing_loop_matches_prefixes() -> TestResult {
+        let s = Set::from_iter(["10.0.0.0/8,9,10".parse::<PrefixRange<Ipv4>>()?]);
+        let mut looped = Vec::new();
+        for prefix in &s {
+            looped.push(prefix);
+        }
+        assert_eq!(looped, s.prefixes().collect::<Vec<_>>());
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn prefixes_hint_is_exact(s in small_ranges()) {
+            let mut prefixes = s.prefixes();
+            let mut remaining = s.len();
+            loop {
+                assert_eq!(prefixes.size_hint(), (remaining, Some(remaining)));
+                if prefixes.next().is_none() {
+                    break;
+                }
+                remaining -= 1;
+            }
+            assert_eq!(remaining, 0);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn ranges_hint_is_bound(s in small_ranges()) {
+            let mut ranges = s.ranges();
+            let mut remaining = s.ranges().count();
+            loop {
+                let (lower, upper) = ranges.size_hint();
+                assert!(lower <= remaining);
+                assert!(upper.is_none_or(|upper| remaining <= upper));
+                if ranges.next().is_none() {
+                    break;
+                }
+                remaining -= 1;
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn within_hint_is_bound(s in small_ranges(), cover in small_prefixes()) {
+            let prefixes = s.prefixes_within(cover);
+            let (lower, upper) = prefixes.size_hint();
+            let count = prefixes.count();
+            assert!(lower <= count);
+            assert!(upper.is_none_or(|upper| count <= upper));
+        }
+    }
+}
+
+mod assign_ops {
+    use std::collections::HashSet;
+
+    use proptest::proptest;
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    fn members(s: &Set<Ipv4>) -> HashSet<Prefix<Ipv4>> {
+        s.prefixes().collect()
+    }
+
+    proptest! {
+        #[test]
+        fn bitor_assign(s in small_ranges(), t in small_ranges()) {
+            let expected = members(&s).union(&members(&t)).copied().collect();
+            let mut r = s.clone();
+            r |= t.clone();
+            assert_eq!(members(&r), expected);
+            assert_eq!(r, s.clone() | t.clone());
+            let mut r = s;
+            r += t;
+            assert_eq!(members(&r), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn bitand_assign(s in small_ranges(), t in small_ranges()) {
+            let expected = members(&s).intersection(&members(&t)).copied().collect();
+            let mut r = s.clone();
+            r &= t.clone();
+            assert_eq!(members(&r), expected);
+            assert_eq!(r, s.clone() & t.clone());
+            let mut r = s;
+            r *= t;
+            assert_eq!(members(&r), expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn bitxor_assign(s in small_ranges(), t in small_ranges()) {
+            let expected = members(&s).symmetric_difference(&members(&t)).copied().collect();
+            let mut r = s.clone();
+            r ^= t.clone();
+            assert_eq!(members(&r), expected);
+            assert_eq!(r, s ^ t);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn sub_assign(s in small_ranges(), t in small_ranges()) {
+            let expected = members(&s).difference(&members(&t)).copied().collect();
+            let mut r = s.clone();
+            r -= t.clone();
+            assert_eq!(members(&r), expected);
+            assert_eq!(r, s - t);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn len_after_assign_ops(s in small_ranges(), t in small_ranges()) {
+            let check = |r: &Set<Ipv4>| {
+                assert_eq!(r.len(), r.prefixes().count());
+                assert_eq!(r.is_empty(), r.prefixes().next().is_none());
+            };
+            let mut r = s.clone();
+            r |= t.clone();
+            check(&r);
+            let mut r = s.clone();
+            r &= t.clone();
+            check(&r);
+            let mut r = s.clone();
+            r ^= t.clone();
+            check(&r);
+            let mut r = s;
+            r -= t;
+            check(&r);
+        }
+    }
+}
+
+mod append {
+    use std::collections::HashSet;
+
+    use proptest::proptest;
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn matches_insert_from(s in small_ranges(), t in small_ranges()) {
+            let mut expected = s.clone();
+            _ = expected.insert_from(t.ranges());
+            let mut r = s;
+            _ = r.append(t);
+            assert_eq!(r, expected);
+        }
+    }
+
+    #[test]
+    fn fold_many() -> TestResult {
+        let length = PrefixLength::from_primitive(16)?;
+        let sets = (0..=255u8)
+            .map(|i| {
+                Set::<Ipv4>::from_iter([Prefix::new(Address::from_octets([10, i, 0, 0]), length)])
+            })
+            .collect::<Vec<_>>();
+        let members = sets.iter().flat_map(Set::prefixes).collect::<HashSet<_>>();
+        let set = sets.into_iter().fold(Set::new(), |mut set, other| {
+            _ = set.append(other);
+            set
+        });
+        assert_eq!(set.prefixes().collect::<HashSet<_>>(), members);
+        assert_eq!(set.ranges().count(), 1);
+        Ok(())
+    }
+}
+
+mod display {
+    use std::string::ToString as _;
+
+    use proptest::proptest;
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(s in small_ranges()) {
+            let parsed = s
+                .to_string()
+                .lines()
+                .map(str::parse::<PrefixRange<Ipv4>>)
+                .collect::<Result<Set<_>, _>>()
+                .unwrap();
+            assert_eq!(parsed, s);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn alternate_lists_prefixes(s in small_ranges()) {
+            let parsed = std::format!("{s:#}")
+                .lines()
+                .map(str::parse::<Prefix<Ipv4>>)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(parsed, s.prefixes().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Set::<crate::Ipv6>::new().to_string(), "");
+    }
+}
+
+mod hash {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    use proptest::proptest;
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    fn hash_of<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    proptest! {
+        #[test]
+        fn equal_sets_hash_equal(s in small_ranges()) {
+            let prefixes = s.prefixes().collect::<Vec<_>>();
+            let reversed = prefixes.iter().rev().collect::<Set<_>>();
+            let mut one_by_one = Set::new();
+            for prefix in &prefixes {
+                _ = one_by_one.insert(*prefix);
+            }
+            let ranges = s.ranges().collect::<Vec<_>>();
+            let from_ranges = ranges.iter().rev().collect::<Set<_>>();
+            for t in [reversed, one_by_one, from_ranges] {
+                assert_eq!(t, s);
+                assert!(t.ranges().eq(s.ranges()));
+                assert_eq!(hash_of(&t), hash_of(&s));
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn operator_results_hash_equal(s in small_ranges(), t in small_ranges()) {
+            let union = &s | &t;
+            let rebuilt = (&s - &t) | (&t - &s) | (&s & &t);
+            assert_eq!(rebuilt, union);
+            assert_eq!(hash_of(&rebuilt), hash_of(&union));
+        }
+    }
+}
+
+mod canonical {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    use proptest::{collection::vec, prop_oneof, proptest, strategy::Strategy};
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    enum Op {
+        Insert(Set<Ipv4>),
+        InsertOneByOne(Set<Ipv4>),
+        Remove(Set<Ipv4>),
+        RemoveOneByOne(Set<Ipv4>),
+        Union(Set<Ipv4>),
+        Intersection(Set<Ipv4>),
+        Difference(Set<Ipv4>),
+        SymmetricDifference(Set<Ipv4>),
+    }
+
+    fn ops() -> impl Strategy<Value = Vec<Op>> {
+        vec(
+            prop_oneof![
+                small_ranges().prop_map(Op::Insert),
+                small_ranges().prop_map(Op::InsertOneByOne),
+                small_ranges().prop_map(Op::Remove),
+                small_ranges().prop_map(Op::RemoveOneByOne),
+                small_ranges().prop_map(Op::Union),
+                small_ranges().prop_map(Op::Intersection),
+                small_ranges().prop_map(Op::Difference),
+                small_ranges().prop_map(Op::SymmetricDifference),
+            ],
+            0..8,
+        )
+    }
+
+    fn members(s: &Set<Ipv4>) -> HashSet<Prefix<Ipv4>> {
+        s.prefixes().collect()
+    }
+
+    fn hash_of(s: &Set<Ipv4>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn apply(s: &mut Set<Ipv4>, model: &mut HashSet<Prefix<Ipv4>>, op: Op) {
+        match op {
+            Op::Insert(t) => {
+                model.extend(t.prefixes());
+                _ = s.insert_from(t.ranges());
+            }
+            Op::InsertOneByOne(t) => {
+                model.extend(t.prefixes());
+                for prefix in t.prefixes() {
+                    _ = s.insert(prefix);
+                }
+            }
+            Op::Remove(t) => {
+                model.retain(|p| !t.contains(*p));
+                _ = s.remove_from(t.ranges());
+            }
+            Op::RemoveOneByOne(t) => {
+                model.retain(|p| !t.contains(*p));
+                for range in t.ranges() {
+                    _ = s.remove_range(range);
+                }
+            }
+            Op::Union(t) => {
+                model.extend(t.prefixes());
+                *s |= t;
+            }
+            Op::Intersection(t) => {
+                model.retain(|p| t.contains(*p));
+                *s &= t;
+            }
+            Op::Difference(t) => {
+                model.retain(|p| !t.contains(*p));
+                *s -= t;
+            }
+            Op::SymmetricDifference(t) => {
+                *model = model.symmetric_difference(&members(&t)).copied().collect();
+                *s ^= t;
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn operation_sequences_are_canonical(init in small_ranges(), ops in ops()) {
+            let mut s = init.clone();
+            let mut model = members(&init);
+            for op in ops {
+                apply(&mut s, &mut model, op);
+            }
+            assert_eq!(members(&s), model);
+            let rebuilt = model.iter().collect::<Set<_>>();
+            assert!(s.ranges().eq(rebuilt.ranges()));
+            assert_eq!(s, rebuilt);
+            assert_eq!(hash_of(&s), hash_of(&rebuilt));
+        }
+    }
+
+    #[test]
+    fn insert_then_remove_is_empty() -> TestResult {
+        let mut s = Set::<Ipv4>::new();
+        _ = s.insert("10.0.0.0/8,8,12".parse::<PrefixRange<Ipv4>>()?);
+        _ = s.remove_range("10.0.0.0/8,8,12".parse::<PrefixRange<Ipv4>>()?);
+        assert_eq!(s, Set::new());
+        assert_eq!(hash_of(&s), hash_of(&Set::new()));
+        Ok(())
+    }
+
+    #[test]
+    fn split_and_whole_range_are_equal() -> TestResult {
+        let whole = Set::from_iter(["10.0.0.0/8,9,9".parse::<PrefixRange<Ipv4>>()?]);
+        let split = Set::from_iter([
+            "10.128.0.0/9".parse::<Prefix<Ipv4>>()?,
+            "10.0.0.0/9".parse()?,
+        ]);
+        let mut removed = Set::from_iter(["10.0.0.0/8,8,9".parse::<PrefixRange<Ipv4>>()?]);
+        _ = removed.remove("10.0.0.0/8".parse::<Prefix<Ipv4>>()?);
+        for s in [split, removed] {
+            assert_eq!(s, whole);
+            assert_eq!(hash_of(&s), hash_of(&whole));
+        }
+        Ok(())
+    }
+}
+
+mod algebra {
+    use proptest::{
+        arbitrary::{any_with, Arbitrary},
+        collection::SizeRange,
+        proptest,
+        strategy::Strategy,
+    };
+
+    use super::*;
+    use crate::{any, Ipv6};
+
+    // Limit the number of ranges in each generated set to keep the tests
+    // fast.
+    fn small<T, P>() -> impl Strategy<Value = T>
+    where
+        T: Arbitrary<Parameters = (SizeRange, P)>,
+        P: Default,
+    {
+        any_with::<T>(((0..16).into(), P::default()))
+    }
+
+    proptest! {
+        #[test]
+        fn union_and_intersection_commute(s in small::<Set<Ipv6>, _>(), t in small::<Set<Ipv6>, _>()) {
+            assert_eq!(&s | &t, &t | &s);
+            assert_eq!(&s & &t, &t & &s);
+        }
+
+        #[test]
+        fn intersection_and_union_bound_operands(
+            s in small::<Set<Ipv4>, _>(),
+            t in small::<Set<Ipv4>, _>(),
+        ) {
+            let (union, intersection) = (&s | &t, &s & &t);
+            assert!(intersection.is_subset(&s) && intersection.is_subset(&t));
+            assert!(union.is_superset(&s) && union.is_superset(&t));
+            assert_eq!(s.is_disjoint(&t), intersection.is_empty());
+        }
+
+        #[test]
+        fn generated_sets_are_canonical(s in small::<Set<Ipv4>, _>()) {
+            assert_eq!(s.ranges().collect::<Set<Ipv4>>(), s);
+        }
+
+        #[test]
+        fn mixed_sets_partition_by_family(s in small::<any::PrefixSet, _>()) {
+            let (ipv4, ipv6) = s.clone().partition();
+            assert_eq!(s.len(), ipv4.len().saturating_add(ipv6.len()));
+            assert_eq!(any::PrefixSet::from(ipv4) | any::PrefixSet::from(ipv6), s);
+        }
+    }
+}
+
+mod ref_ops {
+    use proptest::proptest;
+
+    use super::subsets::small_ranges;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn bitand(s in small_ranges(), t in small_ranges()) {
+            let expected = s.clone() & t.clone();
+            assert_eq!(&s & &t, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn bitor(s in small_ranges(), t in small_ranges()) {
+            let expected = s.clone() | t.clone();
+            assert_eq!(&s | &t, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn bitxor(s in small_ranges(), t in small_ranges()) {
+            let expected = s.clone() ^ t.clone();
+            assert_eq!(&s ^ &t, expected);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn sub(s in small_ranges(), t in small_ranges()) {
+            let expected = s.clone() - t.clone();
+            assert_eq!(&s - &t, expected);
+        }
+    }
+
+    #[test]
+    fn empty_operands() {
+        let empty = Set::<Ipv4>::new();
+        let mut set = Set::<Ipv4>::new();
+        _ = set.insert("10.0.0.0/8,8,16".parse::<PrefixRange<Ipv4>>().unwrap());
+        assert_eq!(&set | &empty, set);
+        assert_eq!(&empty | &set, set);
+        assert_eq!(&set & &empty, empty);
+        assert_eq!(&set - &empty, set);
+        assert_eq!(&empty - &set, empty);
+        assert_eq!(&set ^ &set, empty);
+    }
+}
+
+mod exclude {
+    use super::*;
+    use crate::traits::Prefix as _;
+
+    fn brute_force(range: &PrefixRange<Ipv4>, prefix: Prefix<Ipv4>) -> Vec<Prefix<Ipv4>> {
+        let mut prefixes = range
+            .clone()
+            .into_iter()
+            .filter(|p| *p != prefix)
+            .collect::<Vec<_>>();
+        prefixes.sort_by_key(|p| (p.network(), p.length()));
+        prefixes
+    }
+
+    #[test]
+    fn matches_brute_force() -> TestResult {
+        for (range, prefix) in [
+            ("10.0.0.0/8,8,12", "10.0.0.0/8"),
+            ("10.0.0.0/8,8,12", "10.128.0.0/9"),
+            ("10.0.0.0/8,8,12", "10.240.0.0/12"),
+            ("10.0.0.0/8,10,12", "10.0.0.0/9"),
+            ("10.0.0.0/8,10,12", "10.0.0.0/16"),
+            ("10.0.0.0/8,10,12", "192.0.2.0/24"),
+        ] {
+            let range = range.parse::<PrefixRange<Ipv4>>()?;
+            let prefix = prefix.parse()?;
+            let mut excluded = range.clone().exclude(prefix).prefixes().collect::<Vec<_>>();
+            excluded.sort_by_key(|p| (p.network(), p.length()));
+            assert_eq!(excluded, brute_force(&range, prefix));
+        }
+        Ok(())
+    }
+}
@@ -1,15 +1,31 @@
-use super::{Bitmask, Hostmask, Netmask, Prefix, PrefixLength};
+use core::iter::FusedIterator;
+
+use super::{Prefix, PrefixLength};
+#[cfg(target_pointer_width = "64")]
+use crate::concrete::Ipv4;
 use crate::{
     error::{err, Error, Kind},
-    traits::{Afi, Prefix as _, PrefixLength as _},
+    traits::{Afi, Prefix as _},
 };
 
 /// Iterator returned by [`Prefix::subprefixes`].
+///
+/// Subprefixes are yielded in ascending order from the front of the
+/// iterator, and in descending order from the back.
+///
+/// The number of subprefixes may exceed [`usize::MAX`] for very short base
+/// prefixes of the IPv6 address family. In that case the [`size_hint`]
+/// is a conservative `(usize::MAX, None)` and [`checked_len`] returns
+/// [`None`]. For this reason [`ExactSizeIterator`] is only implemented for
+/// [`Ipv4`][crate::concrete::Ipv4], and only on 64-bit targets.
+///
+/// [`size_hint`]: Iterator::size_hint
+/// [`checked_len`]: Self::checked_len
 #[derive(Debug, Clone)]
 pub struct Subprefixes<A: Afi> {
     base: Prefix<A>,
-    next: Option<Prefix<A>>,
-    step: Option<Bitmask<A>>,
+    front: Option<Prefix<A>>,
+    back: Option<Prefix<A>>,
 }
 
 impl<A: Afi> Subprefixes<A> {
@@ -17,13 +33,55 @@ impl<A: Afi> Subprefixes<A> {
         if length < base.length() {
             Err(err!(Kind::PrefixLength))
         } else {
-            let next = Some(Prefix::new(base.prefix(), length));
-            let step = length
-                .decrement()
-                .map(Hostmask::from)
-                .map(|hostbits| hostbits & Netmask::from(length))
-                .ok();
-            Ok(Self { base, next, step })
+            Ok(Self {
+                base,
+                front: Some(Prefix::new(base.prefix(), length)),
+                back: Some(Prefix::new(base.broadcast(), length)),
+            })
+        }
+    }
+
+    /// Returns the number of subprefixes remaining in the iterator, or
+    /// [`None`] if that number exceeds [`usize::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{traits::Prefix as _, Ipv6, Prefix};
+    ///
+    /// let prefix = "2001:db8::/32".parse::<Prefix<Ipv6>>()?;
+    ///
+    /// let mut subprefixes = prefix.subprefixes(prefix.new_prefix_length(48)?)?;
+    /// assert_eq!(subprefixes.checked_len(), Some(1 << 16));
+    /// subprefixes.next_back();
+    /// assert_eq!(subprefixes.checked_len(), Some((1 << 16) - 1));
+    ///
+    /// let subprefixes = prefix.subprefixes(prefix.new_prefix_length(128)?)?;
+    /// assert_eq!(subprefixes.checked_len(), None);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[must_use]
+    pub fn checked_len(&self) -> Option<usize> {
+        let Some((front, back)) = self.front.zip(self.back) else {
+            return Some(0);
+        };
+        let front = front.index_within(&self.base).ok()?;
+        let back = back.index_within(&self.base).ok()?;
+        usize::try_from(back.checked_sub(front)?.checked_add(1)?).ok()
+    }
+
+    // Take `next` out of the iterator, replacing it with the result of `step`
+    // unless the front and back of the iterator have met.
+    fn advance<F>(&mut self, next: Prefix<A>, step: F) -> Option<Prefix<A>>
+    where
+        F: FnOnce(Prefix<A>) -> Option<Prefix<A>>,
+    {
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+            None
+        } else {
+            step(next)
         }
     }
 }
@@ -32,18 +90,34 @@ impl<A: Afi> Iterator for Subprefixes<A> {
     type Item = Prefix<A>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.next.take() {
-            self.next = self
-                .step
-                .and_then(|step| next.map_addr(|addr| addr + step))
-                .filter(|prefix| self.base.contains(prefix));
-            Some(next)
-        } else {
-            None
-        }
+        let next = self.front?;
+        // `next` cannot be the last subprefix of `self.base` unless it is also
+        // `self.back`, so the offset is always in range.
+        self.front = self.advance(next, |prefix| prefix.offset(1).ok());
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.checked_len()
+            .map_or((usize::MAX, None), |n| (n, Some(n)))
+    }
+}
+
+impl<A: Afi> DoubleEndedIterator for Subprefixes<A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = self.back?;
+        self.back = self.advance(next, |prefix| prefix.offset(-1).ok());
+        Some(next)
     }
 }
 
+// There are at most 2^32 IPv4 subprefixes of any prefix, which always fits
+// in a 64-bit `usize`.
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Subprefixes<Ipv4> {}
+
+impl<A: Afi> FusedIterator for Subprefixes<A> {}
+
 #[cfg(test)]
 mod tests {
     use crate::{traits::Prefix as _, Any, Ipv4, Ipv6, Prefix};
@@ -78,4 +152,67 @@ mod tests {
         let len = p.new_prefix_length(8).unwrap();
         assert_eq!(p.subprefixes(len).unwrap().count(), 256);
     }
+
+    #[test]
+    fn reversed_subprefixes_ipv4() {
+        let p: Prefix<Ipv4> = "192.0.2.0/24".parse().unwrap();
+        let len = p.new_prefix_length(26).unwrap();
+        let mut subprefixes = p.subprefixes(len).unwrap();
+        assert_eq!(subprefixes.len(), 4);
+        assert_eq!(subprefixes.next_back(), "192.0.2.192/26".parse().ok());
+        assert_eq!(subprefixes.next(), "192.0.2.0/26".parse().ok());
+        assert_eq!(subprefixes.len(), 2);
+        assert_eq!(subprefixes.next_back(), "192.0.2.128/26".parse().ok());
+        assert_eq!(subprefixes.next_back(), "192.0.2.64/26".parse().ok());
+        assert_eq!(subprefixes.len(), 0);
+        assert_eq!(subprefixes.next(), None);
+        assert_eq!(subprefixes.next_back(), None);
+    }
+
+    #[test]
+    fn checked_len_of_default_ipv4() {
+        let p: Prefix<Ipv4> = "0.0.0.0/0".parse().unwrap();
+        let subprefixes = p.subprefixes(p.new_prefix_length(32).unwrap()).unwrap();
+        assert_eq!(subprefixes.checked_len(), usize::try_from(1u64 << 32).ok());
+    }
+
+    #[test]
+    fn checked_len_of_exhausted() {
+        let p: Prefix<Ipv6> = "2001:db8::/127".parse().unwrap();
+        let mut subprefixes = p.subprefixes(p.new_prefix_length(128).unwrap()).unwrap();
+        assert_eq!(subprefixes.checked_len(), Some(2));
+        assert_eq!(subprefixes.nth(1), "2001:db8::1/128".parse().ok());
+        assert_eq!(subprefixes.checked_len(), Some(0));
+        assert_eq!(subprefixes.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn reversed_subprefixes_of_default() {
+        let p: Prefix<Ipv6> = "::/0".parse().unwrap();
+        let mut subprefixes = p.subprefixes(p.new_prefix_length(128).unwrap()).unwrap();
+        assert_eq!(
+            subprefixes.next_back(),
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff/128".parse().ok()
+        );
+        assert_eq!(subprefixes.next(), "::/128".parse().ok());
+        assert_eq!(subprefixes.size_hint(), (usize::MAX, None));
+        assert_eq!(subprefixes.checked_len(), None);
+    }
+
+    #[test]
+    fn checked_len_of_default_ipv6() {
+        let p: Prefix<Ipv6> = "::/0".parse().unwrap();
+        let subprefixes = p.subprefixes(p.new_prefix_length(128).unwrap()).unwrap();
+        assert_eq!(subprefixes.checked_len(), None);
+        assert_eq!(subprefixes.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn reversed_subprefixes_any() {
+        let p: Prefix<Any> = "2001:db8::/32".parse().unwrap();
+        let len = p.new_prefix_length(48).unwrap();
+        let subprefixes = p.subprefixes(len).unwrap();
+        assert_eq!(subprefixes.checked_len(), Some(1 << 16));
+        assert_eq!(subprefixes.rev().nth(1), "2001:db8:fffe::/48".parse().ok());
+    }
 }
@@ -5,6 +5,7 @@ use core::fmt;
 pub struct Error {
     kind: Kind,
     msg: Option<&'static str>,
+    position: Option<usize>,
     source: Option<SourceError>,
 }
 
@@ -17,10 +18,16 @@ impl Error {
         Self {
             kind,
             msg: msg.map(S::as_ref),
+            position: None,
             source,
         }
     }
 
+    pub(crate) const fn at(mut self, position: usize) -> Self {
+        self.position = Some(position);
+        self
+    }
+
     /// Returns the [`Kind`] of error.
     ///
     /// # Examples
@@ -35,15 +42,39 @@ impl Error {
     pub const fn kind(&self) -> Kind {
         self.kind
     }
+
+    /// Returns the byte offset into the input at which parsing failed, if
+    /// known.
+    ///
+    /// This is always available for errors of kind
+    /// [`ParserError`][Kind::ParserError].
+    ///
+    /// # Examples
+    ///
+    /// ``` rust
+    /// use ip::{Address, Ipv4};
+    ///
+    /// let err = "10.0.0.256".parse::<Address<Ipv4>>().unwrap_err();
+    /// assert_eq!(err.position(), Some(7));
+    /// assert_eq!(err.to_string(), "parser error: number out of range at offset 7");
+    /// ```
+    #[must_use]
+    pub const fn position(&self) -> Option<usize> {
+        self.position
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(msg) = self.msg {
-            write!(f, "{}: {}", self.kind, msg)
+            write!(f, "{}: {}", self.kind, msg)?;
         } else {
-            self.kind.fmt(f)
+            self.kind.fmt(f)?;
+        }
+        if let Some(position) = self.position {
+            write!(f, " at offset {position}")?;
         }
+        Ok(())
     }
 }
 
@@ -68,7 +99,11 @@ impl Error {
 }
 
 /// The "kind" of an [`Error`].
+///
+/// New kinds may be added in future releases, so matches on [`Kind`] must
+/// include a wildcard arm.
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Kind {
     /// An [`Error`] resulting from an operation on a prefix-length.
     PrefixLength,
@@ -82,6 +117,21 @@ pub enum Kind {
     /// An [`Error`] resulting from an attempt to construct an address from a
     /// byte-slice too long for the address family.
     OctetSliceOverrun,
+    /// An [`Error`] resulting from an attempt to construct an address range
+    /// with a start address greater than its end address.
+    AddressRangeBounds,
+    /// An [`Error`] resulting from arithmetic on an address or prefix that
+    /// would exceed the bounds of the address family.
+    Overflow,
+    /// An [`Error`] resulting from an operation requiring a prefix to be
+    /// contained within another prefix, where it is not.
+    PrefixNotContained,
+    /// An [`Error`] resulting from an attempt to convert an address range
+    /// that does not correspond exactly to a single prefix.
+    AddressRangeNotPrefix,
+    /// An [`Error`] resulting from an attempt to construct a prefix from an
+    /// address with bits set beyond the prefix length.
+    NonZeroHostBits,
 }
 
 impl fmt::Display for Kind {
@@ -94,6 +144,11 @@ impl fmt::Display for Kind {
             Self::AfiMismatch => write!(f, "address family mis-match"),
             Self::PrefixLengthRange => write!(f, "invalid prefix-length range"),
             Self::OctetSliceOverrun => write!(f, "octet slice too long for address-family"),
+            Self::AddressRangeBounds => write!(f, "address range start greater than end"),
+            Self::Overflow => write!(f, "address-family bounds exceeded"),
+            Self::PrefixNotContained => write!(f, "prefix not contained by parent prefix"),
+            Self::AddressRangeNotPrefix => write!(f, "address range is not a single prefix"),
+            Self::NonZeroHostBits => write!(f, "host bits set in prefix address"),
         }
     }
 }
@@ -101,7 +156,7 @@ impl fmt::Display for Kind {
 #[cfg(feature = "std")]
 type SourceError = &'static (dyn std::error::Error + Send + Sync + 'static);
 #[cfg(not(feature = "std"))]
-type SourceError = &'static (dyn core::any::Any);
+type SourceError = &'static dyn core::any::Any;
 
 macro_rules! err {
     ( $kind:expr ) => {
@@ -0,0 +1,455 @@
+//!
+//! Each sub-module of this module documents the textual representations
+//! accepted by the [`FromStr`][core::str::FromStr] implementation of a
+//! single type, and the canonical form emitted by the corresponding
+//! [`Display`][core::fmt::Display] implementation.
+//!
+//! Every table is generated from the same list of entries that is used to
+//! drive that type's conformance tests, so the documentation cannot drift
+//! from the behaviour of the parser and formatter.
+//!
+//! In each table, the "Result" column contains either `Ok`, in which case
+//! the "Display" column contains the canonical text form of the parsed
+//! value, or the [`Kind`][crate::error::Kind] of the resulting error.
+//!
+//! Parsing is strict: leading or trailing whitespace, and any other
+//! trailing characters, are rejected. The only exceptions are the list and
+//! range formats whose tables say otherwise: address ranges allow
+//! whitespace around the `-` separator, and prefix sets are lists of items
+//! separated by whitespace and/or commas.
+//!
+//! Some lenient or scoped forms are accepted by dedicated constructors,
+//! rather than by [`FromStr`][core::str::FromStr], and so are rejected in
+//! these tables:
+//!
+//! - IPv4 addresses with leading zeros or fewer than four octets, by
+//!   [`Address::<Ipv4>::parse_with()`][crate::concrete::Address::parse_with];
+//! - IPv6 addresses with a `%`-separated zone identifier, by
+//!   [`Address::<Ipv6>::parse_scoped()`][crate::concrete::Address::parse_scoped];
+//! - bracketed IPv6 addresses, by
+//!   [`Address::<Ipv6>::from_bracketed()`][crate::concrete::Address::from_bracketed];
+//! - reverse DNS names, by
+//!   [`Address::<A>::from_reverse_dns()`][crate::concrete::Address::from_reverse_dns]
+//!   and
+//!   [`Prefix::<A>::from_reverse_dns()`][crate::concrete::Prefix::from_reverse_dns].
+
+macro_rules! row {
+    ( $input:literal, Ok($display:literal) ) => {
+        concat!("| `", $input, "` | `Ok` | `", $display, "` |")
+    };
+    ( $input:literal, Err($kind:ident) ) => {
+        concat!(
+            "| `",
+            $input,
+            "` | [`",
+            stringify!($kind),
+            "`][crate::error::Kind::",
+            stringify!($kind),
+            "] | |"
+        )
+    };
+}
+
+#[cfg(test)]
+macro_rules! check {
+    ( $ty:ty, $input:literal, Ok($display:literal) ) => {
+        match $input.parse::<$ty>() {
+            Ok(parsed) => assert_eq!(parsed.to_string(), $display, "displaying {:?}", $input),
+            Err(err) => panic!("failed to parse {:?}: {}", $input, err),
+        }
+    };
+    ( $ty:ty, $input:literal, Err($kind:ident) ) => {
+        match $input.parse::<$ty>() {
+            Ok(parsed) => panic!("{:?} unexpectedly parsed as {}", $input, parsed),
+            Err(err) => {
+                assert_eq!(err.kind(), Kind::$kind, "parsing {:?}", $input);
+                // parser errors always carry a position
+                if err.kind() == Kind::ParserError {
+                    assert!(err.position().is_some(), "parsing {:?}", $input);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! formats {
+    ( $(
+        $( #[$attr:meta] )*
+        $name:ident: $ty:ty {
+            $( $input:literal => $res:ident($out:tt) ),* $(,)?
+        }
+    )* ) => {
+        $(
+            $( #[$attr] )*
+            ///
+            /// | Input | Result | Display |
+            /// |-------|--------|---------|
+            $( #[doc = row!($input, $res($out))] )*
+            pub mod $name {
+                #[cfg(test)]
+                mod tests {
+                    use std::string::ToString as _;
+
+                    use crate::error::Kind;
+
+                    #[test]
+                    fn conformance() {
+                        $( check!($ty, $input, $res($out)); )*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+formats! {
+    /// Text formats of [`Address<Ipv4>`][crate::concrete::Address].
+    ///
+    /// IPv4 addresses are written in dotted-quad notation. Each octet is a
+    /// decimal integer no greater than `255`.
+    ipv4_address: crate::Address<crate::Ipv4> {
+        "192.0.2.1" => Ok("192.0.2.1"),
+        "0.0.0.0" => Ok("0.0.0.0"),
+        "255.255.255.255" => Ok("255.255.255.255"),
+        "192.0.2.256" => Err(ParserError),
+        "192.0.2" => Err(ParserError),
+        "192.0.2.1.1" => Err(ParserError),
+        "192.0.2.1/32" => Err(ParserError),
+        " 192.0.2.1" => Err(ParserError),
+        "010.0.0.1" => Err(ParserError),
+        "192.0.2.1:80" => Err(ParserError),
+        "2001:db8::1" => Err(ParserError),
+    }
+
+    /// Text formats of [`Address<Ipv6>`][crate::concrete::Address].
+    ///
+    /// IPv6 addresses are written as described in [RFC 4291 Section 2.2],
+    /// and displayed in the canonical form described in [RFC 5952].
+    ///
+    /// [RFC 4291 Section 2.2]: https://tools.ietf.org/html/rfc4291#section-2.2
+    /// [RFC 5952]: https://tools.ietf.org/html/rfc5952
+    ipv6_address: crate::Address<crate::Ipv6> {
+        "2001:db8::1" => Ok("2001:db8::1"),
+        "2001:0db8:0000:0000:0000:0000:0000:0001" => Ok("2001:db8::1"),
+        "2001:DB8::1" => Ok("2001:db8::1"),
+        "2001:db8:0:0:1:0:0:1" => Ok("2001:db8::1:0:0:1"),
+        "::" => Ok("::"),
+        "::1" => Ok("::1"),
+        "::ffff:192.0.2.1" => Ok("::ffff:192.0.2.1"),
+        "64:ff9b::192.0.2.1" => Ok("64:ff9b::c000:201"),
+        "2001:db8::1::1" => Err(ParserError),
+        "2001:db8:0:0:0:0:0:0:1" => Err(ParserError),
+        "12345::" => Err(ParserError),
+        "fe80::1%eth0" => Err(ParserError),
+        "[2001:db8::1]" => Err(ParserError),
+        "[2001:db8::1]:80" => Err(ParserError),
+        "192.0.2.1" => Err(ParserError),
+    }
+
+    /// Text formats of [`Address<Any>`][crate::any::Address].
+    ///
+    /// The input is parsed as an IPv4 address, or failing that, as an IPv6
+    /// address.
+    any_address: crate::Address<crate::Any> {
+        "192.0.2.1" => Ok("192.0.2.1"),
+        "2001:db8::1" => Ok("2001:db8::1"),
+        "::ffff:192.0.2.1" => Ok("::ffff:192.0.2.1"),
+        "192.0.2.256" => Err(ParserError),
+        "2001:db8::1::1" => Err(ParserError),
+    }
+
+    /// Text formats of [`AddressRange<Ipv4>`][crate::concrete::AddressRange].
+    ///
+    /// Address ranges are parsed from a pair of addresses separated by `-`,
+    /// optionally surrounded by whitespace, or from a single address. They
+    /// are displayed as `start-end`.
+    ipv4_address_range: crate::concrete::AddressRange<crate::Ipv4> {
+        "192.0.2.10-192.0.2.20" => Ok("192.0.2.10-192.0.2.20"),
+        "192.0.2.10 - 192.0.2.20" => Ok("192.0.2.10-192.0.2.20"),
+        "192.0.2.10" => Ok("192.0.2.10-192.0.2.10"),
+        "192.0.2.20-192.0.2.10" => Err(AddressRangeBounds),
+        "192.0.2.10-" => Err(ParserError),
+        "192.0.2.0/24" => Err(ParserError),
+    }
+
+    /// Text formats of [`AddressRange<Ipv6>`][crate::concrete::AddressRange].
+    ///
+    /// Address ranges are parsed from a pair of addresses separated by `-`,
+    /// optionally surrounded by whitespace, or from a single address. They
+    /// are displayed as `start-end`.
+    ipv6_address_range: crate::concrete::AddressRange<crate::Ipv6> {
+        "2001:db8::1-2001:db8::ff" => Ok("2001:db8::1-2001:db8::ff"),
+        "2001:db8::1 -2001:DB8::FF" => Ok("2001:db8::1-2001:db8::ff"),
+        "2001:db8::ff-2001:db8::1" => Err(AddressRangeBounds),
+        "192.0.2.10-192.0.2.20" => Err(ParserError),
+    }
+
+    /// Text formats of [`AddressRange`][crate::any::AddressRange].
+    ///
+    /// The input is parsed as an IPv6 address range if it contains a `:`,
+    /// and as an IPv4 address range otherwise.
+    any_address_range: crate::any::AddressRange {
+        "192.0.2.10-192.0.2.20" => Ok("192.0.2.10-192.0.2.20"),
+        "2001:db8::1 - 2001:db8::ff" => Ok("2001:db8::1-2001:db8::ff"),
+        "192.0.2.10" => Ok("192.0.2.10-192.0.2.10"),
+        "192.0.2.20-192.0.2.10" => Err(AddressRangeBounds),
+        "2001:db8::ff-2001:db8::1" => Err(AddressRangeBounds),
+        "192.0.2.10-2001:db8::1" => Err(ParserError),
+    }
+
+    /// Text formats of [`Netmask<Ipv4>`][crate::concrete::Netmask].
+    ///
+    /// Netmasks are written as an address whose bits are a contiguous run
+    /// of ones followed by a contiguous run of zeros.
+    ipv4_netmask: crate::Netmask<crate::Ipv4> {
+        "255.255.255.0" => Ok("255.255.255.0"),
+        "0.0.0.0" => Ok("0.0.0.0"),
+        "255.255.255.255" => Ok("255.255.255.255"),
+        "255.0.255.0" => Err(ParserError),
+        "0.0.0.255" => Err(ParserError),
+        "/24" => Err(ParserError),
+    }
+
+    /// Text formats of [`Netmask<Ipv6>`][crate::concrete::Netmask].
+    ///
+    /// Netmasks are written as an address whose bits are a contiguous run
+    /// of ones followed by a contiguous run of zeros.
+    ipv6_netmask: crate::Netmask<crate::Ipv6> {
+        "ffff:ffff:ffff::" => Ok("ffff:ffff:ffff::"),
+        "FFFF:FFFF::" => Ok("ffff:ffff::"),
+        "::" => Ok("::"),
+        "ffff::ffff" => Err(ParserError),
+        "255.255.255.0" => Err(ParserError),
+    }
+
+    /// Text formats of [`Netmask<Any>`][crate::any::Netmask].
+    ///
+    /// The input is parsed as an IPv4 netmask, or failing that, as an IPv6
+    /// netmask.
+    any_netmask: crate::Netmask<crate::Any> {
+        "255.255.255.0" => Ok("255.255.255.0"),
+        "ffff:ffff:ffff::" => Ok("ffff:ffff:ffff::"),
+        "255.255.0.255" => Err(ParserError),
+    }
+
+    /// Text formats of [`Hostmask<Ipv4>`][crate::concrete::Hostmask].
+    ///
+    /// Hostmasks are written as an address whose bits are a contiguous run
+    /// of zeros followed by a contiguous run of ones.
+    ipv4_hostmask: crate::Hostmask<crate::Ipv4> {
+        "0.0.0.255" => Ok("0.0.0.255"),
+        "0.0.0.0" => Ok("0.0.0.0"),
+        "255.255.255.255" => Ok("255.255.255.255"),
+        "255.255.255.0" => Err(ParserError),
+        "0.255.0.255" => Err(ParserError),
+    }
+
+    /// Text formats of [`Hostmask<Ipv6>`][crate::concrete::Hostmask].
+    ///
+    /// Hostmasks are written as an address whose bits are a contiguous run
+    /// of zeros followed by a contiguous run of ones.
+    ipv6_hostmask: crate::Hostmask<crate::Ipv6> {
+        "::ffff:ffff:ffff:ffff" => Ok("::ffff:ffff:ffff:ffff"),
+        "::ffff:ffff" => Ok("::255.255.255.255"),
+        "::" => Ok("::"),
+        "ffff::" => Err(ParserError),
+    }
+
+    /// Text formats of [`Hostmask<Any>`][crate::any::Hostmask].
+    ///
+    /// The input is parsed as an IPv4 hostmask, or failing that, as an IPv6
+    /// hostmask.
+    any_hostmask: crate::Hostmask<crate::Any> {
+        "0.0.0.255" => Ok("0.0.0.255"),
+        "::ffff:ffff:ffff:ffff" => Ok("::ffff:ffff:ffff:ffff"),
+        "255.255.255.0" => Err(ParserError),
+    }
+
+    /// Text formats of [`PrefixLength<Ipv4>`][crate::concrete::PrefixLength].
+    ///
+    /// Prefix lengths are parsed with or without a leading `/`, and
+    /// displayed without.
+    ipv4_prefix_length: crate::PrefixLength<crate::Ipv4> {
+        "/24" => Ok("24"),
+        "24" => Ok("24"),
+        "/0" => Ok("0"),
+        "/32" => Ok("32"),
+        "/33" => Err(PrefixLength),
+        "256" => Err(PrefixLength),
+        "1000" => Err(PrefixLength),
+        "024" => Err(ParserError),
+        " 24" => Err(ParserError),
+        "//24" => Err(ParserError),
+    }
+
+    /// Text formats of [`PrefixLength<Ipv6>`][crate::concrete::PrefixLength].
+    ///
+    /// Prefix lengths are parsed with or without a leading `/`, and
+    /// displayed without.
+    ipv6_prefix_length: crate::PrefixLength<crate::Ipv6> {
+        "/48" => Ok("48"),
+        "48" => Ok("48"),
+        "/128" => Ok("128"),
+        "/129" => Err(PrefixLength),
+        "48 " => Err(ParserError),
+    }
+
+    /// Text formats of [`Prefix<Ipv4>`][crate::concrete::Prefix].
+    ///
+    /// Prefixes are written in CIDR notation. Any host bits set in the
+    /// address are cleared.
+    ipv4_prefix: crate::Prefix<crate::Ipv4> {
+        "192.0.2.0/24" => Ok("192.0.2.0/24"),
+        "192.0.2.1/24" => Ok("192.0.2.0/24"),
+        "0.0.0.0/0" => Ok("0.0.0.0/0"),
+        "192.0.2.1/32" => Ok("192.0.2.1/32"),
+        "192.0.2.0/33" => Err(PrefixLength),
+        "192.0.2.0" => Err(ParserError),
+        "192.0.2.0/" => Err(ParserError),
+        "2001:db8::/32" => Err(ParserError),
+    }
+
+    /// Text formats of [`Prefix<Ipv6>`][crate::concrete::Prefix].
+    ///
+    /// Prefixes are written in CIDR notation. Any host bits set in the
+    /// address are cleared.
+    ipv6_prefix: crate::Prefix<crate::Ipv6> {
+        "2001:db8::/32" => Ok("2001:db8::/32"),
+        "2001:db8::1/32" => Ok("2001:db8::/32"),
+        "::/0" => Ok("::/0"),
+        "2001:db8::/129" => Err(PrefixLength),
+        "2001:db8::" => Err(ParserError),
+        "192.0.2.0/24" => Err(ParserError),
+    }
+
+    /// Text formats of [`Prefix<Any>`][crate::any::Prefix].
+    ///
+    /// The input is parsed as an IPv4 prefix, or failing that, as an IPv6
+    /// prefix. As a result, errors are those of the IPv6 parser.
+    any_prefix: crate::Prefix<crate::Any> {
+        "192.0.2.0/24" => Ok("192.0.2.0/24"),
+        "2001:db8::1/32" => Ok("2001:db8::/32"),
+        "192.0.2.0/33" => Err(ParserError),
+        "2001:db8::/129" => Err(PrefixLength),
+    }
+
+    /// Text formats of [`Interface<Ipv4>`][crate::concrete::Interface].
+    ///
+    /// Interfaces are written in CIDR notation. Unlike prefixes, any host
+    /// bits set in the address are retained.
+    ipv4_interface: crate::Interface<crate::Ipv4> {
+        "192.0.2.1/24" => Ok("192.0.2.1/24"),
+        "192.0.2.0/24" => Ok("192.0.2.0/24"),
+        "192.0.2.1/33" => Err(PrefixLength),
+        "192.0.2.1" => Err(ParserError),
+    }
+
+    /// Text formats of [`Interface<Ipv6>`][crate::concrete::Interface].
+    ///
+    /// Interfaces are written in CIDR notation. Unlike prefixes, any host
+    /// bits set in the address are retained.
+    ipv6_interface: crate::Interface<crate::Ipv6> {
+        "2001:db8::1/64" => Ok("2001:db8::1/64"),
+        "2001:db8::1/129" => Err(PrefixLength),
+        "2001:db8::1" => Err(ParserError),
+        "fe80::1%eth0/64" => Err(ParserError),
+    }
+
+    /// Text formats of [`Interface<Any>`][crate::any::Interface].
+    ///
+    /// The input is parsed as an IPv4 interface, or failing that, as an IPv6
+    /// interface.
+    any_interface: crate::Interface<crate::Any> {
+        "192.0.2.1/24" => Ok("192.0.2.1/24"),
+        "2001:db8::1/64" => Ok("2001:db8::1/64"),
+        "192.0.2.1" => Err(ParserError),
+    }
+
+    /// Text formats of [`PrefixRange<Ipv4>`][crate::concrete::PrefixRange].
+    ///
+    /// Prefix ranges are parsed from a prefix followed by comma-separated
+    /// lower and upper length bounds, and displayed as the prefix followed
+    /// by `^lower-upper`.
+    ipv4_prefix_range: crate::PrefixRange<crate::Ipv4> {
+        "192.0.2.0/24,25,26" => Ok("192.0.2.0/24^25-26"),
+        "192.0.2.0/24,24,24" => Ok("192.0.2.0/24^24-24"),
+        "192.0.2.0/24,24,32" => Ok("192.0.2.0/24^24-32"),
+        "192.0.2.0/24,23,24" => Err(PrefixLengthRange),
+        "192.0.2.0/24,26,25" => Err(PrefixLengthRange),
+        "192.0.2.0/24,25,33" => Err(PrefixLength),
+        "192.0.2.0/24" => Err(ParserError),
+    }
+
+    /// Text formats of [`PrefixRange<Ipv6>`][crate::concrete::PrefixRange].
+    ///
+    /// Prefix ranges are parsed from a prefix followed by comma-separated
+    /// lower and upper length bounds, and displayed as the prefix followed
+    /// by `^lower-upper`.
+    ipv6_prefix_range: crate::PrefixRange<crate::Ipv6> {
+        "2001:db8::/32,48,64" => Ok("2001:db8::/32^48-64"),
+        "2001:db8::/32,128,128" => Ok("2001:db8::/32^128-128"),
+        "2001:db8::/32,16,32" => Err(PrefixLengthRange),
+        "2001:db8::/32,48,129" => Err(PrefixLength),
+    }
+
+    /// Text formats of [`PrefixRange<Any>`][crate::any::PrefixRange].
+    ///
+    /// The input is parsed as an IPv4 prefix range, or failing that, as an
+    /// IPv6 prefix range.
+    any_prefix_range: crate::PrefixRange<crate::Any> {
+        "192.0.2.0/24,25,26" => Ok("192.0.2.0/24^25-26"),
+        "2001:db8::/32,48,64" => Ok("2001:db8::/32^48-64"),
+        "192.0.2.0/24" => Err(ParserError),
+    }
+
+    /// Text formats of [`PrefixSet<Ipv4>`][crate::concrete::PrefixSet].
+    ///
+    /// Prefix sets are parsed from a list of prefixes and prefix ranges,
+    /// separated by whitespace and/or commas, with a prefix range written as
+    /// in the [`PrefixRange<Ipv4>`](ipv4_prefix_range) table above. The
+    /// items are aggregated, and displayed one prefix range per line (only
+    /// single-line results are shown here).
+    ///
+    /// Errors are reported at the byte offset of the offending item.
+    #[cfg(feature = "std")]
+    ipv4_prefix_set: crate::PrefixSet<crate::Ipv4> {
+        "192.0.2.0/24" => Ok("192.0.2.0/24,24,24"),
+        "192.0.2.0/25 192.0.2.128/25" => Ok("192.0.2.0/24,25,25"),
+        "192.0.2.0/24,25,26, 192.0.2.0/24,24,24" => Ok("192.0.2.0/24,24,26"),
+        "" => Ok(""),
+        "192.0.2.0/24 192.0.2.0/33" => Err(PrefixLength),
+        "10.0.0.0/8,4,8" => Err(PrefixLengthRange),
+        "192.0.2.0/24 2001:db8::/32" => Err(AfiMismatch),
+        "192.0.2.0/24;" => Err(ParserError),
+    }
+
+    /// Text formats of [`PrefixSet<Ipv6>`][crate::concrete::PrefixSet].
+    ///
+    /// Prefix sets are parsed from a list of prefixes and prefix ranges,
+    /// separated by whitespace and/or commas. The items are aggregated, and
+    /// displayed one prefix range per line (only single-line results are
+    /// shown here).
+    #[cfg(feature = "std")]
+    ipv6_prefix_set: crate::PrefixSet<crate::Ipv6> {
+        "2001:db8::/32" => Ok("2001:db8::/32,32,32"),
+        "2001:db8::/32,48,48 2001:db8::/32,33,47" => Ok("2001:db8::/32,33,48"),
+        "2001:db8::/129" => Err(PrefixLength),
+        "192.0.2.0/24" => Err(AfiMismatch),
+    }
+
+    /// Text formats of [`PrefixSet<Any>`][crate::any::PrefixSet].
+    ///
+    /// Each item in the list may be of either address family. The items are
+    /// aggregated, and displayed one prefix range per line with all IPv4
+    /// items before any IPv6 items (only single-line results are shown
+    /// here).
+    #[cfg(feature = "std")]
+    any_prefix_set: crate::PrefixSet<crate::Any> {
+        "192.0.2.0/24" => Ok("192.0.2.0/24,24,24"),
+        "2001:db8::/32 2001:db8::/32,33,33" => Ok("2001:db8::/32,32,33"),
+        "192.0.2.0/24 10.0.0.0/33" => Err(PrefixLength),
+        "10.0.0.0/8,4,8" => Err(PrefixLengthRange),
+        "2001:db8::/32, 2001:db8::/129" => Err(PrefixLength),
+        "192.0.2.0/24 garbage" => Err(ParserError),
+    }
+}
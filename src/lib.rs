@@ -74,6 +74,12 @@
 //! variants corresponding to the two concrete address families, with each
 //! variant containing the corresponding `concrete::*` item.
 //!
+//! Items are converted from [`concrete`] to [`any`] with [`From`], and back
+//! with [`TryFrom`]. The `into_any()` and `into_concrete()` methods (such as
+//! [`concrete::Address::into_any()`] and [`any::Address::into_concrete()`])
+//! are shorthands for these conversions, which spare generic code from
+//! spelling out the conversion bound at each call site.
+//!
 //! ### Address-family classes
 //!
 //! Usually a given use-case will call for *either* processing objects of a
@@ -192,14 +198,13 @@
 //!     filtered
 //! );
 //! ```
-#![doc(html_root_url = "https://docs.rs/generic-ip/0.1.1")]
+#![doc(html_root_url = "https://docs.rs/generic-ip/0.2.0")]
 // clippy lints
 #![warn(clippy::pedantic)]
 #![warn(clippy::cargo)]
 #![warn(clippy::nursery)]
 #![allow(clippy::redundant_pub_crate)]
 // rustc lints
-#![allow(box_pointers)]
 #![warn(absolute_paths_not_starting_with_crate)]
 #![warn(deprecated_in_future)]
 #![warn(elided_lifetimes_in_paths)]
@@ -213,7 +218,6 @@
 #![warn(missing_docs)]
 #![warn(non_ascii_idents)]
 #![warn(noop_method_call)]
-#![warn(pointer_structural_match)]
 #![warn(rust_2021_incompatible_closure_captures)]
 #![warn(rust_2021_incompatible_or_patterns)]
 #![warn(rust_2021_prefixes_incompatible_syntax)]
@@ -234,6 +238,8 @@
 #![warn(variant_size_differences)]
 // docs.rs build config
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(all(feature = "step_trait", nightly), allow(unstable_features))]
+#![cfg_attr(all(feature = "step_trait", nightly), feature(step_trait))]
 // no_std support
 #![no_std]
 #[cfg(feature = "std")]
@@ -253,7 +259,7 @@ pub use self::any::Any;
 
 /// Types for working with IP objects of a specific address family.
 pub mod concrete;
-pub use self::concrete::{Ipv4, Ipv6};
+pub use self::concrete::{Ipv4, Ipv6, Ipv6MulticastScope};
 
 /// Traits describing address family independent interfaces for IP objects.
 pub mod traits;
@@ -263,6 +269,9 @@ pub use self::traits::{Afi, AfiClass};
 pub mod error;
 pub use self::error::Error;
 
+/// Reference tables of the textual representations of IP objects.
+pub mod formats;
+
 /// IP address formatting traits
 mod fmt;
 
@@ -4,35 +4,55 @@ use crate::error::{err, Error, Kind};
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_addr(input: &str) -> Result<u32, Error> {
-    Parser::new(input)
+    let mut parser = Parser::new(input);
+    parser
         .take_only(Parser::take_ipv4_octets)
-        .ok_or_else(|| err!(Kind::ParserError))
+        .ok_or_else(|| parser.error())
+        .map(u32::from_be_bytes)
+}
+
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub(crate) fn parse_addr_with(
+    input: &str,
+    leading_zeros: bool,
+    classful_shorthand: bool,
+) -> Result<u32, Error> {
+    let min = if classful_shorthand { 1 } else { 4 };
+    let mut parser = Parser::new(input);
+    parser
+        .take_only(|p| p.take_ipv4_octets_with(leading_zeros, min))
+        .ok_or_else(|| parser.error())
         .map(u32::from_be_bytes)
 }
 
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_length(input: &str) -> Result<u8, Error> {
-    Parser::new(input)
-        .take_only(Parser::take_length)
-        .ok_or_else(|| err!(Kind::ParserError))
+    let mut parser = Parser::new(input);
+    parser
+        .take_only(Parser::take_bare_length)
+        .ok_or_else(|| parser.error())
+        .and_then(|len| u8::try_from(len).map_err(|_| err!(Kind::PrefixLength)))
 }
 
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_prefix(input: &str) -> Result<(u32, u8), Error> {
-    Parser::new(input)
+    let mut parser = Parser::new(input);
+    parser
         .take_with_length(Parser::take_ipv4_octets)
-        .ok_or_else(|| err!(Kind::ParserError))
+        .ok_or_else(|| parser.error())
         .map(|(octets, len)| (u32::from_be_bytes(octets), len))
 }
 
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_range(input: &str) -> Result<(u32, u8, u8, u8), Error> {
-    Parser::new(input)
+    let mut parser = Parser::new(input);
+    parser
         .take_with_length_range(Parser::take_ipv4_octets)
-        .ok_or_else(|| err!(Kind::ParserError))
+        .ok_or_else(|| parser.error())
         .map(|(octets, len, lower, upper)| (u32::from_be_bytes(octets), len, lower, upper))
 }
 
@@ -63,6 +83,29 @@ mod tests {
         assert!(addr.is_err());
     }
 
+    #[test]
+    fn lenient_leading_zeros() {
+        assert_eq!(
+            parse_addr_with("010.0.0.001", true, false).unwrap(),
+            0x0a00_0001
+        );
+        assert!(parse_addr_with("010.0.0.001", false, false).is_err());
+        assert!(parse_addr_with("0010.0.0.1", true, false).is_err());
+    }
+
+    #[test]
+    fn lenient_classful_shorthand() {
+        assert_eq!(parse_addr_with("10", false, true).unwrap(), 0x0a00_0000);
+        assert_eq!(parse_addr_with("172.16", false, true).unwrap(), 0xac10_0000);
+        assert_eq!(
+            parse_addr_with("192.0.2", false, true).unwrap(),
+            0xc000_0200
+        );
+        assert!(parse_addr_with("192.0.2", false, false).is_err());
+        assert!(parse_addr_with("10.", false, true).is_err());
+        assert!(parse_addr_with("", false, true).is_err());
+    }
+
     #[test]
     fn consume_all_input() {
         let input = "192.168.0.1\0";
@@ -84,10 +127,81 @@ mod tests {
         assert_eq!(length, 24);
     }
 
+    #[test]
+    fn bare_prefix_len() {
+        assert_eq!(parse_length("24").unwrap(), 24);
+        assert_eq!(parse_length("0").unwrap(), 0);
+    }
+
+    #[test]
+    fn prefix_len_overflow() {
+        for input in ["/256", "999", "1000", "/65536", "99999999999999999999"] {
+            assert_eq!(parse_length(input).unwrap_err().kind(), Kind::PrefixLength);
+        }
+        assert_eq!(parse_length("024").unwrap_err().kind(), Kind::ParserError);
+    }
+
+    #[test]
+    fn prefix_len_whitespace() {
+        for input in [" 24", "24 ", "/ 24", "\t24", "24\n"] {
+            assert_eq!(parse_length(input).unwrap_err().kind(), Kind::ParserError);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn failure(input: &str) -> (Option<usize>, std::string::String) {
+        use std::string::ToString as _;
+
+        let err = parse_addr(input).unwrap_err();
+        (err.position(), err.to_string())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn error_positions() {
+        use std::string::ToString as _;
+
+        assert_eq!(
+            failure("192.0.2.256"),
+            (
+                Some(8),
+                "parser error: number out of range at offset 8".to_string()
+            )
+        );
+        assert_eq!(
+            failure("1.01.0.0"),
+            (
+                Some(2),
+                "parser error: unexpected leading zero at offset 2".to_string()
+            )
+        );
+        assert_eq!(
+            failure("192.0.2"),
+            (
+                Some(7),
+                "parser error: expected '.' at offset 7".to_string()
+            )
+        );
+        assert_eq!(
+            failure("192.0.2.1x"),
+            (
+                Some(9),
+                "parser error: expected end of input at offset 9".to_string()
+            )
+        );
+        assert_eq!(
+            failure("192.0.x.1"),
+            (
+                Some(6),
+                "parser error: expected decimal digit at offset 6".to_string()
+            )
+        );
+    }
+
     #[cfg(feature = "std")]
     mod proptests {
         use std::net::Ipv4Addr;
-        use std::string::ToString;
+        use std::string::ToString as _;
 
         use proptest::{arbitrary::any, proptest};
 
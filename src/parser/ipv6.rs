@@ -7,35 +7,50 @@ use crate::{
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_addr(input: &str) -> Result<u128, Error> {
-    Parser::new(input)
+    let mut parser = Parser::new(input);
+    parser
         .take_only(Parser::take_ipv6_segments)
-        .ok_or_else(|| err!(Kind::ParserError))
+        .ok_or_else(|| parser.error())
         .map(u128::from_segments)
 }
 
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub(crate) fn parse_scoped_addr(input: &str) -> Result<(u128, Option<&str>), Error> {
+    let mut parser = Parser::new(input);
+    parser
+        .take_with_zone(Parser::take_ipv6_segments)
+        .ok_or_else(|| parser.error())
+        .map(|(segments, zone)| (u128::from_segments(segments), zone))
+}
+
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_length(input: &str) -> Result<u8, Error> {
-    Parser::new(input)
-        .take_only(Parser::take_length)
-        .ok_or_else(|| err!(Kind::ParserError))
+    let mut parser = Parser::new(input);
+    parser
+        .take_only(Parser::take_bare_length)
+        .ok_or_else(|| parser.error())
+        .and_then(|len| u8::try_from(len).map_err(|_| err!(Kind::PrefixLength)))
 }
 
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_prefix(input: &str) -> Result<(u128, u8), Error> {
-    Parser::new(input)
+    let mut parser = Parser::new(input);
+    parser
         .take_with_length(Parser::take_ipv6_segments)
-        .ok_or_else(|| err!(Kind::ParserError))
+        .ok_or_else(|| parser.error())
         .map(|(segments, len)| (u128::from_segments(segments), len))
 }
 
 #[allow(clippy::inline_always)]
 #[inline(always)]
 pub(crate) fn parse_range(input: &str) -> Result<(u128, u8, u8, u8), Error> {
-    Parser::new(input)
+    let mut parser = Parser::new(input);
+    parser
         .take_with_length_range(Parser::take_ipv6_segments)
-        .ok_or_else(|| err!(Kind::ParserError))
+        .ok_or_else(|| parser.error())
         .map(|(segments, len, lower, upper)| (u128::from_segments(segments), len, lower, upper))
 }
 
@@ -92,6 +107,40 @@ mod tests {
         assert!(addr.is_err());
     }
 
+    #[test]
+    fn scoped() {
+        assert_eq!(
+            parse_scoped_addr("fe80::1%eth0").unwrap(),
+            (0xfe80_0000_0000_0000_0000_0000_0000_0001, Some("eth0"))
+        );
+        assert_eq!(
+            parse_scoped_addr("fe80::1%1").unwrap(),
+            (0xfe80_0000_0000_0000_0000_0000_0000_0001, Some("1"))
+        );
+        assert_eq!(
+            parse_scoped_addr("fe80::1").unwrap(),
+            (0xfe80_0000_0000_0000_0000_0000_0000_0001, None)
+        );
+    }
+
+    #[test]
+    fn scoped_empty_zone() {
+        let err = parse_scoped_addr("fe80::1%").unwrap_err();
+        assert_eq!(err.position(), Some(8));
+    }
+
+    #[test]
+    fn unscoped_rejects_zone() {
+        assert!(parse_addr("fe80::1%eth0").is_err());
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(parse_addr("1:0ffff::").unwrap_err().position(), Some(6));
+        assert_eq!(parse_addr("2001:db8::g").unwrap_err().position(), Some(10));
+        assert_eq!(parse_addr("2001:db8:1").unwrap_err().position(), Some(10));
+    }
+
     #[test]
     fn disallow_excess_parts() {
         let input = "1:2::4:5:6:7:8:9";
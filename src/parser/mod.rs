@@ -1,6 +1,17 @@
+use crate::error::{err, Error, Kind};
+
 pub(crate) mod ipv4;
 pub(crate) mod ipv6;
 
+#[allow(clippy::inline_always)]
+#[inline(always)]
+pub(crate) fn parse_port(input: &str) -> Result<u16, Error> {
+    let mut parser = Parser::new(input);
+    parser
+        .take_only(|p| p.take_number(10, 5, false))
+        .ok_or_else(|| parser.error())
+}
+
 trait Number: Eq + Sized {
     const ZERO: Self;
     fn checked_add(self, rhs: u8) -> Option<Self>;
@@ -27,13 +38,17 @@ impl_number! { u8: 1, u16: 2 }
 
 #[derive(Debug)]
 struct Parser<'a> {
+    len: usize,
     state: &'a [u8],
+    failure: Option<(usize, &'static str)>,
 }
 
 impl<'a> Parser<'a> {
     const fn new(input: &'a str) -> Self {
         Self {
+            len: input.len(),
             state: input.as_bytes(),
+            failure: None,
         }
     }
 
@@ -41,6 +56,33 @@ impl<'a> Parser<'a> {
         self.state.is_empty()
     }
 
+    const fn offset(&self) -> usize {
+        self.len - self.state.len()
+    }
+
+    // Record a failure at `offset`, unless a failure has already been
+    // recorded further into the input.
+    //
+    // Because of backtracking, the furthest failure is the best available
+    // indication of where the input is actually malformed.
+    fn fail_at<T>(&mut self, offset: usize, expected: &'static str) -> Option<T> {
+        if self.failure.is_none_or(|(furthest, _)| offset >= furthest) {
+            self.failure = Some((offset, expected));
+        }
+        None
+    }
+
+    fn fail<T>(&mut self, expected: &'static str) -> Option<T> {
+        self.fail_at(self.offset(), expected)
+    }
+
+    fn error(&self) -> Error {
+        match self.failure {
+            Some((offset, expected)) => err!(Kind::ParserError, expected).at(offset),
+            None => err!(Kind::ParserError).at(self.offset()),
+        }
+    }
+
     fn atomically<F, T>(&mut self, f: F) -> Option<T>
     where
         F: FnOnce(&mut Self) -> Option<T>,
@@ -61,19 +103,40 @@ impl<'a> Parser<'a> {
     }
 
     fn skip(&mut self, bytes: &[u8]) -> Option<&mut Self> {
-        bytes
-            .iter()
-            .try_for_each(|c| self.take().and_then(|next| next.eq(c).then_some(())))
-            .map(|()| self)
+        if let Some(tail) = self.state.strip_prefix(bytes) {
+            self.state = tail;
+            Some(self)
+        } else {
+            self.fail(match bytes {
+                b"." => "expected '.'",
+                b":" => "expected ':'",
+                b"::" => "expected '::'",
+                b"/" => "expected '/'",
+                b"," => "expected ','",
+                b"%" => "expected '%'",
+                _ => "expected separator",
+            })
+        }
     }
 
     fn take_digit(&mut self, radix: u8) -> Option<u8> {
+        let offset = self.offset();
         match self.take() {
             Some(val @ b'0'..=b'9') => Some(val & 0x0f),
             Some(val @ (b'a'..=b'f' | b'A'..=b'F')) if radix > 10 => Some((val & 0x0f) + 0x09),
             _ => None,
         }
         .filter(|val| val < &radix)
+        .or_else(|| {
+            self.fail_at(
+                offset,
+                if radix > 10 {
+                    "expected hexadecimal digit"
+                } else {
+                    "expected decimal digit"
+                },
+            )
+        })
     }
 
     fn take_number<T>(&mut self, radix: u8, max_digits: usize, leading_zeros: bool) -> Option<T>
@@ -82,14 +145,19 @@ impl<'a> Parser<'a> {
     {
         let mut result = T::ZERO;
         let mut digits: usize = 0;
+        let start = self.offset();
         while let Some(digit) = self.atomically(|p| p.take_digit(radix)) {
             if !leading_zeros && digits > 0 && result == T::ZERO {
-                return None;
+                return self.fail_at(start, "unexpected leading zero");
             }
             digits += 1;
-            result = result
+            result = match result
                 .checked_mul(radix)
-                .and_then(|val| val.checked_add(digit))?;
+                .and_then(|val| val.checked_add(digit))
+            {
+                Some(val) => val,
+                None => return self.fail_at(start, "number out of range"),
+            };
             if digits == max_digits {
                 break;
             }
@@ -118,11 +186,17 @@ impl<'a> Parser<'a> {
     }
 
     fn take_ipv4_octets(&mut self) -> Option<[u8; 4]> {
+        self.take_ipv4_octets_with(false, 4)
+    }
+
+    // Parse at least `min` and at most four dot-separated octets, filling
+    // any missing trailing octets with zeros.
+    fn take_ipv4_octets_with(&mut self, leading_zeros: bool, min: usize) -> Option<[u8; 4]> {
         let mut buf = [0; 4];
         (self.take_separated(b".", buf.len(), |p, i| {
-            buf[i] = p.take_number(10, 3, false)?;
+            buf[i] = p.take_number(10, 3, leading_zeros)?;
             Some((1, true))
-        }) == 4)
+        }) >= min)
             .then_some(buf)
     }
 
@@ -167,12 +241,65 @@ impl<'a> Parser<'a> {
         self.skip(b"/").and_then(|p| p.take_number(10, 3, false))
     }
 
+    // Parse a prefix length that may stand alone, without an address before
+    // it. The leading '/' is optional. Any number of digits is accepted, and
+    // the result saturates rather than overflowing, so that an out-of-range
+    // length can be told apart from malformed input.
+    fn take_bare_length(&mut self) -> Option<u16> {
+        if self.state.starts_with(b"/") {
+            _ = self.skip(b"/");
+        }
+        let start = self.offset();
+        let mut len: Option<u16> = None;
+        while let Some(digit) = self.atomically(|p| p.take_digit(10)) {
+            len = match len {
+                Some(0) => return self.fail_at(start, "unexpected leading zero"),
+                Some(len) => Some(len.saturating_mul(10).saturating_add(digit.into())),
+                None => Some(digit.into()),
+            };
+        }
+        len
+    }
+
     fn take_only<F, T>(&mut self, mut f: F) -> Option<T>
     where
         F: FnMut(&mut Self) -> Option<T>,
     {
         let result = f(self)?;
-        self.is_eof().then_some(result)
+        self.take_eof()?;
+        Some(result)
+    }
+
+    fn take_eof(&mut self) -> Option<()> {
+        if self.is_eof() {
+            Some(())
+        } else {
+            self.fail("expected end of input")
+        }
+    }
+
+    fn take_zone(&mut self) -> Option<&'a str> {
+        _ = self.skip(b"%")?;
+        if self.is_eof() {
+            return self.fail("expected zone identifier");
+        }
+        // the remaining input begins immediately after an ASCII '%', so is
+        // valid UTF-8
+        let zone = core::str::from_utf8(self.state).ok()?;
+        self.state = &[];
+        Some(zone)
+    }
+
+    fn take_with_zone<F, T>(&mut self, mut f: F) -> Option<(T, Option<&'a str>)>
+    where
+        F: FnMut(&mut Self) -> Option<T>,
+    {
+        let result = f(self)?;
+        if self.is_eof() {
+            Some((result, None))
+        } else {
+            self.take_zone().map(|zone| (result, Some(zone)))
+        }
     }
 
     fn take_with_length<F, T>(&mut self, mut f: F) -> Option<(T, u8)>
@@ -181,7 +308,8 @@ impl<'a> Parser<'a> {
     {
         let result = f(self)?;
         let len = self.take_length()?;
-        self.is_eof().then_some((result, len))
+        self.take_eof()?;
+        Some((result, len))
     }
 
     fn take_with_length_range<F, T>(&mut self, mut f: F) -> Option<(T, u8, u8, u8)>
@@ -198,6 +326,7 @@ impl<'a> Parser<'a> {
                 .then_some(())
         })?;
         let [len, lower, upper] = buf;
-        self.is_eof().then_some((result, len, lower, upper))
+        self.take_eof()?;
+        Some((result, len, lower, upper))
     }
 }
@@ -554,4 +554,95 @@ pub trait Address:
     fn is_unicast_global(&self) -> bool {
         self.is_unicast() && self.is_global()
     }
+
+    /// Returns [`true`] if this is a unicast link-local address.
+    ///
+    /// For IPv6, this is equivalent to
+    /// [`is_link_local()`][Self::is_link_local()]. IPv4 addresses always
+    /// return [`false`].
+    ///
+    /// # [`std::net`] Compatibility
+    ///
+    /// This method is defined on [`Ipv6Addr`][std::net::Ipv6Addr] but not on
+    /// [`Ipv4Addr`][std::net::Ipv4Addr] or [`IpAddr`][std::net::IpAddr].
+    ///
+    /// This implementation provides the method for addresses of all families.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip::{traits::Address as _, Address, Any, Ipv4, Ipv6};
+    ///
+    /// let v6_link_local = "fe80::1".parse::<Address<Ipv6>>()?;
+    /// let v6_site_local = "fec0::1".parse::<Address<Ipv6>>()?;
+    /// let v4_link_local = "169.254.0.1".parse::<Address<Any>>()?;
+    ///
+    /// assert_eq!(v6_link_local.is_unicast_link_local(), true);
+    /// assert_eq!(v6_site_local.is_unicast_link_local(), false);
+    /// assert_eq!(v4_link_local.is_unicast_link_local(), false);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    fn is_unicast_link_local(&self) -> bool;
+
+    /// Returns [`true`] if this is a global unicast address, by allocation.
+    ///
+    /// For IPv6, these are the addresses within `2000::/3`, as described in
+    /// [RFC 4291]. Unlike [`is_unicast_global()`][Self::is_unicast_global()],
+    /// this does not consult the IANA Special-Purpose Address Registry.
+    ///
+    /// IPv4 has no equivalent allocation, so this is the same as
+    /// [`is_unicast_global()`][Self::is_unicast_global()].
+    ///
+    /// [RFC 4291]: https://tools.ietf.org/html/rfc4291#section-2.4
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip::{traits::Address as _, Address, Any, Ipv4, Ipv6};
+    ///
+    /// let v6_doc = "2001:db8::1".parse::<Address<Ipv6>>()?;
+    /// let v6_ula = "fc00::1".parse::<Address<Ipv6>>()?;
+    /// let v4_unicast_global = "1.1.1.1".parse::<Address<Ipv4>>()?;
+    /// let v4_unicast_private = "192.168.1.1".parse::<Address<Any>>()?;
+    ///
+    /// assert_eq!(v6_doc.is_global_unicast(), true);
+    /// assert_eq!(v6_doc.is_unicast_global(), false);
+    /// assert_eq!(v6_ula.is_global_unicast(), false);
+    /// assert_eq!(v4_unicast_global.is_global_unicast(), true);
+    /// assert_eq!(v4_unicast_private.is_global_unicast(), false);
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    fn is_global_unicast(&self) -> bool;
+
+    /// Returns the fully-qualified domain name used for reverse DNS lookups
+    /// of the address.
+    ///
+    /// IPv4 addresses are mapped to a name under `in-addr.arpa.`, with one
+    /// label per octet. IPv6 addresses are mapped to a name under `ip6.arpa.`,
+    /// with one label per nibble.
+    ///
+    /// In both cases, labels are ordered from least- to most-significant.
+    ///
+    /// See [RFC 1035 Section 3.5] and [RFC 3596 Section 2.5].
+    ///
+    /// [RFC 1035 Section 3.5]: https://tools.ietf.org/html/rfc1035#section-3.5
+    /// [RFC 3596 Section 2.5]: https://tools.ietf.org/html/rfc3596#section-2.5
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip::{traits::Address as _, Address, Any, Ipv4, Ipv6};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.1".parse::<Address<Ipv4>>()?.to_reverse_dns(),
+    ///     "1.2.0.192.in-addr.arpa.",
+    /// );
+    /// assert_eq!(
+    ///     "2001:db8::1".parse::<Address<Any>>()?.to_reverse_dns(),
+    ///     "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa.",
+    /// );
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_reverse_dns(&self) -> std::string::String;
 }
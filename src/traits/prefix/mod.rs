@@ -203,6 +203,8 @@ pub trait Prefix:
     /// Returns [`true`] if `self` and `other` share the same immediate
     /// supernet. See also [`supernet()`][Self::supernet()].
     ///
+    /// Prefixes of different address families are never siblings.
+    ///
     /// # Examples
     ///
     /// ``` rust
@@ -261,7 +263,9 @@ pub trait Prefix:
     ///
     /// # Errors
     ///
-    /// An error is returned if `new_prefix_len < self.prefix_len()`.
+    /// An error is returned if `new_prefix_len < self.prefix_len()`, or if
+    /// `new_prefix_len` and `self` are of different address families (which
+    /// is only possible for [`Prefix<Any>`][crate::any::Prefix]).
     ///
     /// # Examples
     ///
@@ -315,4 +319,45 @@ pub trait Prefix:
     /// ```
     // #[cfg(feature = "std")]
     fn new_prefix_length(&self, length: u8) -> Result<Self::Length, Error>;
+
+    /// Returns the fully-qualified domain name of the reverse DNS zone
+    /// corresponding to the prefix.
+    ///
+    /// The name contains one label for each whole octet (IPv4) or nibble
+    /// (IPv6) of the network address covered by the prefix-length.
+    ///
+    /// See also [`Address::to_reverse_dns()`].
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`PrefixLength`][crate::error::Kind::PrefixLength]
+    /// is returned if the prefix-length is not a multiple of `8` (IPv4) or
+    /// `4` (IPv6), since such prefixes do not correspond to a single reverse
+    /// DNS zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip::{traits::Prefix as _, Any, Ipv4, Ipv6, Prefix};
+    ///
+    /// assert_eq!(
+    ///     "192.0.2.0/24".parse::<Prefix<Ipv4>>()?.to_reverse_dns()?,
+    ///     "2.0.192.in-addr.arpa.",
+    /// );
+    /// assert_eq!(
+    ///     "2001:db8::/32".parse::<Prefix<Ipv6>>()?.to_reverse_dns()?,
+    ///     "8.b.d.0.1.0.0.2.ip6.arpa.",
+    /// );
+    /// assert_eq!(
+    ///     "::/0".parse::<Prefix<Any>>()?.to_reverse_dns()?,
+    ///     "ip6.arpa.",
+    /// );
+    /// assert!("192.0.2.0/25"
+    ///     .parse::<Prefix<Ipv4>>()?
+    ///     .to_reverse_dns()
+    ///     .is_err());
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_reverse_dns(&self) -> Result<std::string::String, Error>;
 }
@@ -38,6 +38,64 @@ pub trait Range:
     /// Return the upper bound [`Self::Length`] of `self`.
     fn upper(&self) -> Self::Length;
 
+    /// Test whether `prefix` is contained in `self`.
+    ///
+    /// That is, whether `prefix` is covered by [`self.prefix()`][Self::prefix]
+    /// and has a prefix-length between [`self.lower()`][Self::lower] and
+    /// [`self.upper()`][Self::upper] inclusive.
+    ///
+    /// This does not iterate over the prefixes contained in `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip::{traits::PrefixRange as _, Any, Ipv6, PrefixRange};
+    ///
+    /// let range: PrefixRange<Ipv6> = "2001:db8::/32,48,64".parse()?;
+    ///
+    /// assert!(range.contains_prefix("2001:db8:f00::/48".parse()?));
+    /// assert!(!range.contains_prefix("2001:db8::/32".parse()?));
+    /// assert!(!range.contains_prefix("2001:db9::/48".parse()?));
+    ///
+    /// let range: PrefixRange<Any> = "::/0,0,128".parse()?;
+    ///
+    /// assert!(range.contains_prefix("2001:db8::/48".parse()?));
+    /// assert!(!range.contains_prefix("192.0.2.0/24".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    fn contains_prefix(&self, prefix: Self::Prefix) -> bool {
+        let length = prefix.prefix_len();
+        self.afi() == prefix.afi()
+            && self.prefix().contains(&prefix)
+            && self.lower() <= length
+            && length <= self.upper()
+    }
+
+    /// Test whether any prefix contained in `self` contains `addr`.
+    ///
+    /// Since `self` contains a prefix of length [`self.lower()`][Self::lower]
+    /// covering each address in [`self.prefix()`][Self::prefix], this is
+    /// equivalent to testing whether [`self.prefix()`][Self::prefix] contains
+    /// `addr`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ip::{traits::PrefixRange as _, Any, Ipv4, PrefixRange};
+    ///
+    /// let range: PrefixRange<Ipv4> = "192.0.2.0/24,26,28".parse()?;
+    ///
+    /// assert!(range.contains_address("192.0.2.1".parse()?));
+    /// assert!(!range.contains_address("198.51.100.1".parse()?));
+    ///
+    /// let range: PrefixRange<Any> = "2001:db8::/32,48,48".parse()?;
+    ///
+    /// assert!(range.contains_address("2001:db8::1".parse()?));
+    /// assert!(!range.contains_address("192.0.2.1".parse()?));
+    /// # Ok::<(), ip::Error>(())
+    /// ```
+    fn contains_address(&self, addr: <Self::Prefix as Prefix>::Address) -> bool;
+
     /// Construct a new IP prefix-range from the intersection of `self` and
     /// `len_range`.
     ///
@@ -58,6 +58,87 @@ pub trait Set<'a>:
     /// ```
     fn contains(&self, prefix: Self::Prefix) -> bool;
 
+    /// Test whether every prefix in `range` is contained in `self`.
+    ///
+    /// This does not require expanding `range` into its constituent
+    /// prefixes, and so is efficient even for very large ranges.
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv6, PrefixRange, PrefixSet};
+    /// let set = PrefixSet::<Ipv6>::from_iter([
+    ///     "2001:db8::/32,48,64".parse::<PrefixRange<Ipv6>>()?,
+    /// ]);
+    /// assert!(set.contains_range(&"2001:db8:f00::/40,56,64".parse()?));
+    /// assert!(!set.contains_range(&"2001:db8::/32,47,48".parse()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn contains_range(&self, range: &Self::Range) -> bool;
+
+    /// Test whether any prefix contained in `self` contains `addr`.
+    ///
+    /// This is equivalent to, but cheaper than,
+    /// [`self.longest_match(addr).is_some()`][Self::longest_match].
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Address, Error, Ipv6, Prefix, PrefixSet};
+    /// let set = PrefixSet::<Ipv6>::from_iter(["2001:db8::/48".parse::<Prefix<Ipv6>>()?]);
+    /// assert!(set.contains_address("2001:db8::1".parse::<Address<Ipv6>>()?));
+    /// assert!(!set.contains_address("2001:db8:1::1".parse::<Address<Ipv6>>()?));
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn contains_address(&self, addr: <Self::Prefix as Prefix>::Address) -> bool;
+
+    /// Find the longest prefix contained in `self` that contains `addr`.
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Address, Error, Ipv4, Prefix, PrefixSet};
+    /// let set: PrefixSet<Ipv4> = ["10.0.0.0/8", "10.1.0.0/16", "10.1.1.0/24"]
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<Prefix<Ipv4>>())
+    ///     .collect::<Result<_, _>>()?;
+    /// assert_eq!(
+    ///     set.longest_match("10.1.2.3".parse::<Address<Ipv4>>()?),
+    ///     Some("10.1.0.0/16".parse()?),
+    /// );
+    /// assert_eq!(set.longest_match("11.0.0.1".parse::<Address<Ipv4>>()?), None);
+    /// # Ok::<_, Error>(())
+    /// ```
+    ///
+    /// For an [`any::PrefixSet`][crate::any::PrefixSet], the search is
+    /// restricted to prefixes of the same address family as `addr`:
+    ///
+    /// ```
+    /// # use core::str::FromStr;
+    /// # use ip::{traits::PrefixSet as _, Address, Any, Error, Prefix, PrefixSet};
+    /// let set: PrefixSet<Any> = ["0.0.0.0/0", "2001:db8::/32"]
+    ///     .into_iter()
+    ///     .map(Prefix::<Any>::from_str)
+    ///     .collect::<Result<_, _>>()?;
+    /// assert_eq!(
+    ///     set.longest_match("2001:db8::1".parse::<Address<Any>>()?),
+    ///     Some("2001:db8::/32".parse()?),
+    /// );
+    /// assert_eq!(set.longest_match("2001:db9::1".parse::<Address<Any>>()?), None);
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn longest_match(&self, addr: <Self::Prefix as Prefix>::Address) -> Option<Self::Prefix>;
+
+    /// Find the longest prefix contained in `self` that contains `prefix`.
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv6, Prefix, PrefixRange, PrefixSet};
+    /// let set: PrefixSet<Ipv6> = ["2001:db8::/32,48,48".parse::<PrefixRange<Ipv6>>()?]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(
+    ///     set.longest_match_prefix("2001:db8:f00::/56".parse()?),
+    ///     Some("2001:db8:f00::/48".parse()?),
+    /// );
+    /// assert_eq!(set.longest_match_prefix("2001:db8::/40".parse()?), None);
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn longest_match_prefix(&self, prefix: Self::Prefix) -> Option<Self::Prefix>;
+
     /// Get an iterator over the [`Self::Prefix`]s contained in `self`.
     ///
     /// ```
@@ -110,6 +191,10 @@ pub trait Set<'a>:
 
     /// Get the number of prefixes in `self`.
     ///
+    /// The implementations provided by this crate maintain this count as the
+    /// set is modified, so that this method does not need to iterate over
+    /// `self`. The count saturates at [`usize::MAX`].
+    ///
     /// ```
     /// # use ip::{PrefixSet, traits::PrefixSet as _, Error, Ipv4, PrefixRange};
     /// let set: PrefixSet<Ipv4> = ["192.0.2.0/24,26,26".parse::<PrefixRange<Ipv4>>()?]
@@ -134,4 +219,87 @@ pub trait Set<'a>:
     fn is_empty(&'a self) -> bool {
         self.ranges().count() == 0
     }
+
+    /// Insert `prefix` into `self`.
+    ///
+    /// This allows sets to be built generically over the address-family:
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet, Error, Ipv4, Prefix};
+    /// fn build<'a, S: PrefixSet<'a>>(prefixes: impl IntoIterator<Item = S::Prefix>) -> S {
+    ///     let mut set = S::default();
+    ///     for prefix in prefixes {
+    ///         set.insert(prefix);
+    ///     }
+    ///     set
+    /// }
+    ///
+    /// let prefixes = ["192.0.2.0/24".parse::<Prefix<Ipv4>>()?];
+    /// let set: ip::PrefixSet<Ipv4> = build(prefixes);
+    /// assert!(set.contains(prefixes[0]));
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn insert(&mut self, prefix: Self::Prefix) -> &mut Self {
+        self.extend([prefix]);
+        self
+    }
+
+    /// Insert every prefix in `range` into `self`.
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Any, Error, PrefixRange, PrefixSet};
+    /// let mut set = PrefixSet::<Any>::default();
+    /// set.insert_range("2001:db8::/32,48,48".parse::<PrefixRange<Any>>()?);
+    /// assert_eq!(set.len(), 1 << 16);
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn insert_range(&mut self, range: Self::Range) -> &mut Self {
+        self.extend([range]);
+        self
+    }
+
+    /// Remove `prefix` from `self`.
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, Prefix, PrefixRange, PrefixSet};
+    /// let mut set: PrefixSet<Ipv4> = ["192.0.2.0/24,26,26".parse::<PrefixRange<Ipv4>>()?]
+    ///     .into_iter()
+    ///     .collect();
+    /// set.remove("192.0.2.0/26".parse::<Prefix<Ipv4>>()?);
+    /// assert_eq!(set.len(), 3);
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn remove(&mut self, prefix: Self::Prefix) -> &mut Self {
+        *self = core::mem::take(self) - Self::from_iter([prefix]);
+        self
+    }
+
+    /// Remove every prefix in `range` from `self`.
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Error, Ipv4, PrefixRange, PrefixSet};
+    /// let mut set: PrefixSet<Ipv4> = ["192.0.2.0/24,24,26".parse::<PrefixRange<Ipv4>>()?]
+    ///     .into_iter()
+    ///     .collect();
+    /// set.remove_range("192.0.2.0/24,26,26".parse::<PrefixRange<Ipv4>>()?);
+    /// assert_eq!(set.len(), 3);
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn remove_range(&mut self, range: Self::Range) -> &mut Self {
+        *self = core::mem::take(self) - Self::from_iter([range]);
+        self
+    }
+
+    /// Remove all prefixes from `self`.
+    ///
+    /// ```
+    /// # use ip::{traits::PrefixSet as _, Any, Error, PrefixSet};
+    /// let mut set = PrefixSet::<Any>::any();
+    /// set.clear();
+    /// assert!(set.is_empty());
+    /// # Ok::<_, Error>(())
+    /// ```
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
 }
@@ -5,11 +5,11 @@ use core::mem;
 use core::ops::{Add, BitAnd, BitOr, BitXor, Deref, DerefMut, Not, RangeInclusive, Shl, Shr, Sub};
 
 use bitvec::{slice::BitSlice, BitArr};
-use num_traits::CheckedAdd;
+use num_traits::{CheckedAdd, CheckedSub, One};
 
 use super::Afi;
 use crate::{
-    concrete::{Ipv4, Ipv6},
+    concrete::{Ipv4, Ipv6, SpecialPurpose},
     error::Error,
     parser,
 };
@@ -25,10 +25,16 @@ pub trait Address<A: Afi>:
     + BitOr<Self, Output = Self>
     + BitXor<Self, Output = Self>
     + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
     + CheckedAdd
+    + CheckedSub
+    + One
     + Not<Output = Self>
     + Shl<Self::Length, Output = Self>
     + Shr<Self::Length, Output = Self>
+    + TryInto<usize>
+    + Into<u128>
+    + TryFrom<u128>
     + 'static
 {
     /// Underlying primitive type used to store bit-widths of `Self`.
@@ -105,9 +111,24 @@ pub trait Address<A: Afi>:
     /// addresses", if that is defined for this address family.
     const ULA_RANGE: Option<RangeInclusive<Self>>;
 
+    /// The range of primitive address values defined for "global unicast"
+    /// use, if that is defined for this address family.
+    const GLOBAL_UNICAST_RANGE: Option<RangeInclusive<Self>>;
+
+    /// The entries of the IANA Special-Purpose Address Registry for this
+    /// address family, ordered by the first address of each entry, and then
+    /// from least- to most-specific.
+    const SPECIAL_PURPOSE_RANGES: &'static [(SpecialPurpose, RangeInclusive<Self>)];
+
     /// Get the number of leading zeros in the binary representation of `self`.
     fn leading_zeros(self) -> Self::Length;
 
+    /// Get the number of ones in the binary representation of `self`.
+    fn count_ones(self) -> Self::Length;
+
+    /// Get the number of zeros in the binary representation of `self`.
+    fn count_zeros(self) -> Self::Length;
+
     /// Convert `self` to big-endian [`A::Octets`][Afi::Octets].
     fn to_be_bytes(self) -> A::Octets;
 
@@ -221,12 +242,121 @@ impl Address<Ipv4> for u32 {
     const THISNET_RANGE: Option<RangeInclusive<Self>> =
         Some(ipv4!(0, 0, 0, 0)..=ipv4!(0, 255, 255, 255));
     const ULA_RANGE: Option<RangeInclusive<Self>> = None;
+    const GLOBAL_UNICAST_RANGE: Option<RangeInclusive<Self>> = None;
+    const SPECIAL_PURPOSE_RANGES: &'static [(SpecialPurpose, RangeInclusive<Self>)] = &[
+        (
+            SpecialPurpose::ThisNetwork,
+            ipv4!(0, 0, 0, 0)..=ipv4!(0, 255, 255, 255),
+        ),
+        (
+            SpecialPurpose::ThisHost,
+            ipv4!(0, 0, 0, 0)..=ipv4!(0, 0, 0, 0),
+        ),
+        (
+            SpecialPurpose::Private,
+            ipv4!(10, 0, 0, 0)..=ipv4!(10, 255, 255, 255),
+        ),
+        (
+            SpecialPurpose::Shared,
+            ipv4!(100, 64, 0, 0)..=ipv4!(100, 127, 255, 255),
+        ),
+        (
+            SpecialPurpose::Loopback,
+            ipv4!(127, 0, 0, 0)..=ipv4!(127, 255, 255, 255),
+        ),
+        (
+            SpecialPurpose::LinkLocal,
+            ipv4!(169, 254, 0, 0)..=ipv4!(169, 254, 255, 255),
+        ),
+        (
+            SpecialPurpose::Private,
+            ipv4!(172, 16, 0, 0)..=ipv4!(172, 31, 255, 255),
+        ),
+        (
+            SpecialPurpose::ProtocolAssignments,
+            ipv4!(192, 0, 0, 0)..=ipv4!(192, 0, 0, 255),
+        ),
+        (
+            SpecialPurpose::ServiceContinuity,
+            ipv4!(192, 0, 0, 0)..=ipv4!(192, 0, 0, 7),
+        ),
+        (
+            SpecialPurpose::DummyAddress,
+            ipv4!(192, 0, 0, 8)..=ipv4!(192, 0, 0, 8),
+        ),
+        (
+            SpecialPurpose::PortControlProtocolAnycast,
+            ipv4!(192, 0, 0, 9)..=ipv4!(192, 0, 0, 9),
+        ),
+        (
+            SpecialPurpose::TraversalUsingRelaysAnycast,
+            ipv4!(192, 0, 0, 10)..=ipv4!(192, 0, 0, 10),
+        ),
+        (
+            SpecialPurpose::Nat64Discovery,
+            ipv4!(192, 0, 0, 170)..=ipv4!(192, 0, 0, 171),
+        ),
+        (
+            SpecialPurpose::Documentation,
+            ipv4!(192, 0, 2, 0)..=ipv4!(192, 0, 2, 255),
+        ),
+        (
+            SpecialPurpose::As112,
+            ipv4!(192, 31, 196, 0)..=ipv4!(192, 31, 196, 255),
+        ),
+        (
+            SpecialPurpose::Amt,
+            ipv4!(192, 52, 193, 0)..=ipv4!(192, 52, 193, 255),
+        ),
+        (
+            SpecialPurpose::SixToFourRelayAnycast,
+            ipv4!(192, 88, 99, 0)..=ipv4!(192, 88, 99, 255),
+        ),
+        (
+            SpecialPurpose::Private,
+            ipv4!(192, 168, 0, 0)..=ipv4!(192, 168, 255, 255),
+        ),
+        (
+            SpecialPurpose::DirectDelegationAs112,
+            ipv4!(192, 175, 48, 0)..=ipv4!(192, 175, 48, 255),
+        ),
+        (
+            SpecialPurpose::Benchmarking,
+            ipv4!(198, 18, 0, 0)..=ipv4!(198, 19, 255, 255),
+        ),
+        (
+            SpecialPurpose::Documentation,
+            ipv4!(198, 51, 100, 0)..=ipv4!(198, 51, 100, 255),
+        ),
+        (
+            SpecialPurpose::Documentation,
+            ipv4!(203, 0, 113, 0)..=ipv4!(203, 0, 113, 255),
+        ),
+        (
+            SpecialPurpose::Reserved,
+            ipv4!(240, 0, 0, 0)..=ipv4!(255, 255, 255, 255),
+        ),
+        (
+            SpecialPurpose::LimitedBroadcast,
+            ipv4!(255, 255, 255, 255)..=ipv4!(255, 255, 255, 255),
+        ),
+    ];
 
     #[allow(clippy::cast_possible_truncation)]
     fn leading_zeros(self) -> Self::Length {
         self.leading_zeros() as Self::Length
     }
 
+    #[allow(clippy::cast_possible_truncation)]
+    fn count_ones(self) -> Self::Length {
+        self.count_ones() as Self::Length
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn count_zeros(self) -> Self::Length {
+        self.count_zeros() as Self::Length
+    }
+
     fn to_be_bytes(self) -> <Ipv4 as Afi>::Octets {
         self.to_be_bytes()
     }
@@ -348,12 +478,106 @@ impl Address<Ipv6> for u128 {
     const THISNET_RANGE: Option<RangeInclusive<Self>> = None;
     const ULA_RANGE: Option<RangeInclusive<Self>> =
         Some(0xfc00_0000_0000_0000_0000_0000_0000_0000..=0xfd00_0000_0000_0000_0000_0000_0000_0000);
+    const GLOBAL_UNICAST_RANGE: Option<RangeInclusive<Self>> =
+        Some(0x2000_0000_0000_0000_0000_0000_0000_0000..=0x3fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff);
+    const SPECIAL_PURPOSE_RANGES: &'static [(SpecialPurpose, RangeInclusive<Self>)] = &[
+        (
+            SpecialPurpose::Unspecified,
+            0x0000_0000_0000_0000_0000_0000_0000_0000..=0x0000_0000_0000_0000_0000_0000_0000_0000,
+        ),
+        (
+            SpecialPurpose::Loopback,
+            0x0000_0000_0000_0000_0000_0000_0000_0001..=0x0000_0000_0000_0000_0000_0000_0000_0001,
+        ),
+        (
+            SpecialPurpose::Ipv4Mapped,
+            0x0000_0000_0000_0000_0000_ffff_0000_0000..=0x0000_0000_0000_0000_0000_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::Ipv4Ipv6Translation,
+            0x0064_ff9b_0000_0000_0000_0000_0000_0000..=0x0064_ff9b_0000_0000_0000_0000_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::Ipv4Ipv6TranslationLocal,
+            0x0064_ff9b_0001_0000_0000_0000_0000_0000..=0x0064_ff9b_0001_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::DiscardOnly,
+            0x0100_0000_0000_0000_0000_0000_0000_0000..=0x0100_0000_0000_0000_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::ProtocolAssignments,
+            0x2001_0000_0000_0000_0000_0000_0000_0000..=0x2001_01ff_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::Teredo,
+            0x2001_0000_0000_0000_0000_0000_0000_0000..=0x2001_0000_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::PortControlProtocolAnycast,
+            0x2001_0001_0000_0000_0000_0000_0000_0001..=0x2001_0001_0000_0000_0000_0000_0000_0001,
+        ),
+        (
+            SpecialPurpose::TraversalUsingRelaysAnycast,
+            0x2001_0001_0000_0000_0000_0000_0000_0002..=0x2001_0001_0000_0000_0000_0000_0000_0002,
+        ),
+        (
+            SpecialPurpose::Benchmarking,
+            0x2001_0002_0000_0000_0000_0000_0000_0000..=0x2001_0002_0000_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::Amt,
+            0x2001_0003_0000_0000_0000_0000_0000_0000..=0x2001_0003_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::As112,
+            0x2001_0004_0112_0000_0000_0000_0000_0000..=0x2001_0004_0112_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::Orchid,
+            0x2001_0010_0000_0000_0000_0000_0000_0000..=0x2001_001f_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::OrchidV2,
+            0x2001_0020_0000_0000_0000_0000_0000_0000..=0x2001_002f_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::Documentation,
+            0x2001_0db8_0000_0000_0000_0000_0000_0000..=0x2001_0db8_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::SixToFour,
+            0x2002_0000_0000_0000_0000_0000_0000_0000..=0x2002_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::DirectDelegationAs112,
+            0x2620_004f_8000_0000_0000_0000_0000_0000..=0x2620_004f_8000_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::UniqueLocal,
+            0xfc00_0000_0000_0000_0000_0000_0000_0000..=0xfdff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+        (
+            SpecialPurpose::LinkLocal,
+            0xfe80_0000_0000_0000_0000_0000_0000_0000..=0xfebf_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+        ),
+    ];
 
     #[allow(clippy::cast_possible_truncation)]
     fn leading_zeros(self) -> Self::Length {
         self.leading_zeros() as Self::Length
     }
 
+    #[allow(clippy::cast_possible_truncation)]
+    fn count_ones(self) -> Self::Length {
+        self.count_ones() as Self::Length
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn count_zeros(self) -> Self::Length {
+        self.count_zeros() as Self::Length
+    }
+
     fn to_be_bytes(self) -> <Ipv6 as Afi>::Octets {
         self.to_be_bytes()
     }
@@ -462,7 +686,7 @@ pub(crate) trait IntoIpv6Segments: Address<Ipv6> {
         // Safety:
         // it is always safe to transmute `[u16; 8]` to `[u8; 16]`
         let octets = unsafe {
-            mem::transmute([
+            mem::transmute::<[u16; 8], [u8; 16]>([
                 segments[0].to_be(),
                 segments[1].to_be(),
                 segments[2].to_be(),
@@ -1,6 +1,6 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
-use ip::{traits::PrefixSet as _, AfiClass, Any, Ipv4, Ipv6, Prefix, PrefixSet};
+use ip::{traits::PrefixSet as _, AfiClass, Any, Ipv4, Ipv6, Prefix, PrefixLength, PrefixSet};
 use itertools::Itertools;
 use proptest::{arbitrary::ParamsFor, prelude::*};
 
@@ -155,3 +155,48 @@ property_tests! {
     ipv6 => Ipv6,
     any => Any,
 }
+
+macro_rules! length_tests {
+    ( $( $mod:ident => $p:ty ),* $(,)? ) => {
+        $(
+            mod $mod {
+                use super::*;
+
+                proptest! {
+                    #[test]
+                    fn length_histogram_matches_prefix_lengths(
+                        s in any::<TestPrefixSet<$p>>(),
+                    ) {
+                        let mut expected = BTreeMap::new();
+                        s.cs.iter()
+                            .for_each(|prefix| *expected.entry(prefix.length()).or_insert(0) += 1);
+                        prop_assert_eq!(
+                            s.ps.length_histogram().collect::<Vec<_>>(),
+                            expected.into_iter().collect::<Vec<_>>()
+                        );
+                    }
+
+                    #[test]
+                    fn count_where_matches_filtered_prefixes(
+                        s in any::<TestPrefixSet<$p>>(),
+                        x in any::<PrefixLength<$p>>(),
+                        y in any::<PrefixLength<$p>>(),
+                    ) {
+                        let lengths = x.min(y)..=x.max(y);
+                        prop_assert_eq!(
+                            s.ps.count_where(lengths.clone()),
+                            s.cs.iter()
+                                .filter(|prefix| lengths.contains(&prefix.length()))
+                                .count()
+                        );
+                    }
+                }
+            }
+        )*
+    }
+}
+
+length_tests! {
+    ipv4_lengths => Ipv4,
+    ipv6_lengths => Ipv6,
+}
@@ -1,8 +1,8 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use ip::{
     traits::{Prefix as _, PrefixSet as _},
-    Ipv4, Ipv6, Prefix, PrefixRange, PrefixSet,
+    Ipv4, Ipv6, Prefix, PrefixLength, PrefixRange, PrefixSet,
 };
 use num_traits::Zero;
 use utils::data_set;
@@ -10,6 +10,39 @@ use utils::data_set;
 mod ipv4 {
     use super::*;
 
+    #[test]
+    fn length_histogram_matches_prefix_lengths() {
+        for name in ["AS-WOLCOMM-ipv4-ranges", "AS-HURRICANE-ipv4-ranges"] {
+            let set: PrefixSet<Ipv4> = data_set::<PrefixRange<Ipv4>>(name, 0, 0)
+                .read()
+                .into_iter()
+                .collect();
+            let mut expected = BTreeMap::new();
+            set.prefixes()
+                .for_each(|prefix| *expected.entry(prefix.length()).or_insert(0) += 1);
+            assert_eq!(
+                set.length_histogram().collect::<Vec<_>>(),
+                expected.into_iter().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn count_where_matches_filtered_prefixes() {
+        let set: PrefixSet<Ipv4> = data_set::<PrefixRange<Ipv4>>("AS-WOLCOMM-ipv4-ranges", 0, 0)
+            .read()
+            .into_iter()
+            .collect();
+        let len = |length| PrefixLength::<Ipv4>::from_primitive(length).unwrap();
+        let lengths = len(20)..=len(24);
+        assert_eq!(
+            set.count_where(lengths.clone()),
+            set.prefixes()
+                .filter(|prefix| lengths.contains(&prefix.length()))
+                .count()
+        );
+    }
+
     #[test]
     fn set_from_prefixes_contains_all_prefixes() {
         let prefixes: Vec<Prefix<Ipv4>> = data_set("AS-WOLCOMM-ipv4-prefixes", 0, 0).read();
@@ -191,6 +224,39 @@ mod ipv4 {
 mod ipv6 {
     use super::*;
 
+    #[test]
+    fn length_histogram_matches_prefix_lengths() {
+        for name in ["AS-WOLCOMM-ipv6-ranges", "AS-HURRICANE-ipv6-ranges"] {
+            let set: PrefixSet<Ipv6> = data_set::<PrefixRange<Ipv6>>(name, 0, 0)
+                .read()
+                .into_iter()
+                .collect();
+            let mut expected = BTreeMap::new();
+            set.prefixes()
+                .for_each(|prefix| *expected.entry(prefix.length()).or_insert(0) += 1);
+            assert_eq!(
+                set.length_histogram().collect::<Vec<_>>(),
+                expected.into_iter().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn count_where_matches_filtered_prefixes() {
+        let set: PrefixSet<Ipv6> = data_set::<PrefixRange<Ipv6>>("AS-WOLCOMM-ipv6-ranges", 0, 0)
+            .read()
+            .into_iter()
+            .collect();
+        let len = |length| PrefixLength::<Ipv6>::from_primitive(length).unwrap();
+        let lengths = len(32)..=len(48);
+        assert_eq!(
+            set.count_where(lengths.clone()),
+            set.prefixes()
+                .filter(|prefix| lengths.contains(&prefix.length()))
+                .count()
+        );
+    }
+
     #[test]
     fn set_from_prefixes_contains_all_prefixes() {
         let prefixes: Vec<Prefix<Ipv6>> = data_set("AS-WOLCOMM-ipv6-prefixes", 0, 0).read();